@@ -523,7 +523,7 @@ fn main() {
 				use oct::encode::{Encode, Output, SizedEncode};
 
 				const ITEM_SIZE: usize =
-					isize::MAX_ENCODED_SIZE // discriminant
+					u8::MAX_ENCODED_SIZE // discriminant
 					+ Unit::MAX_ENCODED_SIZE;
 
 				let mut buf = vec![0x00; ITEM_SIZE * VALUE_COUNT].into_boxed_slice();
@@ -0,0 +1,177 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::FieldAttrs;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Fields, Ident, Type};
+
+/// The position of a bit field.
+///
+/// Consecutive bit fields form a group, which is packed into a single buffer of bytes.
+pub struct BitSlot {
+	/// The index of the group.
+	pub group: usize,
+
+	/// The offset of the field within the group, in bits.
+	pub offset: usize,
+
+	/// The width of the field, in bits.
+	pub width: u32,
+
+	/// The size of the group, in bytes.
+	pub len: usize,
+
+	/// Whether this is the first field of the group.
+	pub first: bool,
+
+	/// Whether this is the last field of the group.
+	pub last: bool,
+}
+
+impl BitSlot {
+	/// Retrieves the identifier of the group's buffer.
+	#[must_use]
+	fn buf(&self) -> Ident {
+		Ident::new(&format!("bits{}", self.group), Span::call_site())
+	}
+
+	/// Declares the group's buffer for encoding.
+	///
+	/// This is only generated for the first field of the group.
+	#[must_use]
+	pub fn encode_start(&self) -> Option<TokenStream> {
+		let buf = self.buf();
+		let len = self.len;
+
+		self.first.then(|| quote! { let mut #buf = [0x00u8; #len]; })
+	}
+
+	/// Packs the field into the group's buffer.
+	///
+	/// The expression evaluates to a `Result`, the error type of which is `BitFieldError`.
+	#[must_use]
+	pub fn encode(&self, ty: &Type, value: &Ident) -> TokenStream {
+		let buf    = self.buf();
+		let offset = self.offset;
+		let width  = self.width;
+
+		quote! { ::oct::repr::bits::pack::<#ty>(#value, &mut #buf, #offset, #width) }
+	}
+
+	/// Writes the group's buffer.
+	///
+	/// This is only generated for the last field of the group.
+	#[must_use]
+	pub fn encode_end(&self) -> Option<TokenStream> {
+		let buf = self.buf();
+
+		self.last.then(|| quote! { stream.write(&#buf).unwrap(); })
+	}
+
+	/// Reads the group's buffer for decoding.
+	///
+	/// This is only generated for the first field of the group.
+	#[must_use]
+	pub fn decode_start(&self) -> Option<TokenStream> {
+		let buf = self.buf();
+		let len = self.len;
+
+		self.first.then(|| quote! { let #buf = stream.read(#len).unwrap(); })
+	}
+
+	/// Unpacks the field from the group's buffer.
+	///
+	/// The expression evaluates to a `Result`, the error type of which is `BitFieldError`.
+	#[must_use]
+	pub fn decode(&self, ty: &Type) -> TokenStream {
+		let buf    = self.buf();
+		let offset = self.offset;
+		let width  = self.width;
+
+		quote! { ::oct::repr::bits::unpack::<#ty>(#buf, #offset, #width) }
+	}
+}
+
+/// Lays out the bit fields of a structure.
+///
+/// Each run of consecutive fields with the `bits` attribute is packed into the fewest bytes possible.
+/// The returned vector has an entry for each field, with `None` denoting fields that are not bit fields.
+#[must_use]
+pub fn bit_slots(fields: &Fields) -> Vec<Option<BitSlot>> {
+	let widths: Vec<_> = fields
+		.iter()
+		.map(|field| FieldAttrs::get(&field.attrs).bits)
+		.collect();
+
+	let mut slots = Vec::with_capacity(widths.len());
+
+	let mut group = 0x0;
+	let mut index = 0x0;
+
+	while index < widths.len() {
+		if widths[index].is_none() {
+			slots.push(None);
+			index += 0x1;
+
+			continue;
+		}
+
+		// Find the end of the group.
+		let start = index;
+		let end   = widths[start..].iter().position(Option::is_none).map_or(widths.len(), |len| start + len);
+
+		let total: usize = widths[start..end].iter().flatten().map(|&width| width as usize).sum();
+		let len = total.div_ceil(0x8);
+
+		let mut offset = 0x0;
+
+		for (position, width) in widths[start..end].iter().flatten().copied().enumerate() {
+			slots.push(Some(BitSlot {
+				group,
+				offset,
+				width,
+				len,
+				first: position == 0x0,
+				last:  start + position == end - 0x1,
+			}));
+
+			offset += width as usize;
+		}
+
+		group += 0x1;
+		index  = end;
+	}
+
+	slots
+}
+
+/// Retrieves the combined size of all bit-field groups, in bytes.
+#[must_use]
+pub fn bits_size(fields: &Fields) -> usize {
+	bit_slots(fields)
+		.iter()
+		.flatten()
+		.filter(|slot| slot.first)
+		.map(|slot| slot.len)
+		.sum()
+}
@@ -0,0 +1,365 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	ContainerAttrs,
+	Discriminants,
+	FieldAttrs,
+	IntRepr,
+	Repr,
+	VariantAttrs,
+	mentions,
+	option_arg,
+	referenced_index,
+};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	Attribute,
+	Data,
+	DataEnum,
+	DeriveInput,
+	Field,
+	Fields,
+	Ident,
+	Member,
+};
+
+/// A derive macro.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Derive {
+	Decode,
+	Encode,
+	Fingerprint,
+	FixedEncode,
+	Message,
+	SizedEncode,
+}
+
+impl Derive {
+	#[inline]
+	#[must_use]
+	pub const fn to_str(self) -> &'static str {
+		match self {
+			Self::Decode      => "Decode",
+			Self::Encode      => "Encode",
+			Self::Fingerprint => "Fingerprint",
+			Self::FixedEncode => "FixedEncode",
+			Self::Message     => "Message",
+			Self::SizedEncode => "SizedEncode",
+		}
+	}
+}
+
+/// Checks the input of a derive macro.
+///
+/// All attributes of the container, its variants, and its fields are parsed, and their combinations are validated against each other and against `derive`.
+/// Expansion assumes that its input has passed this check, and may panic otherwise.
+///
+/// # Errors
+///
+/// Every problem is reported as an error spanned to the offending attribute, variant, or field, with all errors being combined into one.
+pub fn check(input: &DeriveInput, derive: Derive) -> syn::Result<()> {
+	let mut errors = Vec::new();
+
+	let attrs = collect(&mut errors, ContainerAttrs::parse(&input.attrs)).unwrap_or_default();
+
+	match input.data {
+		Data::Struct(ref data) => {
+			check_struct_fields(&mut errors, &data.fields);
+		},
+
+		Data::Enum(ref data) => {
+			if attrs.versioned {
+				errors.push(syn::Error::new_spanned(oct_attrs(&input.attrs), "the `versioned` attribute is only supported on structures"));
+			}
+
+			check_enum(&mut errors, input, data, &attrs);
+		},
+
+		Data::Union(ref data) => {
+			let message = format!("unions cannot derive `{}`", derive.to_str());
+
+			errors.push(syn::Error::new_spanned(data.union_token, message));
+		},
+	}
+
+	// The remaining checks depend on valid attributes.
+	if !errors.is_empty() {
+		return combine(errors);
+	}
+
+	let fields: Vec<&Field> = match input.data {
+		Data::Struct(ref data) => data.fields.iter().collect(),
+
+		Data::Enum(ref data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+
+		Data::Union(..) => Vec::new(),
+	};
+
+	let container = || oct_attrs(&input.attrs);
+
+	match derive {
+		Derive::Decode if attrs.from.is_some() || attrs.try_from.is_some() => {
+			if attrs.generate_error {
+				errors.push(syn::Error::new_spanned(container(), "generated errors are not supported with `from` or `try_from`"));
+			}
+
+			if attrs.validate.is_some() {
+				errors.push(syn::Error::new_spanned(container(), "`validate` is not supported with `from` or `try_from`"));
+			}
+		},
+
+		Derive::Encode if attrs.into.is_some() && attrs.generate_error => {
+			errors.push(syn::Error::new_spanned(container(), "generated errors are not supported with `into`"));
+		},
+
+		Derive::Message if attrs.message_id.is_none() => {
+			errors.push(syn::Error::new_spanned(&input.ident, "deriving `Message` requires the `message_id` attribute"));
+		},
+
+		Derive::SizedEncode if attrs.into.is_none() => {
+			if let Data::Enum(ref data) = input.data {
+				for variant in data.variants.iter().filter(|variant| VariantAttrs::get(&variant.attrs).other) {
+					errors.push(syn::Error::new_spanned(variant, "enumerations with `other` variants cannot derive `SizedEncode`"));
+				}
+			}
+
+			for field in &fields {
+				let field_attrs = FieldAttrs::get(&field.attrs);
+
+				let sized = field_attrs.skip
+					|| field_attrs.max_size.is_some()
+					|| field_attrs.int_repr.is_some()
+					|| field_attrs.bits.is_some()
+					|| field_attrs.encode_with.is_none();
+
+				if !sized {
+					errors.push(syn::Error::new_spanned(field, "fields with custom encoders require the `max_size` attribute for deriving `SizedEncode`"));
+				}
+			}
+		},
+
+		Derive::FixedEncode if attrs.into.is_none() => {
+			check_fixed(&mut errors, input, &attrs, &fields);
+		},
+
+		_ => { },
+	}
+
+	// Generated error types have a variant for each field.
+	if matches!(derive, Derive::Decode | Derive::Encode) && attrs.generate_error {
+		if !input.generics.params.is_empty() {
+			errors.push(syn::Error::new_spanned(&input.generics, "generated errors are not supported for generic types"));
+		}
+
+		if attrs.extensible {
+			errors.push(syn::Error::new_spanned(container(), "generated errors are not supported for extensible enumerations"));
+		}
+
+		if matches!(input.data, Data::Struct(ref data) if attrs.is_versioned(&data.fields)) {
+			errors.push(syn::Error::new_spanned(container(), "generated errors are not supported for versioned structures"));
+		}
+	}
+
+	combine(errors)
+}
+
+/// Checks the fields of a structure.
+fn check_struct_fields(errors: &mut Vec<syn::Error>, fields: &Fields) {
+	let field_attrs: Vec<_> = fields
+		.iter()
+		.map(|field| collect(errors, FieldAttrs::parse(&field.attrs)))
+		.collect();
+
+	let Some(field_attrs) = field_attrs.into_iter().collect::<Option<Vec<_>>>() else {
+		return;
+	};
+
+	// Versioned fields must be declared in order of
+	// introduction, after all unversioned fields.
+	let mut prev = None;
+
+	for (index, (field, attrs)) in fields.iter().zip(&field_attrs).enumerate() {
+		if attrs.since < prev {
+			errors.push(syn::Error::new_spanned(field, "versioned fields must be declared after all unversioned fields and in order of their versions"));
+		}
+
+		prev = attrs.since;
+
+		for member in [&attrs.tag_from, &attrs.len_from].into_iter().flatten() {
+			collect(errors, referenced_index(fields, index, member));
+		}
+
+		// Conditions implicitly reference the earlier named
+		// fields that they mention. Errors are spanned to the
+		// condition itself.
+		if let Some(ref condition) = attrs.condition {
+			let span = condition.clone().into_iter().next().map_or_else(Span::call_site, |token| token.span());
+
+			let idents = fields
+				.iter()
+				.take(index)
+				.filter_map(|field| field.ident.as_ref())
+				.filter(|&ident| mentions(condition.clone(), ident));
+
+			for ident in idents {
+				let member = Member::Named(Ident::new(&ident.to_string(), span));
+
+				collect(errors, referenced_index(fields, index, &member));
+			}
+		}
+
+		if attrs.condition.is_some() && option_arg(&field.ty).is_none() {
+			errors.push(syn::Error::new_spanned(&field.ty, "conditional fields must be of type `Option<T>`"));
+		}
+	}
+}
+
+/// Checks an enumeration, its variants, and their fields.
+fn check_enum(errors: &mut Vec<syn::Error>, input: &DeriveInput, data: &DataEnum, attrs: &ContainerAttrs) {
+	// The `repr` attribute is not used if a tag type is
+	// given.
+	if attrs.tag.is_none() {
+		collect(errors, Repr::parse(&input.attrs));
+	}
+
+	let mut valid = true;
+	let mut other = None;
+
+	for variant in &data.variants {
+		let Some(variant_attrs) = collect(errors, VariantAttrs::parse(&variant.attrs)) else {
+			valid = false;
+			continue;
+		};
+
+		if variant_attrs.other {
+			if !attrs.extensible {
+				errors.push(syn::Error::new_spanned(variant, "`other` variants require the `extensible` attribute on the enumeration"));
+			}
+
+			if variant.fields.len() != 0x2 {
+				errors.push(syn::Error::new_spanned(variant, "`other` variants must have exactly two fields (the tag and the payload)"));
+			}
+
+			if other.replace(variant).is_some() {
+				errors.push(syn::Error::new_spanned(variant, "enumerations can have at most one `other` variant"));
+			}
+		}
+
+		for field in &variant.fields {
+			let Some(field_attrs) = collect(errors, FieldAttrs::parse(&field.attrs)) else {
+				continue;
+			};
+
+			if field_attrs.since.is_some() {
+				errors.push(syn::Error::new_spanned(oct_attrs(&field.attrs), "the `since` attribute is only supported on structure fields"));
+			}
+
+			if field_attrs.tag_from.is_some() || field_attrs.len_from.is_some() || field_attrs.condition.is_some() || field_attrs.bits.is_some() {
+				errors.push(syn::Error::new_spanned(oct_attrs(&field.attrs), "the `tag_from`, `len_from`, `if`, and `bits` attributes are only supported on structure fields"));
+			}
+		}
+	}
+
+	// Tag values are taken from the variant attributes.
+	if valid {
+		collect(errors, Discriminants::new(&data.variants).check());
+	}
+}
+
+/// Checks that the encoded size of a type deriving `FixedEncode` does not depend on its value.
+fn check_fixed(errors: &mut Vec<syn::Error>, input: &DeriveInput, attrs: &ContainerAttrs, fields: &[&Field]) {
+	if attrs.versioned {
+		errors.push(syn::Error::new_spanned(oct_attrs(&input.attrs), "versioned structures cannot derive `FixedEncode`"));
+	}
+
+	if let Data::Enum(ref data) = input.data {
+		for variant in data.variants.iter().filter(|variant| VariantAttrs::get(&variant.attrs).other) {
+			errors.push(syn::Error::new_spanned(variant, "enumerations with `other` variants cannot derive `FixedEncode`"));
+		}
+
+		// The sizes of the variants are compared in a free-
+		// standing constant, which cannot name the generic
+		// parameters.
+		let has_fields = fields.iter().any(|field| !FieldAttrs::get(&field.attrs).skip);
+
+		if has_fields && !input.generics.params.is_empty() {
+			errors.push(syn::Error::new_spanned(&input.generics, "generic enumerations with fields cannot derive `FixedEncode`"));
+		}
+	}
+
+	for field in fields {
+		let field_attrs = FieldAttrs::get(&field.attrs);
+
+		if field_attrs.skip {
+			continue;
+		}
+
+		if field_attrs.since.is_some() {
+			errors.push(syn::Error::new_spanned(oct_attrs(&field.attrs), "versioned structures cannot derive `FixedEncode`"));
+		}
+
+		if field_attrs.condition.is_some() || field_attrs.len_from.is_some() || field_attrs.tag_from.is_some() {
+			errors.push(syn::Error::new_spanned(oct_attrs(&field.attrs), "fields with the `if`, `len_from`, or `tag_from` attributes cannot be used when deriving `FixedEncode`"));
+		}
+
+		if field_attrs.align.is_some() {
+			errors.push(syn::Error::new_spanned(oct_attrs(&field.attrs), "aligned fields cannot be used when deriving `FixedEncode`"));
+		}
+
+		match field_attrs.int_repr {
+			Some(IntRepr::Varint) => {
+				errors.push(syn::Error::new_spanned(oct_attrs(&field.attrs), "fields with the `varint` attribute cannot be used when deriving `FixedEncode`"));
+			},
+
+			None if field_attrs.encode_with.is_some() => {
+				errors.push(syn::Error::new_spanned(field, "fields with custom encoders cannot be used when deriving `FixedEncode`"));
+			},
+
+			_ => { },
+		}
+	}
+}
+
+/// Retrieves the `oct` attributes from a list of attributes, for use as the span of errors.
+fn oct_attrs(attrs: &[Attribute]) -> TokenStream {
+	let attrs = attrs.iter().filter(|attr| attr.path().is_ident("oct"));
+
+	quote! { #(#attrs)* }
+}
+
+/// Moves the error of `result` into `errors`.
+fn collect<T>(errors: &mut Vec<syn::Error>, result: syn::Result<T>) -> Option<T> {
+	result.map_err(|e| errors.push(e)).ok()
+}
+
+/// Combines errors into one.
+fn combine(errors: Vec<syn::Error>) -> syn::Result<()> {
+	errors
+		.into_iter()
+		.reduce(|mut errors, e| {
+			errors.combine(e);
+			errors
+		})
+		.map_or(Ok(()), Err)
+}
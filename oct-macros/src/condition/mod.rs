@@ -0,0 +1,143 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mentions, referenced_capture};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	Fields,
+	GenericArgument,
+	Ident,
+	Member,
+	PathArguments,
+	Type,
+};
+
+/// Retrieves the contained type of a conditional field.
+///
+/// # Panics
+///
+/// If the type is not `Option<T>`, then this function will panic.
+/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+#[must_use]
+pub fn option_inner(ty: &Type) -> &Type {
+	option_arg(ty).expect("conditional fields should have been checked")
+}
+
+/// Retrieves the argument of an `Option<T>` type.
+///
+/// If the type is not (syntactically) `Option<T>`, then [`None`] is returned.
+#[must_use]
+pub fn option_arg(ty: &Type) -> Option<&Type> {
+	if let Type::Path(ref ty) = *ty {
+		ty.path.segments.last().and_then(|segment| {
+			if segment.ident != "Option" {
+				return None;
+			}
+
+			let PathArguments::AngleBracketed(ref args) = segment.arguments else {
+				return None;
+			};
+
+			if args.args.len() != 0x1 {
+				return None;
+			}
+
+			let GenericArgument::Type(ref ty) = *args.args.first()? else {
+				return None;
+			};
+
+			Some(ty)
+		})
+	} else {
+		None
+	}
+}
+
+/// Evaluates the condition of the field at `index`.
+///
+/// Earlier named fields that are mentioned by the condition are bound by reference under their own names.
+/// If `owned` is set, then the captures of these fields are values (as when decoding); otherwise, they are references (as when encoding).
+#[must_use]
+pub fn condition(fields: &Fields, index: usize, condition: &TokenStream, owned: bool) -> TokenStream {
+	let bindings = fields
+		.iter()
+		.take(index)
+		.filter_map(|field| field.ident.as_ref())
+		.filter(|&ident| mentions(condition.clone(), ident))
+		.map(|ident| {
+			let capture = referenced_capture(fields, index, &Member::Named(ident.clone()));
+
+			if owned {
+				quote! { let #ident = &#capture; }
+			} else {
+				quote! { let #ident = #capture; }
+			}
+		});
+
+	quote! {
+		{
+			#(#bindings)*
+
+			let condition: bool = #condition;
+			condition
+		}
+	}
+}
+
+/// Decodes a conditional field.
+///
+/// The contained value is only decoded if `condition` holds.
+/// The expression evaluates to a `Result`, the error type of which is that of the field's `Decode` implementation.
+#[must_use]
+pub fn decode_conditional(ty: &Type, condition: &TokenStream) -> TokenStream {
+	let inner = option_inner(ty);
+
+	quote! {
+		if #condition {
+			::core::result::Result::map(<#inner as ::oct::decode::Decode>::decode(stream), ::core::option::Option::Some)
+		} else {
+			::core::result::Result::Ok(::core::option::Option::None)
+		}
+	}
+}
+
+/// Encodes a conditional field.
+///
+/// The presence of `value` is first checked against `condition`, with mismatches being converted using `map_err`.
+/// The expression evaluates to a `Result`, the error type of which is that of the field's `Encode` implementation.
+#[must_use]
+pub fn encode_conditional(ty: &Type, value: &Ident, condition: &TokenStream, map_err: &TokenStream) -> TokenStream {
+	let inner = option_inner(ty);
+
+	quote! {
+		{
+			::oct::error::PresenceError::check(#condition, ::core::option::Option::is_some(#value))#map_err?;
+
+			match *#value {
+				::core::option::Option::Some(ref value) => <#inner as ::oct::encode::Encode>::encode(value, stream),
+
+				::core::option::Option::None => ::core::result::Result::Ok(()),
+			}
+		}
+	}
+}
@@ -0,0 +1,184 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FieldAttrs, Repr};
+
+use syn::{
+	Attribute,
+	Expr,
+	Fields,
+	Ident,
+	LitByteStr,
+	LitStr,
+	Path,
+	Token,
+	Type,
+	WherePredicate,
+};
+use syn::punctuated::Punctuated;
+
+/// The `oct` attributes of a derived container.
+///
+/// These are set using the `#[oct(...)]` attribute on the structure or enumeration itself.
+/// Each derive macro only makes use of the fields relevant to it.
+#[derive(Default)]
+pub struct ContainerAttrs {
+	/// The message identifier, as set by `message_id`.
+	pub message_id: Option<Expr>,
+
+	/// The custom trait bounds, as set by `bound`.
+	///
+	/// These replace all inferred bounds.
+	pub bound: Option<Punctuated<WherePredicate, Token![,]>>,
+
+	/// The type of enumeration tags, as set by `tag`.
+	///
+	/// This overrides the representation given by `repr`.
+	pub tag: Option<Repr>,
+
+	/// Whether enumeration variants are length-prefixed, as set by `extensible`.
+	pub extensible: bool,
+
+	/// Whether structure fields are length-prefixed, as set by `versioned`.
+	///
+	/// Structures with versioned fields are always length-prefixed.
+	pub versioned: bool,
+
+	/// Whether a dedicated error type is generated, as set by `error = "generate"`.
+	pub generate_error: bool,
+
+	/// The custom validator function, as set by `validate`.
+	pub validate: Option<Path>,
+
+	/// The proxy type for decoding, as set by `from`.
+	pub from: Option<Type>,
+
+	/// The fallible proxy type for decoding, as set by `try_from`.
+	pub try_from: Option<Type>,
+
+	/// The proxy type for encoding, as set by `into`.
+	pub into: Option<Type>,
+
+	/// The magic value that prefixes encodings, as set by `magic`.
+	pub magic: Option<LitByteStr>,
+}
+
+impl ContainerAttrs {
+	/// Parses the `oct` attributes of a container.
+	///
+	/// # Errors
+	///
+	/// If any attribute is malformed or unknown, or if attributes are combined incorrectly, then an error spanned to the offending attribute is returned.
+	pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+		let mut this = Self::default();
+
+		for attr in attrs {
+			if attr.path().is_ident("oct") {
+				attr.parse_nested_meta(|meta| {
+					if meta.path.is_ident("message_id") {
+						this.message_id = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("bound") {
+						let bound: LitStr = meta.value()?.parse()?;
+
+						this.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+					} else if meta.path.is_ident("error") {
+						let mode: LitStr = meta.value()?.parse()?;
+
+						if mode.value() != "generate" {
+							return Err(meta.error("unknown error mode (expected \"generate\")"));
+						}
+
+						this.generate_error = true;
+					} else if meta.path.is_ident("extensible") {
+						this.extensible = true;
+					} else if meta.path.is_ident("versioned") {
+						this.versioned = true;
+					} else if meta.path.is_ident("validate") {
+						let path: LitStr = meta.value()?.parse()?;
+
+						this.validate = Some(path.parse()?);
+					} else if meta.path.is_ident("from") {
+						if this.try_from.is_some() {
+							return Err(meta.error("`from` and `try_from` cannot be combined"));
+						}
+
+						let ty: LitStr = meta.value()?.parse()?;
+
+						this.from = Some(ty.parse()?);
+					} else if meta.path.is_ident("try_from") {
+						if this.from.is_some() {
+							return Err(meta.error("`from` and `try_from` cannot be combined"));
+						}
+
+						let ty: LitStr = meta.value()?.parse()?;
+
+						this.try_from = Some(ty.parse()?);
+					} else if meta.path.is_ident("into") {
+						let ty: LitStr = meta.value()?.parse()?;
+
+						this.into = Some(ty.parse()?);
+					} else if meta.path.is_ident("magic") {
+						let magic: LitByteStr = meta.value()?.parse()?;
+
+						// Keep in sync with `MagicError::MAX_LEN`.
+						if magic.value().len() > 0x10 {
+							return Err(meta.error("magic values cannot be longer than 16 bytes"));
+						}
+
+						this.magic = Some(magic);
+					} else if meta.path.is_ident("tag") {
+						let ident: Ident = meta.value()?.parse()?;
+
+						this.tag = Some(Repr::from_ident(&ident)?);
+					} else {
+						return Err(meta.error("unknown container attribute"));
+					}
+
+					Ok(())
+				})?;
+			}
+
+			// Ignore all other attributes.
+		}
+
+		Ok(this)
+	}
+
+	/// Retrieves the `oct` attributes of a container.
+	///
+	/// # Panics
+	///
+	/// If the attributes are invalid, then this method will panic.
+	/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+	#[inline]
+	#[must_use]
+	pub fn get(attrs: &[Attribute]) -> Self {
+		Self::parse(attrs).expect("container attributes should have been checked")
+	}
+
+	/// Checks whether a structure with the given fields uses the versioned (length-prefixed) layout.
+	///
+	/// This is the case if the structure has the `versioned` attribute or if any field has the `since` attribute.
+	#[must_use]
+	pub fn is_versioned(&self, fields: &Fields) -> bool {
+		self.versioned || fields.iter().any(|field| FieldAttrs::get(&field.attrs).since.is_some())
+	}
+}
@@ -19,13 +19,28 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
+use crate::{Repr, VariantAttrs};
+
 use std::borrow::Borrow;
-use proc_macro2::Span;
-use syn::{Expr, Lit, LitInt, Variant};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	Expr,
+	ExprLit,
+	Lit,
+	LitInt,
+	Variant,
+	parse_quote,
+};
 
+/// An iterator over the tag values of enumeration variants.
+///
+/// Each value is yielded as a constant expression.
+/// Explicit values are taken from the `tag_value` attribute or, otherwise, from the variant's discriminant.
+/// Unspecified values increment the previous variant's value.
 pub struct Discriminants<I: IntoIterator<Item: Borrow<Variant>>> {
 	variants: I::IntoIter,
-	prev: Option<u128>,
+	prev: Option<(Option<Expr>, u128)>,
 }
 
 impl<I: IntoIterator<Item: Borrow<Variant>>> Discriminants<I> {
@@ -37,45 +52,84 @@ impl<I: IntoIterator<Item: Borrow<Variant>>> Discriminants<I> {
 			prev: None,
 		}
 	}
-}
 
-impl<I: IntoIterator<Item: Borrow<Variant>>> Iterator for Discriminants<I> {
-	type Item = LitInt;
+	/// Checks that the tag value of every variant can be determined.
+	///
+	/// # Errors
+	///
+	/// If an explicit value is not a valid literal, or if an implicit value overflows, then an error spanned to the offending variant is returned.
+	pub fn check(mut self) -> syn::Result<()> {
+		while let Some(discriminant) = self.try_next() {
+			discriminant?;
+		}
 
-	#[inline]
-	fn next(&mut self) -> Option<Self::Item> {
-		let variant = self.variants.next()?;
+		Ok(())
+	}
 
-		let discriminant = if let Some((_, ref expr)) = variant.borrow().discriminant {
-			let Expr::Lit(ref expr) = *expr else {
-				panic!("expected literal expression for discriminant value");
-			};
+	/// Determines the tag value of the next variant.
+	fn try_next(&mut self) -> Option<syn::Result<Expr>> {
+		let variant = self.variants.next()?;
+		let variant = variant.borrow();
 
-			let Lit::Int(ref expr) = expr.lit else {
-				panic!("expected (potentially signed) integer literal for discriminant value`");
-			};
+		Some(self.discriminant(variant))
+	}
 
-			let expr = expr.base10_digits();
+	/// Determines the tag value of the given variant, which follows the previous one.
+	fn discriminant(&mut self, variant: &Variant) -> syn::Result<Expr> {
+		let explicit = VariantAttrs::get(&variant.attrs)
+			.tag_value
+			.or_else(|| variant.discriminant.as_ref().map(|discriminant| discriminant.1.clone()));
 
-			let value: u128 = expr
-				.parse()
-				.or_else(|_| expr.parse::<i128>().map(|v| v as u128))
-				.unwrap();
+		// Literal values are kept as plain integers, whilst
+		// other expressions are used as a base for the fol-
+		// lowing variants.
+		let (base, offset) = if let Some(expr) = explicit {
+			if let Expr::Lit(ExprLit { lit: Lit::Int(ref lit), .. }) = expr {
+				let value: u128 = lit.base10_parse()?;
 
-			value
-		} else if let Some(prev) = self.prev {
-			prev
+				(None, value)
+			} else {
+				(Some(expr), 0x0)
+			}
+		} else if let Some((ref base, prev)) = self.prev {
+			let offset = prev
 				.checked_add(0x1)
-				.unwrap_or_else(|| panic!("overflow following discriminant `{prev:?}`"))
+				.ok_or_else(|| syn::Error::new_spanned(&variant.ident, format!("overflow following discriminant `{prev:?}`")))?;
+
+			(base.clone(), offset)
 		} else {
 			Default::default()
 		};
 
-		self.prev = Some(discriminant);
+		let literal = Expr::Lit(ExprLit {
+			attrs: Vec::new(),
+			lit:   Lit::Int(LitInt::new(&offset.to_string(), Span::call_site())),
+		});
+
+		let discriminant = match base {
+			Some(ref base) if offset == 0x0 => parse_quote! { (#base) },
+
+			Some(ref base) => parse_quote! { (#base) + #literal },
+
+			None => literal,
+		};
+
+		self.prev = Some((base, offset));
+
+		Ok(discriminant)
+	}
+}
 
-		let discriminant = LitInt::new(&discriminant.to_string(), Span::call_site());
+impl<I: IntoIterator<Item: Borrow<Variant>>> Iterator for Discriminants<I> {
+	type Item = Expr;
 
-		Some(discriminant)
+	/// # Panics
+	///
+	/// If the tag value cannot be determined, then this method will panic.
+	/// Derive macros [`check`](Self::check) the discriminants before expansion, so this does not happen in practice.
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.try_next().map(|discriminant| discriminant.expect("discriminants should have been checked"))
 	}
 
 	#[inline(always)]
@@ -83,3 +137,29 @@ impl<I: IntoIterator<Item: Borrow<Variant>>> Iterator for Discriminants<I> {
 		self.variants.size_hint()
 	}
 }
+
+/// Generates a compile-time check for duplicate discriminants.
+///
+/// The generated item fails to evaluate if any two discriminants are equal.
+#[must_use]
+pub fn check_discriminants(discriminants: &[Expr], repr: Repr) -> TokenStream {
+	let count = discriminants.len();
+
+	quote! {
+		const _: () = {
+			let discriminants: [#repr; #count] = [#(#discriminants, )*];
+
+			let mut i = 0x0usize;
+			while i < #count {
+				let mut j = i + 0x1;
+				while j < #count {
+					::core::assert!(discriminants[i] != discriminants[j], "enumeration has duplicate tag values");
+
+					j += 0x1;
+				}
+
+				i += 0x1;
+			}
+		};
+	}
+}
@@ -0,0 +1,541 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ContainerAttrs, FieldAttrs, Repr};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+	Data,
+	DeriveInput,
+	Field,
+	Ident,
+};
+
+/// The trait for which an error type is generated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+	Decode,
+	Encode,
+}
+
+impl ErrorKind {
+	#[inline]
+	#[must_use]
+	pub const fn to_str(self) -> &'static str {
+		match self {
+			Self::Decode => "Decode",
+			Self::Encode => "Encode",
+		}
+	}
+
+	#[inline]
+	#[must_use]
+	const fn verb(self) -> &'static str {
+		match self {
+			Self::Decode => "decoded",
+			Self::Encode => "encoded",
+		}
+	}
+}
+
+/// A generated error type.
+///
+/// This is emitted for types with the `error = "generate"` attribute, and contains one variant per encoded field.
+pub struct ErrorEnum {
+	/// The name of the error type.
+	pub ident: Ident,
+
+	kind: ErrorKind,
+
+	/// Whether the error type has an `Invalid` variant for failed validations of the container.
+	///
+	/// Failed validations of fields instead have their own variants (see [`validation_variant_name`](Self::validation_variant_name)).
+	validated: bool,
+
+	/// Whether the error type has a `BadMagic` variant for mismatched magic values and constants.
+	magic: bool,
+
+	/// Whether the error type has a `BadPadding` variant for non-zero padding.
+	padding: bool,
+
+	/// Whether the error type has a `BadTag` variant for mismatched external tags.
+	tagged: bool,
+
+	/// Whether the error type has a `BadLength` variant for mismatched external lengths.
+	len_checked: bool,
+
+	/// Whether the error type has a `BadPresence` variant for mismatched conditional fields.
+	conditional: bool,
+}
+
+impl ErrorEnum {
+	/// Constructs the error type for the given input, if it has the `error = "generate"` attribute.
+	///
+	/// The input must not be generic or use length prefixes, as is [checked](crate::check) before expansion.
+	#[must_use]
+	pub fn new(input: &DeriveInput, kind: ErrorKind) -> Option<Self> {
+		let attrs = ContainerAttrs::get(&input.attrs);
+
+		if !attrs.generate_error {
+			return None;
+		}
+
+		let ident = format_ident!("{}{}Error", input.ident, kind.to_str());
+
+		let field_attrs: Vec<FieldAttrs> = match input.data {
+			Data::Struct(ref data) => data.fields.iter().map(|field| FieldAttrs::get(&field.attrs)).collect(),
+
+			Data::Enum(ref data) => {
+				data.variants
+					.iter()
+					.flat_map(|variant| &variant.fields)
+					.map(|field| FieldAttrs::get(&field.attrs))
+					.collect()
+			},
+
+			Data::Union(..) => Vec::new(),
+		};
+
+		let validated = kind == ErrorKind::Decode && attrs.validate.is_some();
+
+		let magic = kind == ErrorKind::Decode
+			&& (attrs.magic.is_some() || field_attrs.iter().any(|attrs| attrs.constant.is_some()));
+
+		let padding = kind == ErrorKind::Decode && field_attrs.iter().any(|attrs| attrs.zeroed);
+
+		let tagged = kind == ErrorKind::Encode && field_attrs.iter().any(|attrs| attrs.tag_from.is_some());
+
+		let len_checked = kind == ErrorKind::Encode && field_attrs.iter().any(|attrs| attrs.len_from.is_some());
+
+		let conditional = kind == ErrorKind::Encode && field_attrs.iter().any(|attrs| attrs.condition.is_some());
+
+		Some(Self { ident, kind, validated, magic, padding, tagged, len_checked, conditional })
+	}
+
+	/// Names the error variant of the given field.
+	///
+	/// Named fields are converted to upper camel case, whilst unnamed fields are prefixed with `Field`.
+	/// For enumerations, the variant name is prefixed instead.
+	#[must_use]
+	pub fn variant_name(variant: Option<&Ident>, index: usize, field: &Field) -> Ident {
+		let field_name = field.ident.as_ref().map_or_else(
+			|| index.to_string(),
+			|ident| {
+				ident
+					.to_string()
+					.split('_')
+					.map(|word| {
+						let mut chars = word.chars();
+
+						chars.next().map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+					})
+					.collect()
+			},
+		);
+
+		let prefix = variant.map_or_else(
+			|| if field.ident.is_some() { String::new() } else { String::from("Field") },
+			ToString::to_string,
+		);
+
+		Ident::new(&format!("{prefix}{field_name}"), Span::call_site())
+	}
+
+	/// Names the error variant for failed validations of the given field.
+	///
+	/// This is the name of the field's own variant (see [`variant_name`](Self::variant_name)), prefixed with `Invalid`.
+	#[must_use]
+	pub fn validation_variant_name(variant: Option<&Ident>, index: usize, field: &Field) -> Ident {
+		format_ident!("Invalid{}", Self::variant_name(variant, index, field))
+	}
+
+	/// Generates the conversion of a field's error into the error type.
+	///
+	/// The returned tokens are to be chained onto the `Result` of encoding or decoding the field.
+	#[must_use]
+	pub fn map_field_err(&self, variant: Option<&Ident>, index: usize, field: &Field) -> TokenStream {
+		let ident        = &self.ident;
+		let variant_name = Self::variant_name(variant, index, field);
+
+		match self.field_error(field) {
+			FieldError::Native(_) => quote! { .map_err(#ident::#variant_name) },
+
+			FieldError::Generic(ty) => quote! {
+				.map_err(::core::convert::Into::<#ty>::into)
+				.map_err(#ident::#variant_name)
+			},
+		}
+	}
+
+	/// Generates the conversion of a validation error of the container into the error type.
+	#[must_use]
+	pub fn map_validation_err(&self) -> TokenStream {
+		let ident = &self.ident;
+
+		quote! { .map_err(#ident::Invalid) }
+	}
+
+	/// Generates the conversion of a validation error of a field into the error type.
+	#[must_use]
+	pub fn map_field_validation_err(&self, variant: Option<&Ident>, index: usize, field: &Field) -> TokenStream {
+		let ident        = &self.ident;
+		let variant_name = Self::validation_variant_name(variant, index, field);
+
+		quote! { .map_err(#ident::#variant_name) }
+	}
+
+	/// Generates the conversion of a magic error into the error type.
+	#[must_use]
+	pub fn map_magic_err(&self) -> TokenStream {
+		let ident = &self.ident;
+
+		quote! { .map_err(#ident::BadMagic) }
+	}
+
+	/// Generates the conversion of a padding error into the error type.
+	#[must_use]
+	pub fn map_padding_err(&self) -> TokenStream {
+		let ident = &self.ident;
+
+		quote! { .map_err(#ident::BadPadding) }
+	}
+
+	/// Generates the conversion of a tag mismatch error into the error type.
+	#[must_use]
+	pub fn map_tag_err(&self) -> TokenStream {
+		let ident = &self.ident;
+
+		quote! { .map_err(#ident::BadTag) }
+	}
+
+	/// Generates the conversion of a length mismatch error into the error type.
+	#[must_use]
+	pub fn map_len_err(&self) -> TokenStream {
+		let ident = &self.ident;
+
+		quote! { .map_err(#ident::BadLength) }
+	}
+
+	/// Generates the conversion of a presence error into the error type.
+	#[must_use]
+	pub fn map_presence_err(&self) -> TokenStream {
+		let ident = &self.ident;
+
+		quote! { .map_err(#ident::BadPresence) }
+	}
+
+	/// Generates the definition of the error type and its implementations.
+	#[must_use]
+	pub fn definition(&self, input: &DeriveInput) -> TokenStream {
+		let ident     = &self.ident;
+		let self_name = &input.ident;
+		let vis       = &input.vis;
+
+		let mut variants = Vec::new();
+		let mut paths    = Vec::new();
+		let mut tys      = Vec::new();
+		let mut docs     = Vec::new();
+
+		let mut invalid_variants = Vec::new();
+		let mut invalid_paths    = Vec::new();
+		let mut invalid_docs     = Vec::new();
+
+		let verb = self.kind.verb();
+
+		let mut push_fields = |variant: Option<&Ident>, fields: &syn::Fields| {
+			for (index, field) in fields.iter().enumerate() {
+				let attrs = FieldAttrs::get(&field.attrs);
+
+				if attrs.skip {
+					continue;
+				}
+
+				let field_name = field.ident.as_ref().map_or_else(|| index.to_string(), ToString::to_string);
+
+				let (path, doc) = variant.map_or_else(
+					|| (format!(".{field_name}"), format!("The `{field_name}` field could not be {verb}.")),
+					|variant| (format!("::{variant}.{field_name}"), format!("The `{field_name}` field of the `{variant}` variant could not be {verb}.")),
+				);
+
+				if self.kind == ErrorKind::Decode && attrs.is_validated() {
+					let doc = variant.map_or_else(
+						|| format!("The `{field_name}` field did not pass validation."),
+						|variant| format!("The `{field_name}` field of the `{variant}` variant did not pass validation."),
+					);
+
+					invalid_variants.push(Self::validation_variant_name(variant, index, field));
+					invalid_paths.push(path.clone());
+					invalid_docs.push(doc);
+				}
+
+				variants.push(Self::variant_name(variant, index, field));
+				paths.push(path);
+				tys.push(self.field_error(field).into_type());
+				docs.push(doc);
+			}
+		};
+
+		let mut extra = TokenStream::new();
+		let mut extra_path = TokenStream::new();
+		let mut extra_cause = TokenStream::new();
+		let mut extra_from = TokenStream::new();
+
+		match input.data {
+			Data::Struct(ref data) => push_fields(None, &data.fields),
+
+			Data::Enum(ref data) => {
+				for variant in &data.variants {
+					push_fields(Some(&variant.ident), &variant.fields);
+				}
+
+				let repr = Repr::resolve(&input.attrs, data);
+
+				match self.kind {
+					ErrorKind::Decode => {
+						extra = quote! {
+							/// An unassigned discriminant was encountered.
+							UnassignedDiscriminant {
+								/// The unassigned discriminant value.
+								value: #repr,
+							},
+						};
+
+						extra_path = quote! {
+							Self::UnassignedDiscriminant { .. } => ::core::result::Result::Ok(()),
+						};
+
+						extra_cause = quote! {
+							Self::UnassignedDiscriminant { value } => ::core::write!(f, "`{value}` is not an assigned discriminant"),
+						};
+
+						extra_from = quote! {
+							#ident::UnassignedDiscriminant { value } => {
+								::oct::error::EnumDecodeError::<#repr, ::core::convert::Infallible>::UnassignedDiscriminant { value }.into()
+							},
+						};
+					},
+
+					ErrorKind::Encode => {
+						extra = quote! {
+							/// The discriminant could not be encoded.
+							BadDiscriminant(<#repr as ::oct::encode::Encode>::Error),
+						};
+
+						extra_path = quote! {
+							Self::BadDiscriminant(..) => ::core::result::Result::Ok(()),
+						};
+
+						extra_cause = quote! {
+							Self::BadDiscriminant(ref e) => ::core::write!(f, "discriminant could not be encoded: {e}"),
+						};
+
+						extra_from = quote! {
+							#ident::BadDiscriminant(e) => e.into(),
+						};
+					},
+				}
+			},
+
+			Data::Union(..) => unreachable!(),
+		}
+
+		// Failed validations of fields keep their paths.
+		for ((variant, path), doc) in invalid_variants.iter().zip(&invalid_paths).zip(&invalid_docs) {
+			extra.extend(quote! {
+				#[doc = #doc]
+				#variant(::oct::error::ValidationError),
+			});
+
+			extra_path.extend(quote! {
+				Self::#variant(..) => ::core::write!(f, #path),
+			});
+
+			extra_cause.extend(quote! {
+				Self::#variant(ref e) => ::core::fmt::Display::fmt(e, f),
+			});
+
+			extra_from.extend(quote! {
+				#ident::#variant(e) => e.into(),
+			});
+		}
+
+		// Errors that are not specific to any one field.
+		let container_variants = [
+			(self.validated,   "Invalid",     quote! { ::oct::error::ValidationError },     "A decoded value did not pass validation."),
+			(self.magic,       "BadMagic",    quote! { ::oct::error::MagicError },          "A magic value or constant did not match."),
+			(self.padding,     "BadPadding",  quote! { ::oct::error::PaddingError },        "Padding or reserved bytes were not zero."),
+			(self.tagged,      "BadTag",      quote! { ::oct::error::TagMismatchError },    "An externally-tagged value did not match its tag field."),
+			(self.len_checked, "BadLength",   quote! { ::oct::error::LengthMismatchError }, "A collection did not match its length field."),
+			(self.conditional, "BadPresence", quote! { ::oct::error::PresenceError },       "A conditional field did not match its condition."),
+		];
+
+		for (enabled, variant, ty, doc) in container_variants {
+			if !enabled {
+				continue;
+			}
+
+			let variant = Ident::new(variant, Span::call_site());
+
+			extra.extend(quote! {
+				#[doc = #doc]
+				#variant(#ty),
+			});
+
+			extra_path.extend(quote! {
+				Self::#variant(..) => ::core::result::Result::Ok(()),
+			});
+
+			extra_cause.extend(quote! {
+				Self::#variant(ref e) => ::core::fmt::Display::fmt(e, f),
+			});
+
+			extra_from.extend(quote! {
+				#ident::#variant(e) => e.into(),
+			});
+		}
+
+		let generic_error = match self.kind {
+			ErrorKind::Decode => quote! { ::oct::error::GenericDecodeError },
+			ErrorKind::Encode => quote! { ::oct::error::GenericEncodeError },
+		};
+
+		let doc = format!("An error from {} [`{self_name}`].", match self.kind {
+			ErrorKind::Decode => "decoding",
+			ErrorKind::Encode => "encoding",
+		});
+
+		let self_name = self_name.to_string();
+
+		quote! {
+			#[doc = #doc]
+			#[derive(Debug)]
+			#[must_use]
+			#vis enum #ident {
+				#(
+					#[doc = #docs]
+					#variants(#tys),
+				)*
+
+				#extra
+			}
+
+			impl ::core::fmt::Display for #ident {
+				#[inline]
+				fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+					::core::write!(f, "`{}", #self_name)?;
+					::oct::error::FieldError::fmt_path(self, f)?;
+					::core::write!(f, "`: ")?;
+					::oct::error::FieldError::fmt_cause(self, f)
+				}
+			}
+
+			impl ::core::error::Error for #ident {
+				#[allow(unreachable_patterns)]
+				#[inline]
+				fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+					match *self {
+						#(Self::#variants(ref e) => ::core::option::Option::Some(e),)*
+
+						_ => ::core::option::Option::None,
+					}
+				}
+			}
+
+			impl ::oct::error::FieldError for #ident {
+				#[inline]
+				fn fmt_path(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+					use ::oct::error::{NestedError as _, NestedFieldError as _};
+
+					match *self {
+						#(
+							Self::#variants(ref e) => {
+								::core::write!(f, #paths)?;
+								(&::oct::error::Nested(e)).fmt_nested_path(f)
+							},
+						)*
+
+						#extra_path
+					}
+				}
+
+				#[inline]
+				fn fmt_cause(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+					use ::oct::error::{NestedError as _, NestedFieldError as _};
+
+					match *self {
+						#(Self::#variants(ref e) => (&::oct::error::Nested(e)).fmt_nested_cause(f),)*
+
+						#extra_cause
+					}
+				}
+			}
+
+			impl ::core::convert::From<#ident> for #generic_error {
+				#[inline]
+				fn from(value: #ident) -> Self {
+					match value {
+						#(#ident::#variants(e) => e.into(),)*
+
+						#extra_from
+					}
+				}
+			}
+		}
+	}
+
+	/// Determines the error type of a field.
+	fn field_error(&self, field: &Field) -> FieldError {
+		let attrs = FieldAttrs::get(&field.attrs);
+		let ty    = &field.ty;
+
+		match self.kind {
+			ErrorKind::Decode if attrs.decode_with.is_some() || attrs.bits.is_some() => FieldError::Generic(quote! { ::oct::error::GenericDecodeError }),
+
+			ErrorKind::Encode if attrs.encode_with.is_some() || attrs.bits.is_some() => FieldError::Generic(quote! { ::oct::error::GenericEncodeError }),
+
+			ErrorKind::Decode => FieldError::Native(quote! { <#ty as ::oct::decode::Decode>::Error }),
+
+			ErrorKind::Encode => FieldError::Native(quote! { <#ty as ::oct::encode::Encode>::Error }),
+		}
+	}
+}
+
+/// The error type of a field.
+enum FieldError {
+	/// The field's own error type.
+	Native(TokenStream),
+
+	/// A generic error type, used for custom encoders and decoders as well as for bit fields.
+	Generic(TokenStream),
+}
+
+impl FieldError {
+	#[inline]
+	#[must_use]
+	fn into_type(self) -> TokenStream {
+		match self {
+			| Self::Native(ty)
+			| Self::Generic(ty)
+			=> ty,
+		}
+	}
+}
@@ -0,0 +1,706 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::option_inner;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	Attribute,
+	Expr,
+	Ident,
+	LitInt,
+	LitStr,
+	Member,
+	Path,
+	Token,
+	Type,
+	WherePredicate,
+	parse_quote,
+};
+use syn::meta::ParseNestedMeta;
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+
+/// The `oct` attributes of a derived field.
+///
+/// These are set using the `#[oct(...)]` attribute on the individual fields of a structure or enumeration variant.
+#[derive(Default)]
+pub struct FieldAttrs {
+	/// Whether the field is omitted from encodings, as set by `skip`.
+	pub skip: bool,
+
+	/// The function for constructing a default value, as set by `default`.
+	pub default: Option<Path>,
+
+	/// The custom encoder function, as set by `encode_with` or `with`.
+	pub encode_with: Option<Path>,
+
+	/// The custom decoder function, as set by `decode_with` or `with`.
+	pub decode_with: Option<Path>,
+
+	/// The maximum encoded size of the field, as set by `max_size`.
+	pub max_size: Option<Expr>,
+
+	/// The custom trait bounds, as set by `bound`.
+	///
+	/// These replace the bounds inferred from the field's type.
+	pub bound: Option<Punctuated<WherePredicate, Token![,]>>,
+
+	/// The version in which the field was introduced, as set by `since`.
+	pub since: Option<u32>,
+
+	/// The custom validator function, as set by `validate`.
+	pub validate: Option<Path>,
+
+	/// The range of valid values, as set by `range`.
+	pub range: Option<TokenStream>,
+
+	/// The maximum length of the field, as set by `max_len`.
+	pub max_len: Option<Expr>,
+
+	/// The constant value of the field, as set by `constant`.
+	pub constant: Option<Expr>,
+
+	/// The amount of padding bytes before the field, as set by `pad`.
+	pub pad: Option<Expr>,
+
+	/// The alignment of the field, as set by `align`.
+	pub align: Option<Expr>,
+
+	/// Whether the field only reserves space, as set by `reserved`.
+	pub reserved: bool,
+
+	/// Whether padding and reserved bytes are verified as zero, as set by `zeroed`.
+	pub zeroed: bool,
+
+	/// The field providing the tag of this field, as set by `tag_from`.
+	pub tag_from: Option<Member>,
+
+	/// The field providing the length of this field, as set by `len_from`.
+	pub len_from: Option<Member>,
+
+	/// The condition for the presence of this field, as set by `if`.
+	pub condition: Option<TokenStream>,
+
+	/// The overridden representation of the field, as set by `be`, `le`, `varint`, or `as`.
+	pub int_repr: Option<IntRepr>,
+
+	/// The width of the field in bits, as set by `bits`.
+	pub bits: Option<u32>,
+}
+
+/// An overridden representation of a field.
+///
+/// Each representation corresponds to a module in `oct::repr`.
+pub enum IntRepr {
+	/// Big-endian, as set by `be`.
+	BigEndian,
+
+	/// Little-endian, as set by `le`.
+	LittleEndian,
+
+	/// Variable-length, as set by `varint`.
+	Varint,
+
+	/// Another type, as set by `as`.
+	Cast(Box<Type>),
+
+	/// A number of bytes, as set by `bytes` (optionally together with `be` or `le`).
+	Bytes {
+		width:      usize,
+		big_endian: bool,
+	},
+}
+
+impl IntRepr {
+	/// Retrieves the path to the module of the representation.
+	#[must_use]
+	fn module(&self) -> TokenStream {
+		match *self {
+			Self::BigEndian => quote! { ::oct::repr::be },
+
+			Self::LittleEndian => quote! { ::oct::repr::le },
+
+			Self::Varint => quote! { ::oct::repr::varint },
+
+			Self::Cast(_) => quote! { ::oct::repr::cast },
+
+			Self::Bytes { .. } => quote! { ::oct::repr::bytes },
+		}
+	}
+
+	/// Retrieves the expression for the maximum encoded size of a field of type `ty`.
+	#[must_use]
+	fn max_encoded_size(&self, ty: &Type) -> TokenStream {
+		match *self {
+			| Self::BigEndian
+			| Self::LittleEndian
+			=> quote! { <#ty as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE },
+
+			Self::Varint => quote! { <#ty as ::oct::repr::Varint>::MAX_VARINT_SIZE },
+
+			Self::Cast(ref target) => quote! { <#target as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE },
+
+			Self::Bytes { width, .. } => quote! { #width },
+		}
+	}
+}
+
+impl FieldAttrs {
+	/// Parses the `oct` attributes of a field.
+	///
+	/// # Errors
+	///
+	/// If any attribute is malformed or unknown, or if attributes are combined incorrectly, then an error spanned to the offending attribute is returned.
+	pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+		let mut this = Self::default();
+
+		// The byte order of byte-width fields may be given
+		// by `be` or `le`, so the two are merged afterwards.
+		let mut bytes = None;
+
+		for attr in attrs {
+			if attr.path().is_ident("oct") {
+				attr.parse_nested_meta(|meta| {
+					if meta.path.is_ident("skip") {
+						this.skip = true;
+					} else if meta.path.is_ident("default") {
+						let path: LitStr = meta.value()?.parse()?;
+
+						this.default = Some(path.parse()?);
+					} else if meta.path.is_ident("with") {
+						let path: LitStr = meta.value()?.parse()?;
+						let path: Path   = path.parse()?;
+
+						let mut encode_with = path.clone();
+						encode_with.segments.push(Ident::new("encode", Span::call_site()).into());
+
+						let mut decode_with = path;
+						decode_with.segments.push(Ident::new("decode", Span::call_site()).into());
+
+						this.encode_with = Some(encode_with);
+						this.decode_with = Some(decode_with);
+					} else if meta.path.is_ident("encode_with") {
+						let path: LitStr = meta.value()?.parse()?;
+
+						this.encode_with = Some(path.parse()?);
+					} else if meta.path.is_ident("decode_with") {
+						let path: LitStr = meta.value()?.parse()?;
+
+						this.decode_with = Some(path.parse()?);
+					} else if meta.path.is_ident("max_size") {
+						this.max_size = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("since") {
+						let since: LitInt = meta.value()?.parse()?;
+
+						this.since = Some(since.base10_parse()?);
+					} else if meta.path.is_ident("bound") {
+						let bound: LitStr = meta.value()?.parse()?;
+
+						this.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+					} else if meta.path.is_ident("validate") {
+						let path: LitStr = meta.value()?.parse()?;
+
+						this.validate = Some(path.parse()?);
+					} else if meta.path.is_ident("range") {
+						this.range = Some(parse_range(meta.value()?)?);
+					} else if meta.path.is_ident("max_len") {
+						this.max_len = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("constant") {
+						this.constant = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("pad") {
+						this.pad = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("align") {
+						this.align = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("reserved") {
+						this.reserved = true;
+					} else if meta.path.is_ident("zeroed") {
+						this.zeroed = true;
+					} else if meta.path.is_ident("tag_from") {
+						let member: LitStr = meta.value()?.parse()?;
+
+						this.tag_from = Some(member.parse()?);
+					} else if meta.path.is_ident("len_from") {
+						let member: LitStr = meta.value()?.parse()?;
+
+						this.len_from = Some(member.parse()?);
+					} else if meta.path.is_ident("be") {
+						set_int_repr(&mut this.int_repr, IntRepr::BigEndian, &meta)?;
+					} else if meta.path.is_ident("le") {
+						set_int_repr(&mut this.int_repr, IntRepr::LittleEndian, &meta)?;
+					} else if meta.path.is_ident("varint") {
+						set_int_repr(&mut this.int_repr, IntRepr::Varint, &meta)?;
+					} else if meta.path.is_ident("as") {
+						let ty: LitStr = meta.value()?.parse()?;
+
+						set_int_repr(&mut this.int_repr, IntRepr::Cast(Box::new(ty.parse()?)), &meta)?;
+					} else if meta.path.is_ident("bytes") {
+						let width: LitInt = meta.value()?.parse()?;
+						let width: usize  = width.base10_parse()?;
+
+						if !(0x1..=0x10).contains(&width) {
+							return Err(meta.error("byte-width fields must be between 1 and 16 bytes wide"));
+						}
+
+						bytes = Some(width);
+					} else if meta.path.is_ident("bits") {
+						let bits: LitInt = meta.value()?.parse()?;
+						let bits: u32    = bits.base10_parse()?;
+
+						if !(0x1..=u128::BITS).contains(&bits) {
+							return Err(meta.error("bit fields must be between 1 and 128 bits wide"));
+						}
+
+						this.bits = Some(bits);
+					} else if meta.path.is_ident("if") {
+						let condition: LitStr = meta.value()?.parse()?;
+
+						this.condition = Some(condition.parse()?);
+					} else {
+						return Err(meta.error("unknown field attribute"));
+					}
+
+					Ok(())
+				})?;
+			}
+
+			// Ignore all other attributes.
+		}
+
+		// Combinations are reported on all of the field's
+		// attributes, as they may be spread across several.
+		let oct_attrs: Vec<_> = attrs.iter().filter(|attr| attr.path().is_ident("oct")).collect();
+
+		let require = |condition: bool, message: &str| -> syn::Result<()> {
+			if condition {
+				Ok(())
+			} else {
+				Err(syn::Error::new_spanned(quote! { #(#oct_attrs)* }, message))
+			}
+		};
+
+		if let Some(width) = bytes {
+			this.int_repr = match this.int_repr {
+				None | Some(IntRepr::LittleEndian) => Some(IntRepr::Bytes { width, big_endian: false }),
+
+				Some(IntRepr::BigEndian) => Some(IntRepr::Bytes { width, big_endian: true }),
+
+				_ => return Err(syn::Error::new_spanned(quote! { #(#oct_attrs)* }, "byte-width fields can only be combined with `be` or `le`")),
+			};
+		}
+
+		// Overridden representations are implemented using
+		// the functions in `oct::repr`.
+		if let Some(ref int_repr) = this.int_repr {
+			require(
+				this.encode_with.is_none() && this.decode_with.is_none(),
+				"fields with overridden representations cannot have custom encoders or decoders",
+			)?;
+
+			let module = int_repr.module();
+
+			let (encode_with, decode_with): (Path, Path) = match *int_repr {
+				IntRepr::Cast(ref target) => (parse_quote! { #module::encode::<#target, _> }, parse_quote! { #module::decode::<#target, _> }),
+
+				IntRepr::Bytes { width, big_endian: false } => (parse_quote! { #module::encode::<_, #width> }, parse_quote! { #module::decode::<_, #width> }),
+
+				IntRepr::Bytes { width, big_endian: true } => (parse_quote! { #module::encode_be::<_, #width> }, parse_quote! { #module::decode_be::<_, #width> }),
+
+				_ => (parse_quote! { #module::encode }, parse_quote! { #module::decode }),
+			};
+
+			this.encode_with = Some(encode_with);
+			this.decode_with = Some(decode_with);
+		}
+
+		require(
+			this.default.is_none() || this.skip || this.since.is_some() || this.reserved,
+			"the `default` attribute requires the `skip`, `since`, or `reserved` attribute",
+		)?;
+
+		require(
+			!this.skip || (this.encode_with.is_none() && this.decode_with.is_none()),
+			"skipped fields cannot have custom encoders or decoders",
+		)?;
+
+		require(
+			!this.skip || !this.is_validated(),
+			"skipped fields cannot be validated",
+		)?;
+
+		require(
+			this.constant.is_none() || (!this.skip && this.encode_with.is_none() && this.decode_with.is_none()),
+			"constant fields cannot be skipped or have custom encoders or decoders",
+		)?;
+
+		require(
+			!this.skip || !this.is_padded(),
+			"skipped fields cannot be padded or reserved",
+		)?;
+
+		require(
+			!this.reserved || (this.constant.is_none() && this.encode_with.is_none() && this.decode_with.is_none()),
+			"reserved fields cannot be constant or have custom encoders or decoders",
+		)?;
+
+		require(
+			this.tag_from.is_none() || (!this.skip && !this.reserved && this.constant.is_none() && this.encode_with.is_none() && this.decode_with.is_none()),
+			"externally-tagged fields cannot be skipped, reserved, constant, or have custom encoders or decoders",
+		)?;
+
+		require(
+			this.len_from.is_none() || (this.tag_from.is_none() && !this.skip && !this.reserved && this.constant.is_none() && this.encode_with.is_none() && this.decode_with.is_none()),
+			"externally-sized fields cannot be externally tagged, skipped, reserved, constant, or have custom encoders or decoders",
+		)?;
+
+		require(
+			this.condition.is_none() || (this.tag_from.is_none() && this.len_from.is_none() && !this.skip && !this.reserved && this.constant.is_none() && this.encode_with.is_none() && this.decode_with.is_none()),
+			"conditional fields cannot be externally tagged or sized, skipped, reserved, constant, or have custom encoders or decoders",
+		)?;
+
+		require(
+			this.bits.is_none() || (this.condition.is_none() && this.tag_from.is_none() && this.len_from.is_none() && !this.skip && !this.is_padded() && this.constant.is_none() && this.since.is_none() && this.encode_with.is_none() && this.decode_with.is_none()),
+			"bit fields cannot be conditional, externally tagged or sized, skipped, padded, reserved, constant, versioned, or have custom encoders or decoders",
+		)?;
+
+		Ok(this)
+	}
+
+	/// Retrieves the `oct` attributes of a field.
+	///
+	/// # Panics
+	///
+	/// If the attributes are invalid, then this method will panic.
+	/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+	#[inline]
+	#[must_use]
+	pub fn get(attrs: &[Attribute]) -> Self {
+		Self::parse(attrs).expect("field attributes should have been checked")
+	}
+
+	/// Retrieves the expression for constructing the field's default value.
+	#[inline]
+	#[must_use]
+	pub fn default_value(&self) -> TokenStream {
+		self.default.as_ref().map_or_else(
+			|| quote! { ::core::default::Default::default() },
+			|path| quote! { #path() },
+		)
+	}
+
+	/// Retrieves the expression for encoding the field.
+	///
+	/// Constant fields encode their constant value instead of `value`, and reserved fields encode zeros.
+	/// The expression evaluates to a `Result`, the error type of which may be converted into `GenericEncodeError`.
+	#[inline]
+	#[must_use]
+	pub fn encode_command(&self, value: &Ident, ty: &Type) -> TokenStream {
+		if self.reserved {
+			return quote! {
+				{
+					stream.write(&[0x00; <#ty as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE]).unwrap();
+					::core::result::Result::<(), <#ty as ::oct::encode::Encode>::Error>::Ok(())
+				}
+			};
+		}
+
+		if let Some(ref constant) = self.constant {
+			return quote! { <#ty as ::oct::encode::Encode>::encode(&(#constant), stream) };
+		}
+
+		self.encode_with.as_ref().map_or_else(
+			|| quote! { ::oct::encode::Encode::encode(#value, stream) },
+			|path| quote! { #path(#value, stream) },
+		)
+	}
+
+	/// Retrieves the expression for decoding the field.
+	///
+	/// The expression evaluates to a `Result`, the error type of which may be converted into `GenericDecodeError`.
+	#[inline]
+	#[must_use]
+	pub fn decode_command(&self) -> TokenStream {
+		self.decode_with.as_ref().map_or_else(
+			|| quote! { ::oct::decode::Decode::decode(stream) },
+			|path| quote! { #path(stream) },
+		)
+	}
+
+	/// Checks whether the field is padded, aligned, or reserved.
+	#[inline]
+	#[must_use]
+	pub const fn is_padded(&self) -> bool {
+		self.pad.is_some() || self.align.is_some() || self.reserved
+	}
+
+	/// Checks whether the field has any validation attributes.
+	#[inline]
+	#[must_use]
+	pub const fn is_validated(&self) -> bool {
+		self.validate.is_some() || self.range.is_some() || self.max_len.is_some()
+	}
+
+	/// Wraps the given decoding command with the field's validation checks.
+	///
+	/// The checks evaluate to `Result`s with `ValidationError`, which are converted using `map_err`.
+	#[inline]
+	#[must_use]
+	pub fn validate_command(&self, ty: &Type, command: TokenStream, map_err: &TokenStream) -> TokenStream {
+		if !self.is_validated() {
+			return command;
+		}
+
+		let range = self.range.as_ref().map(|range| quote! {
+			(if ::core::ops::RangeBounds::contains(&(#range), &value) {
+				::core::result::Result::Ok(())
+			} else {
+				::core::result::Result::Err(::oct::error::ValidationError::OutOfRange)
+			})#map_err?;
+		});
+
+		let max_len = self.max_len.as_ref().map(|max_len| quote! {
+			(if value.len() <= (#max_len) {
+				::core::result::Result::Ok(())
+			} else {
+				::core::result::Result::Err(::oct::error::ValidationError::TooLong { max_len: #max_len, len: value.len() })
+			})#map_err?;
+		});
+
+		let validate = self.validate.as_ref().map(|path| quote! {
+			#path(&value)#map_err?;
+		});
+
+		quote! {
+			{
+				let value: #ty = #command;
+
+				#range
+				#max_len
+				#validate
+
+				value
+			}
+		}
+	}
+
+	/// Retrieves the expression for the maximum encoded size of the field.
+	///
+	/// # Panics
+	///
+	/// If the field has a custom encoder but no `max_size` attribute, then this method will panic.
+	/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+	#[inline]
+	#[must_use]
+	pub fn max_encoded_size(&self, ty: &Type) -> TokenStream {
+		// Alignment may at most require one byte less than
+		// the alignment itself.
+		let pad   = self.pad.as_ref().map(|pad| quote! { + (#pad) });
+		let align = self.align.as_ref().map(|align| quote! { + (#align) - 0x1 });
+
+		if let Some(ref max_size) = self.max_size {
+			return quote! { ((#max_size) #pad #align) };
+		}
+
+		if let Some(ref int_repr) = self.int_repr {
+			let size = int_repr.max_encoded_size(ty);
+
+			return quote! { ((#size) #pad #align) };
+		}
+
+		assert!(self.encode_with.is_none(), "fields with custom encoders require the `max_size` attribute for deriving `SizedEncode`");
+
+		// Conditional fields do not encode the sign of their
+		// `Option`.
+		let ty = if self.condition.is_some() { option_inner(ty) } else { ty };
+
+		quote! { (<#ty as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE #pad #align) }
+	}
+
+	/// Retrieves the expression for the fingerprint of the field.
+	///
+	/// Fields with custom encoders or decoders are fingerprinted by the paths of these.
+	/// Externally-tagged and externally-sized fields additionally include their referenced field, and conditional fields include their condition.
+	/// Padding and reservations are also included.
+	#[inline]
+	#[must_use]
+	pub fn fingerprint(&self, ty: &Type) -> TokenStream {
+		let fingerprint = self.value_fingerprint(ty);
+
+		if !self.is_padded() {
+			return fingerprint;
+		}
+
+		let pad      = self.pad.as_ref().map_or_else(|| quote! { 0x0 }, |pad| quote! { #pad });
+		let align    = self.align.as_ref().map_or_else(|| quote! { 0x0 }, |align| quote! { #align });
+		let reserved = self.reserved;
+
+		quote! {
+			::oct::fingerprint::FingerprintHasher::new()
+				.write(b"padded")
+				.write_u64((#pad) as u64)
+				.write_u64((#align) as u64)
+				.write(&[#reserved as u8])
+				.write_u64(#fingerprint)
+				.finish()
+		}
+	}
+
+	/// Retrieves the expression for the fingerprint of the field's value.
+	#[inline]
+	#[must_use]
+	fn value_fingerprint(&self, ty: &Type) -> TokenStream {
+		if let Some(bits) = self.bits {
+			return quote! {
+				::oct::fingerprint::FingerprintHasher::new()
+					.write(b"bits")
+					.write_u64(#bits as u64)
+					.write_u64(<#ty as ::oct::fingerprint::Fingerprint>::FINGERPRINT)
+					.finish()
+			};
+		}
+
+		if let Some(ref constant) = self.constant {
+			let constant = quote! { #constant }.to_string();
+
+			return quote! {
+				::oct::fingerprint::FingerprintHasher::new()
+					.write(b"constant")
+					.write(#constant.as_bytes())
+					.write_u64(<#ty as ::oct::fingerprint::Fingerprint>::FINGERPRINT)
+					.finish()
+			};
+		}
+
+		if let Some(ref condition) = self.condition {
+			let inner     = option_inner(ty);
+			let condition = condition.to_string();
+
+			return quote! {
+				::oct::fingerprint::FingerprintHasher::new()
+					.write(b"if")
+					.write(#condition.as_bytes())
+					.write_u64(<#inner as ::oct::fingerprint::Fingerprint>::FINGERPRINT)
+					.finish()
+			};
+		}
+
+		let external = self
+			.tag_from
+			.as_ref()
+			.map(|member| (quote! { b"tag_from" }, member))
+			.or_else(|| self.len_from.as_ref().map(|member| (quote! { b"len_from" }, member)));
+
+		if let Some((attr, member)) = external {
+			let member = quote! { #member }.to_string();
+
+			return quote! {
+				::oct::fingerprint::FingerprintHasher::new()
+					.write(#attr)
+					.write(#member.as_bytes())
+					.write_u64(<#ty as ::oct::fingerprint::Fingerprint>::FINGERPRINT)
+					.finish()
+			};
+		}
+
+		if self.encode_with.is_none() && self.decode_with.is_none() {
+			return quote! { <#ty as ::oct::fingerprint::Fingerprint>::FINGERPRINT };
+		}
+
+		// Overridden representations are implemented using
+		// custom encoders, but their encodings also depend
+		// on the field type (and on the target type of
+		// casts).
+		if let Some(ref int_repr) = self.int_repr {
+			let encode_with = self.encode_with.as_ref().map(|path| quote! { #path }.to_string()).unwrap_or_default();
+
+			let target = if let IntRepr::Cast(ref target) = *int_repr {
+				quote! { <#target as ::oct::fingerprint::Fingerprint>::FINGERPRINT }
+			} else {
+				quote! { 0x0 }
+			};
+
+			return quote! {
+				::oct::fingerprint::FingerprintHasher::new()
+					.write(b"repr")
+					.write(#encode_with.as_bytes())
+					.write_u64(<#ty as ::oct::fingerprint::Fingerprint>::FINGERPRINT)
+					.write_u64(#target)
+					.finish()
+			};
+		}
+
+		let encode_with = self.encode_with.as_ref().map(|path| quote! { #path }.to_string()).unwrap_or_default();
+		let decode_with = self.decode_with.as_ref().map(|path| quote! { #path }.to_string()).unwrap_or_default();
+
+		quote! {
+			::oct::fingerprint::FingerprintHasher::new()
+				.write(b"with")
+				.write(#encode_with.as_bytes())
+				.write(#decode_with.as_bytes())
+				.finish()
+		}
+	}
+}
+
+/// Sets the overridden representation of a field.
+///
+/// # Errors
+///
+/// If a representation has already been set, then an error spanned to `meta` is returned.
+fn set_int_repr(int_repr: &mut Option<IntRepr>, value: IntRepr, meta: &ParseNestedMeta) -> syn::Result<()> {
+	if int_repr.is_some() {
+		return Err(meta.error("fields can have at most one of `be`, `le`, `varint`, and `as`"));
+	}
+
+	*int_repr = Some(value);
+
+	Ok(())
+}
+
+/// Parses a range expression (e.g. `0..=100`).
+///
+/// Range expressions are not supported by `syn` without the `full` feature, so their bounds are parsed individually.
+fn parse_range(input: ParseStream) -> syn::Result<TokenStream> {
+	let end_of_range = |input: ParseStream| input.is_empty() || input.peek(Token![,]);
+
+	let start: Option<Expr> = if input.peek(Token![..]) {
+		None
+	} else {
+		Some(input.parse()?)
+	};
+
+	let limits = if input.peek(Token![..=]) {
+		let limits: Token![..=] = input.parse()?;
+		quote! { #limits }
+	} else {
+		let limits: Token![..] = input.parse()?;
+		quote! { #limits }
+	};
+
+	let end: Option<Expr> = if end_of_range(input) {
+		None
+	} else {
+		Some(input.parse()?)
+	};
+
+	Ok(quote! { #start #limits #end })
+}
@@ -0,0 +1,69 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::FieldAttrs;
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::{Fields, Ident, Member};
+
+/// Resolves the index of a field referenced by another field (e.g. using `tag_from` or `len_from`).
+///
+/// # Errors
+///
+/// If the referenced field does not exist, if it is not declared before the field at `index`, or if it is not encoded as a plain value, then an error spanned to `member` is returned.
+pub fn referenced_index(fields: &Fields, index: usize, member: &Member) -> syn::Result<usize> {
+	let referenced_index = fields
+		.members()
+		.position(|other| other == *member)
+		.ok_or_else(|| syn::Error::new_spanned(member, format!("referenced field `{}` does not exist", quote! { #member })))?;
+
+	if referenced_index >= index {
+		return Err(syn::Error::new_spanned(member, "referenced fields must be declared before the fields referencing them"));
+	}
+
+	let attrs = fields
+		.iter()
+		.nth(referenced_index)
+		.map(|field| FieldAttrs::get(&field.attrs))
+		.unwrap_or_default();
+
+	if attrs.skip || attrs.reserved || attrs.constant.is_some() {
+		return Err(syn::Error::new_spanned(member, "referenced fields cannot be skipped, reserved, or constant"));
+	}
+
+	Ok(referenced_index)
+}
+
+/// Resolves the capture of a field referenced by another field.
+///
+/// The referenced field is bound to `value{N}`, where `N` is its index.
+///
+/// # Panics
+///
+/// If the reference is invalid (see [`referenced_index`]), then this function will panic.
+/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+#[must_use]
+pub fn referenced_capture(fields: &Fields, index: usize, member: &Member) -> Ident {
+	let referenced_index = referenced_index(fields, index, member).expect("field references should have been checked");
+
+	Ident::new(&format!("value{referenced_index}"), Span::call_site())
+}
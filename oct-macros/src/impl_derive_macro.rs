@@ -19,54 +19,72 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GenericName, Repr};
+use crate::{
+	ContainerAttrs,
+	Derive,
+	FieldAttrs,
+	GenericName,
+	Repr,
+};
 
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, quote};
 use syn::{
 	Data,
 	DataEnum,
 	DataStruct,
 	DeriveInput,
+	Field,
+	GenericParam,
+	Ident,
 	Path,
 	Token,
+	Type,
+	WherePredicate,
+	parse_quote,
 };
 
 pub fn impl_derive_macro<S, E>(
 	input:          DeriveInput,
+	derive:         Derive,
 	trait_path:     Path,
 	r#unsafe_token: Option<Token![unsafe]>,
+	field_bound:    Option<TokenStream>,
 	struct_body:    S,
 	enum_body:      E,
 ) -> TokenStream
 where
 	S: FnOnce(DataStruct)     -> TokenStream,
-	E: FnOnce(DataEnum, Repr) -> TokenStream,
+	E: FnOnce(DataEnum, Repr, &ContainerAttrs) -> TokenStream,
 {
-	let trait_name = &trait_path
-		.segments
-		.last()
-		.expect("expected non-empty path for derived trait")
-		.ident;
-
 	let self_name = &input.ident;
 
+	let mut generics = input.generics.clone();
+
+	if let Some(ref field_bound) = field_bound {
+		let predicates = bounds(&input, derive, field_bound);
+
+		generics.make_where_clause().predicates.extend(predicates);
+	}
+
+	let attrs = ContainerAttrs::get(&input.attrs);
+
 	let body = match input.data {
 		Data::Struct(data) => struct_body(data),
 
 		Data::Enum(data) => {
-			let repr = Repr::get(&input.attrs).unwrap_or_default();
+			let repr = Repr::resolve(&input.attrs, &data);
 
-			enum_body(data, repr)
+			enum_body(data, repr, &attrs)
 		}
 
-		Data::Union(..) => panic!("unions cannot derive `{trait_name:?}`"),
+		Data::Union(..) => unreachable!("unions should have been checked"),
 	};
 
-	let generic_params = &input.generics.params;
-	let generic_where  = &input.generics.where_clause;
+	let generic_params = &generics.params;
+	let generic_where  = &generics.where_clause;
 
-	let generic_names = GenericName::extract_from(&input.generics);
+	let generic_names = GenericName::extract_from(&generics);
 
 	let output = quote! {
 		#unsafe_token impl<#generic_params> #trait_path for #self_name<#generic_names>
@@ -77,4 +95,99 @@ where
 	};
 
 	output
-}
\ No newline at end of file
+}
+
+/// Determines the trait bounds of a derived implementation.
+///
+/// If the container specifies the `bound` attribute, then only the given bounds are used.
+/// Otherwise, each type parameter used by an encoded field is bound by `field_bound`, unless the field specifies its own bounds.
+fn bounds(input: &DeriveInput, derive: Derive, field_bound: &TokenStream) -> Vec<WherePredicate> {
+	if let Some(bound) = ContainerAttrs::get(&input.attrs).bound {
+		return bound.into_iter().collect();
+	}
+
+	let fields: Vec<&Field> = match input.data {
+		Data::Struct(ref data) => data.fields.iter().collect(),
+
+		Data::Enum(ref data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+
+		Data::Union(..) => Vec::new(),
+	};
+
+	let params: Vec<&Ident> = input.generics.params.iter().filter_map(|param| {
+		if let GenericParam::Type(ref param) = *param {
+			Some(&param.ident)
+		} else {
+			None
+		}
+	}).collect();
+
+	let mut predicates = Vec::new();
+	let mut bound_params = Vec::new();
+
+	for field in fields {
+		let attrs = FieldAttrs::get(&field.attrs);
+
+		if let Some(bound) = attrs.bound {
+			predicates.extend(bound);
+			continue;
+		}
+
+		// Fields that are not encoded using their own im-
+		// plementations do not impose any bounds.
+		if attrs.skip || is_exempt(&attrs, derive) || is_phantom(&field.ty) {
+			continue;
+		}
+
+		for &param in &params {
+			if !bound_params.contains(&param) && mentions(field.ty.to_token_stream(), param) {
+				bound_params.push(param);
+			}
+		}
+	}
+
+	predicates.extend(bound_params.into_iter().map(|param| -> WherePredicate {
+		parse_quote! { #param: #field_bound }
+	}));
+
+	predicates
+}
+
+/// Tests if a type is `PhantomData`.
+/// Checks whether the derived trait is implemented for a field without using the field type's own implementation.
+fn is_exempt(attrs: &FieldAttrs, derive: Derive) -> bool {
+	match derive {
+		Derive::Decode => attrs.bits.is_some() || attrs.decode_with.is_some(),
+
+		Derive::Encode => attrs.bits.is_some() || attrs.encode_with.is_some(),
+
+		Derive::FixedEncode | Derive::SizedEncode => attrs.bits.is_some() || attrs.max_size.is_some(),
+
+		// Fingerprints of bit fields and of fields with over-
+		// ridden representations include that of their type,
+		// whilst those of custom encoders only include the
+		// paths to the encoders.
+		Derive::Fingerprint => attrs.int_repr.is_none() && (attrs.encode_with.is_some() || attrs.decode_with.is_some()),
+
+		Derive::Message => true,
+	}
+}
+
+fn is_phantom(ty: &Type) -> bool {
+	let Type::Path(ref ty) = *ty else {
+		return false;
+	};
+
+	ty.path.segments.last().is_some_and(|segment| segment.ident == "PhantomData")
+}
+
+/// Tests if a token stream mentions the given identifier.
+pub fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+	tokens.into_iter().any(|token| match token {
+		TokenTree::Ident(ref other) => other == ident,
+
+		TokenTree::Group(ref group) => mentions(group.stream(), ident),
+
+		_ => false,
+	})
+}
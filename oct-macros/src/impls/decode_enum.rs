@@ -19,64 +19,48 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Discriminants, Repr};
+use crate::{ContainerAttrs, ErrorEnum, Repr, decode_magic};
 
 use proc_macro2::TokenStream;
-use quote::quote;
-use std::iter;
-use syn::{DataEnum, Fields};
+use quote::{ToTokens, quote};
 
 #[must_use]
-pub fn decode_enum(data: DataEnum, repr: Repr) -> TokenStream {
-	let discriminants: Vec<_> = Discriminants::new(&data.variants).collect();
+pub fn decode_enum(repr: Repr, attrs: &ContainerAttrs, error: Option<&ErrorEnum>) -> TokenStream {
+	let error_ty = error.map_or_else(
+		|| quote! { ::oct::error::EnumDecodeError<#repr, ::oct::error::GenericDecodeError> },
+		|error| error.ident.to_token_stream(),
+	);
 
-	let values = data
-		.variants
-		.into_iter()
-		.map(|variant| {
-			let variant_name = variant.ident;
+	let map_magic_err = error.map_or_else(
+		|| quote! {
+			.map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into)
+			.map_err(::oct::error::EnumDecodeError::BadField)
+		},
+		ErrorEnum::map_magic_err,
+	);
 
-			let commands = iter::repeat_n(
-				quote! {
-					::oct::decode::Decode::decode(stream)
-						.map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into)
-						.map_err(::oct::error::EnumDecodeError::BadField)?
-				},
-				variant.fields.len(),
-			);
+	let magic = attrs.magic.as_ref().map(|magic| decode_magic(magic, &map_magic_err));
 
-			match variant.fields {
-				Fields::Unit => quote! { Self::#variant_name },
-
-				Fields::Unnamed(_fields) => quote! { Self::#variant_name (#(#commands, )*) },
-
-				Fields::Named(fields) => {
-					let field_names = fields
-						.named
-						.into_iter()
-						.map(|field| field.ident.unwrap());
-
-					quote! { Self::#variant_name { #(#field_names: #commands, )* } }
-				},
-			}
-		});
+	// Discriminants cannot fail to decode, so generated
+	// error types have no variant for it.
+	let invalid = if error.is_some() {
+		quote! { .map_err(|e| match e {}) }
+	} else {
+		quote! { .map_err(::oct::error::EnumDecodeError::InvalidDiscriminant) }
+	};
 
 	quote! {
-		type Error = ::oct::error::EnumDecodeError<#repr, ::oct::error::GenericDecodeError>;
+		type Error = #error_ty;
 
 		#[inline]
 		fn decode(stream: &mut ::oct::decode::Input) -> ::core::result::Result<Self, Self::Error> {
+			#magic
+
 			let discriminant = <#repr as ::oct::decode::Decode>::decode(stream)
 				.map_err(::core::convert::Into::<::core::convert::Infallible>::into)
-				.map_err(::oct::error::EnumDecodeError::InvalidDiscriminant)?;
-
-			let this = match discriminant {
-				#(#discriminants => #values,)*
-
-				value => return ::core::result::Result::Err(::oct::error::EnumDecodeError::UnassignedDiscriminant { value }),
-			};
+				#invalid?;
 
-			::core::result::Result::Ok(this)
+			<Self as ::oct::decode::DecodeTagged>::decode_tagged(discriminant, stream)
 		}
 	}
 }
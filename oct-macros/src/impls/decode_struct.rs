@@ -19,25 +19,122 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{DataStruct, Fields};
-use std::iter;
+use crate::{
+	BitSlot,
+	ContainerAttrs,
+	ErrorEnum,
+	FieldAttrs,
+	alignment_start,
+	bit_slots,
+	condition,
+	decode_conditional,
+	decode_constant,
+	decode_magic,
+	decode_padded,
+	decode_prefixed,
+	decode_reserved,
+	decode_tagged,
+	decode_unprefixed,
+	referenced_capture,
+};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{DataStruct, Fields, Ident};
 
 #[must_use]
-pub fn decode_struct(data: DataStruct) -> TokenStream {
-	let commands = iter::repeat_n(
-		quote! {
-			::oct::decode::Decode::decode(stream)
-				.map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into)?
-		},
-		data.fields.len(),
-	);
+pub fn decode_struct(data: DataStruct, attrs: &ContainerAttrs, error: Option<&ErrorEnum>) -> TokenStream {
+	let map_generic_err = quote! { .map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into) };
+
+	let map_validation_err = error.map_or_else(|| map_generic_err.clone(), ErrorEnum::map_validation_err);
+	let map_magic_err      = error.map_or_else(|| map_generic_err.clone(), ErrorEnum::map_magic_err);
+	let map_padding_err    = error.map_or_else(|| map_generic_err.clone(), ErrorEnum::map_padding_err);
+
+	let start = alignment_start(&data.fields);
+
+	let slots = bit_slots(&data.fields);
+
+	let versioned = attrs.is_versioned(&data.fields);
+
+	let commands: Vec<_> = data
+		.fields
+		.iter()
+		.enumerate()
+		.map(|(index, field)| {
+			let attrs = FieldAttrs::get(&field.attrs);
+
+			if attrs.skip {
+				return attrs.default_value();
+			}
+
+			let command = if attrs.reserved {
+				decode_reserved(&attrs, &field.ty, &map_padding_err)
+			} else if let Some(ref constant) = attrs.constant {
+				decode_constant(&field.ty, constant, &map_magic_err)
+			} else {
+				// Bit fields are unpacked from the buffer of their
+				// group. Externally-tagged and -sized fields take
+				// their tag or length from an earlier field, and
+				// conditional fields are only present if their
+				// condition holds.
+				let command = if let Some(ref slot) = slots[index] {
+					slot.decode(&field.ty)
+				} else if let Some(ref tag_from) = attrs.tag_from {
+					decode_tagged(&field.ty, &referenced_capture(&data.fields, index, tag_from))
+				} else if let Some(ref len_from) = attrs.len_from {
+					decode_unprefixed(&field.ty, &referenced_capture(&data.fields, index, len_from))
+				} else if let Some(ref cond) = attrs.condition {
+					decode_conditional(&field.ty, &condition(&data.fields, index, cond, true))
+				} else {
+					attrs.decode_command()
+				};
+
+				let map_err = error.map_or_else(
+					|| quote! { .map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into) },
+					|error| error.map_field_err(None, index, field),
+				);
+
+				let map_field_validation_err = error.map_or_else(
+					|| map_generic_err.clone(),
+					|error| error.map_field_validation_err(None, index, field),
+				);
+
+				attrs.validate_command(&field.ty, quote! { #command #map_err? }, &map_field_validation_err)
+			};
+
+			let command = decode_padded(&attrs, command, &map_padding_err);
+
+			// Versioned fields that are missing from the body
+			// are assumed to be from an older version.
+			if attrs.since.is_some() {
+				let default = attrs.default_value();
+
+				return quote! {
+					if stream.remaining() == 0x0 { #default } else { #command }
+				};
+			}
+
+			command
+		})
+		.collect();
+
+	// The buffer of each bit-field group is read before
+	// its first field.
+	let bits_starts: Vec<_> = slots
+		.iter()
+		.map(|slot| slot.as_ref().and_then(BitSlot::decode_start))
+		.collect();
+
+	// Fields are decoded into locals first, so that they
+	// may be referenced by later fields.
+	let captures: Vec<_> = (0x0..commands.len())
+		.map(|index| Ident::new(&format!("value{index}"), Span::call_site()))
+		.collect();
 
 	let value = match data.fields {
 		Fields::Unit => quote! { Self },
 
-		Fields::Unnamed(_fields) => quote! { Self (#(#commands, )*) },
+		Fields::Unnamed(_fields) => quote! { Self (#(#captures, )*) },
 
 		Fields::Named(fields) => {
 			let field_names = fields
@@ -45,16 +142,37 @@ pub fn decode_struct(data: DataStruct) -> TokenStream {
 				.into_iter()
 				.map(|field| field.ident.unwrap());
 
-			quote! { Self { #(#field_names: #commands, )* } }
+			quote! { Self { #(#field_names: #captures, )* } }
 		},
 	};
 
+	let magic = attrs.magic.as_ref().map(|magic| decode_magic(magic, &map_magic_err));
+
+	let payload = versioned.then(decode_prefixed);
+
+	let validate = attrs.validate.as_ref().map(|path| quote! {
+		#path(&this)#map_validation_err?;
+	});
+
+	let error = error.map_or_else(
+		|| quote! { ::oct::error::GenericDecodeError },
+		|error| error.ident.to_token_stream(),
+	);
+
 	quote! {
-		type Error = ::oct::error::GenericDecodeError;
+		type Error = #error;
 
 		#[inline]
 		fn decode(stream: &mut ::oct::decode::Input) -> ::core::result::Result<Self, Self::Error> {
+			#magic
+			#payload
+			#start
+
+			#(#bits_starts let #captures = #commands;)*
+
 			let this = #value;
+			#validate
+
 			::core::result::Result::Ok(this)
 		}
 	}
@@ -0,0 +1,191 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	ContainerAttrs,
+	Discriminants,
+	ErrorEnum,
+	FieldAttrs,
+	Repr,
+	VariantAttrs,
+	alignment_start,
+	check_discriminants,
+	decode_constant,
+	decode_padded,
+	decode_prefixed,
+	decode_reserved,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, Fields};
+
+#[must_use]
+pub fn decode_tagged_enum(data: DataEnum, repr: Repr, attrs: &ContainerAttrs, error: Option<&ErrorEnum>) -> TokenStream {
+	let discriminants: Vec<_> = Discriminants::new(&data.variants).collect();
+
+	let mut arms = Vec::new();
+
+	let unassigned = error.map_or_else(
+		|| quote! { ::oct::error::EnumDecodeError::UnassignedDiscriminant },
+		|error| {
+			let ident = &error.ident;
+
+			quote! { #ident::UnassignedDiscriminant }
+		},
+	);
+
+	let map_generic_err = quote! {
+		.map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into)
+		.map_err(::oct::error::EnumDecodeError::BadField)
+	};
+
+	let map_validation_err = error.map_or_else(|| map_generic_err.clone(), ErrorEnum::map_validation_err);
+	let map_magic_err      = error.map_or_else(|| map_generic_err.clone(), ErrorEnum::map_magic_err);
+	let map_padding_err    = error.map_or_else(|| map_generic_err.clone(), ErrorEnum::map_padding_err);
+
+	let mut fallback = quote! {
+		value => return ::core::result::Result::Err(#unassigned { value })
+	};
+
+	let mut assigned = Vec::new();
+
+	for (variant, discriminant) in data.variants.into_iter().zip(discriminants) {
+		let variant_name = variant.ident;
+
+		// Unknown variants are captured along with their
+		// raw payload.
+		if VariantAttrs::get(&variant.attrs).other {
+			let values = [
+				quote! { value },
+				quote! { ::core::convert::From::from(payload) },
+			];
+
+			let value = match variant.fields {
+				Fields::Named(fields) => {
+					let field_names = fields
+						.named
+						.into_iter()
+						.map(|field| field.ident.unwrap());
+
+					quote! { Self::#variant_name { #(#field_names: #values, )* } }
+				},
+
+				_ => quote! { Self::#variant_name (#(#values, )*) },
+			};
+
+			fallback = quote! { value => #value };
+
+			continue;
+		}
+
+		let commands: Vec<_> = variant
+			.fields
+			.iter()
+			.enumerate()
+			.map(|(index, field)| {
+				let attrs = FieldAttrs::get(&field.attrs);
+
+				if attrs.skip {
+					return attrs.default_value();
+				}
+
+				let command = if attrs.reserved {
+					decode_reserved(&attrs, &field.ty, &map_padding_err)
+				} else if let Some(ref constant) = attrs.constant {
+					decode_constant(&field.ty, constant, &map_magic_err)
+				} else {
+					let command = attrs.decode_command();
+
+					let map_err = error.map_or_else(
+						|| quote! {
+							.map_err(::core::convert::Into::<::oct::error::GenericDecodeError>::into)
+							.map_err(::oct::error::EnumDecodeError::BadField)
+						},
+						|error| error.map_field_err(Some(&variant_name), index, field),
+					);
+
+					let map_field_validation_err = error.map_or_else(
+						|| map_generic_err.clone(),
+						|error| error.map_field_validation_err(Some(&variant_name), index, field),
+					);
+
+					attrs.validate_command(&field.ty, quote! { #command #map_err? }, &map_field_validation_err)
+				};
+
+				decode_padded(&attrs, command, &map_padding_err)
+			})
+			.collect();
+
+		let start = alignment_start(&variant.fields);
+
+		let value = match variant.fields {
+			Fields::Unit => quote! { Self::#variant_name },
+
+			Fields::Unnamed(_fields) => quote! { Self::#variant_name (#(#commands, )*) },
+
+			Fields::Named(fields) => {
+				let field_names = fields
+					.named
+					.into_iter()
+					.map(|field| field.ident.unwrap());
+
+				quote! { Self::#variant_name { #(#field_names: #commands, )* } }
+			},
+		};
+
+		arms.push(quote! { value if value == #discriminant => { #start #value } });
+
+		assigned.push(discriminant);
+	}
+
+	let check = check_discriminants(&assigned, repr);
+
+	// The payloads of extensible variants are decoded
+	// from their own stream, so that any trailing data
+	// (e.g. from newer fields) is skipped.
+	let payload = attrs.extensible.then(decode_prefixed);
+
+	let validate = attrs.validate.as_ref().map(|path| quote! {
+		#path(&this)#map_validation_err?;
+	});
+
+	quote! {
+		type Tag = #repr;
+
+		#[inline]
+		fn decode_tagged(discriminant: Self::Tag, stream: &mut ::oct::decode::Input) -> ::core::result::Result<Self, <Self as ::oct::decode::Decode>::Error> {
+			#check
+
+			#payload
+
+			let this = match discriminant {
+				#(#arms,)*
+
+				#fallback,
+			};
+
+			#validate
+
+			::core::result::Result::Ok(this)
+		}
+	}
+}
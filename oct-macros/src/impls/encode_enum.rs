@@ -19,76 +19,40 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Discriminants, Repr};
+use crate::{ContainerAttrs, ErrorEnum, Repr, encode_magic};
 
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::{DataEnum, Fields, Ident, LitInt};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
 
 #[must_use]
-pub fn encode_enum(data: DataEnum, repr: Repr) -> TokenStream {
-	let discriminants: Vec<LitInt> = Discriminants::new(&data.variants).collect();
+pub fn encode_enum(repr: Repr, attrs: &ContainerAttrs, error: Option<&ErrorEnum>) -> TokenStream {
+	let (error_ty, bad_discriminant) = error.map_or_else(
+		|| (
+			quote! { ::oct::error::EnumEncodeError<#repr, ::oct::error::GenericEncodeError> },
+			quote! { ::oct::error::EnumEncodeError::BadDiscriminant },
+		),
+		|error| {
+			let ident = &error.ident;
 
-	let captures: Vec<Vec<Ident>> = data
-		.variants
-		.iter()
-		.map(|variant| {
-			variant
-				.fields
-				.iter()
-				.enumerate()
-				.map(|(index, _)| Ident::new(&format!("value{index}"), Span::call_site()))
-				.collect()
-		})
-		.collect();
+			(ident.to_token_stream(), quote! { #ident::BadDiscriminant })
+		},
+	);
 
-	let patterns = data
-		.variants
-		.into_iter()
-		.zip(&captures)
-		.map(|(variant, captures)| {
-			let variant_name = variant.ident;
-
-			match variant.fields {
-				Fields::Unit => quote! { Self::#variant_name },
-
-				Fields::Unnamed(_fields) => quote! { Self::#variant_name (#(ref #captures, )*) },
-
-				Fields::Named(fields) => {
-					let field_names = fields
-						.named
-						.into_iter()
-						.map(|field| field.ident.unwrap());
-
-					quote! { Self::#variant_name { #(#field_names: ref #captures, )* } }
-				},
-			}
-		});
+	let magic = attrs.magic.as_ref().map(encode_magic);
 
 	quote! {
-		type Error = ::oct::error::EnumEncodeError<#repr, ::oct::error::GenericEncodeError>;
+		type Error = #error_ty;
 
-		#[allow(unreachable_patterns)]
 		#[inline]
 		fn encode(&self, stream: &mut ::oct::encode::Output) -> ::core::result::Result<(), Self::Error> {
-			match *self {
-				#(
-					#patterns => {
-						<#repr as ::oct::encode::Encode>::encode(&#discriminants, stream)
-							.map_err(::oct::error::EnumEncodeError::BadDiscriminant)?;
+			#magic
 
-						#(
-							::oct::encode::Encode::encode(#captures, stream)
-								.map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into)
-								.map_err(::oct::error::EnumEncodeError::BadField)?;
-						)*
-					}
-				)*
+			let discriminant = <Self as ::oct::encode::EncodeTagged>::tag(self);
 
-				_ => ::core::unreachable!("no variants defined for this enumeration"),
-			}
+			<#repr as ::oct::encode::Encode>::encode(&discriminant, stream)
+				.map_err(#bad_discriminant)?;
 
-			::core::result::Result::Ok(())
+			<Self as ::oct::encode::EncodeTagged>::encode_untagged(self, stream)
 		}
 	}
 }
@@ -19,23 +19,120 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
+use crate::{
+	ContainerAttrs,
+	ErrorEnum,
+	FieldAttrs,
+	alignment_start,
+	bit_slots,
+	condition,
+	encode_conditional,
+	encode_magic,
+	encode_padding,
+	encode_prefixed,
+	encode_tagged,
+	encode_unprefixed,
+	referenced_capture,
+};
+
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{ToTokens, quote};
 use syn::{DataStruct, Fields, Ident};
 
 #[must_use]
-pub fn encode_struct(data: DataStruct) -> TokenStream {
+pub fn encode_struct(data: DataStruct, attrs: &ContainerAttrs, error: Option<&ErrorEnum>) -> TokenStream {
+	let versioned = attrs.is_versioned(&data.fields);
+
+	let start = alignment_start(&data.fields);
+
+	let slots = bit_slots(&data.fields);
+
 	let captures: Vec<_> = data
 		.fields
 		.iter()
 		.enumerate()
-		.map(|(index, _)| Ident::new(&format!("value{index}"), Span::call_site()))
+		.map(|(index, field)| {
+			let attrs = FieldAttrs::get(&field.attrs);
+
+			(!attrs.skip).then(|| {
+				// The values of constant and reserved fields are
+				// ignored.
+				let capture = if attrs.constant.is_some() || attrs.reserved {
+					Ident::new(&format!("_value{index}"), Span::call_site())
+				} else {
+					Ident::new(&format!("value{index}"), Span::call_site())
+				};
+
+				// Bit fields are packed into the buffer of their
+				// group, which is written after its last field.
+				// Externally-tagged and -sized fields are encoded
+				// without their tag or length, which must match
+				// the referenced field. Likewise, the presence of
+				// conditional fields must match their condition.
+				let command = if let Some(ref slot) = slots[index] {
+					slot.encode(&field.ty, &capture)
+				} else if let Some(ref tag_from) = attrs.tag_from {
+					let map_err = error.map_or_else(
+						|| quote! { .map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into) },
+						ErrorEnum::map_tag_err,
+					);
+
+					encode_tagged(&field.ty, &capture, &referenced_capture(&data.fields, index, tag_from), &map_err)
+				} else if let Some(ref len_from) = attrs.len_from {
+					let map_err = error.map_or_else(
+						|| quote! { .map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into) },
+						ErrorEnum::map_len_err,
+					);
+
+					encode_unprefixed(&field.ty, &capture, &referenced_capture(&data.fields, index, len_from), &map_err)
+				} else if let Some(ref cond) = attrs.condition {
+					let map_err = error.map_or_else(
+						|| quote! { .map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into) },
+						ErrorEnum::map_presence_err,
+					);
+
+					encode_conditional(&field.ty, &capture, &condition(&data.fields, index, cond, false), &map_err)
+				} else {
+					attrs.encode_command(&capture, &field.ty)
+				};
+
+				let map_err = error.map_or_else(
+					|| quote! { .map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into) },
+					|error| error.map_field_err(None, index, field),
+				);
+
+				let (bits_start, bits_end) = slots[index]
+					.as_ref()
+					.map_or((None, None), |slot| (slot.encode_start(), slot.encode_end()));
+
+				let padding = encode_padding(&attrs);
+				let command = quote! { #bits_start #padding #command #map_err?; #bits_end };
+
+				(capture, command)
+			})
+		})
+		.collect();
+
+	let bindings: Vec<_> = captures
+		.iter()
+		.map(|capture| {
+			capture
+				.as_ref()
+				.map(|capture| &capture.0)
+				.map_or_else(|| quote! { _ }, |capture| quote! { ref #capture })
+		})
+		.collect();
+
+	let commands: Vec<_> = captures
+		.into_iter()
+		.flatten()
+		.map(|(_, command)| command)
 		.collect();
 
 	let pattern = match data.fields {
 		Fields::Unit => quote! { Self },
 
-		Fields::Unnamed(_fields) => quote! { Self(#(ref #captures, )*) },
+		Fields::Unnamed(_fields) => quote! { Self(#(#bindings, )*) },
 
 		Fields::Named(fields) => {
 			let field_names = fields
@@ -43,21 +140,39 @@ pub fn encode_struct(data: DataStruct) -> TokenStream {
 				.into_iter()
 				.map(|field| field.ident.unwrap());
 
-			quote! { Self { #(#field_names: ref #captures, )* } }
+			quote! { Self { #(#field_names: #bindings, )* } }
 		},
 	};
 
+	let body = quote! {
+		#start
+		#(#commands)*
+	};
+
+	// Versioned structures are prefixed with the length
+	// of their body.
+	let body = if versioned {
+		encode_prefixed(body, &TokenStream::new())
+	} else {
+		body
+	};
+
+	let magic = attrs.magic.as_ref().map(encode_magic);
+
+	let error = error.map_or_else(
+		|| quote! { ::oct::error::GenericEncodeError },
+		|error| error.ident.to_token_stream(),
+	);
+
 	quote! {
-		type Error = ::oct::error::GenericEncodeError;
+		type Error = #error;
 
 		#[inline]
 		fn encode(&self, stream: &mut ::oct::encode::Output) -> ::core::result::Result<(), Self::Error> {
 			let #pattern = self;
 
-			#(
-				::oct::encode::Encode::encode(#captures, stream)
-					.map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into)?;
-			)*
+			#magic
+			#body
 
 			::core::result::Result::Ok(())
 		}
@@ -0,0 +1,207 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	ContainerAttrs,
+	Discriminants,
+	ErrorEnum,
+	FieldAttrs,
+	Repr,
+	VariantAttrs,
+	alignment_start,
+	check_discriminants,
+	encode_padding,
+	encode_prefixed,
+};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+	DataEnum,
+	Expr,
+	Fields,
+	Ident,
+};
+
+#[must_use]
+pub fn encode_tagged_enum(data: DataEnum, repr: Repr, attrs: &ContainerAttrs, error: Option<&ErrorEnum>) -> TokenStream {
+	let discriminants: Vec<Expr> = Discriminants::new(&data.variants).collect();
+
+	let check = {
+		let discriminants: Vec<_> = data
+			.variants
+			.iter()
+			.zip(&discriminants)
+			.filter(|&(variant, _)| !VariantAttrs::get(&variant.attrs).other)
+			.map(|(_, discriminant)| discriminant.clone())
+			.collect();
+
+		check_discriminants(&discriminants, repr)
+	};
+
+	let mut tags = Vec::new();
+
+	let arms: Vec<_> = data
+		.variants
+		.into_iter()
+		.zip(discriminants)
+		.map(|(variant, discriminant)| {
+			let variant_name = variant.ident;
+
+			let other = VariantAttrs::get(&variant.attrs).other;
+
+			let start = alignment_start(&variant.fields);
+
+			let captures: Vec<_> = variant
+				.fields
+				.iter()
+				.enumerate()
+				.map(|(index, field)| {
+					let attrs = FieldAttrs::get(&field.attrs);
+
+					(other || !attrs.skip).then(|| {
+						// The values of constant and reserved fields are
+						// ignored, as are the tags of unknown variants.
+						let capture = if attrs.constant.is_some() || attrs.reserved || (other && index == 0x0) {
+							Ident::new(&format!("_value{index}"), Span::call_site())
+						} else {
+							Ident::new(&format!("value{index}"), Span::call_site())
+						};
+
+						let command = attrs.encode_command(&capture, &field.ty);
+
+						let map_err = error.map_or_else(
+							|| quote! {
+								.map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into)
+								.map_err(::oct::error::EnumEncodeError::BadField)
+							},
+							|error| error.map_field_err(Some(&variant_name), index, field),
+						);
+
+						let padding = encode_padding(&attrs);
+						let command = quote! { #padding #command #map_err?; };
+
+						(capture, command)
+					})
+				})
+				.collect();
+
+			let bindings = captures
+				.iter()
+				.map(|capture| {
+					capture
+						.as_ref()
+						.map(|capture| &capture.0)
+						.map_or_else(|| quote! { _ }, |capture| quote! { ref #capture })
+				});
+
+			// The tags of unknown variants are stored in their
+			// first field.
+			let tag_member = variant.fields.members().next();
+
+			let pattern = match variant.fields {
+				Fields::Unit => quote! { Self::#variant_name },
+
+				Fields::Unnamed(_fields) => quote! { Self::#variant_name (#(#bindings, )*) },
+
+				Fields::Named(fields) => {
+					let field_names = fields
+						.named
+						.into_iter()
+						.map(|field| field.ident.unwrap());
+
+					quote! { Self::#variant_name { #(#field_names: #bindings, )* } }
+				},
+			};
+
+			let (captures, commands): (Vec<_>, Vec<_>) = captures.into_iter().flatten().unzip();
+
+			// Unknown variants are re-encoded from their raw
+			// tag and payload.
+			if other {
+				let payload = &captures[0x1];
+
+				tags.push(quote! { Self::#variant_name { #tag_member: tag, .. } => tag });
+
+				return quote! {
+					#pattern => {
+						let payload: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(#payload);
+
+						<usize as ::oct::encode::Encode>::encode(&payload.len(), stream)
+							.map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into)
+							.map_err(::oct::error::EnumEncodeError::BadField)?;
+
+						stream.write(payload).unwrap();
+					}
+				};
+			}
+
+			let fields = quote! {
+				#start
+				#(#commands)*
+			};
+
+			// Extensible variants are prefixed with the length
+			// of their payload.
+			let fields = if attrs.extensible {
+				encode_prefixed(fields, &quote! { .map_err(::oct::error::EnumEncodeError::BadField) })
+			} else {
+				fields
+			};
+
+			tags.push(quote! { Self::#variant_name { .. } => #discriminant });
+
+			quote! {
+				#pattern => {
+					#fields
+				}
+			}
+		})
+		.collect();
+
+	quote! {
+		type Tag = #repr;
+
+		#[allow(unreachable_patterns)]
+		#[inline]
+		fn tag(&self) -> Self::Tag {
+			#check
+
+			match *self {
+				#(#tags,)*
+
+				_ => ::core::unreachable!("no variants defined for this enumeration"),
+			}
+		}
+
+		#[allow(unreachable_patterns)]
+		#[inline]
+		fn encode_untagged(&self, stream: &mut ::oct::encode::Output) -> ::core::result::Result<(), <Self as ::oct::encode::Encode>::Error> {
+			match *self {
+				#(#arms)*
+
+				_ => ::core::unreachable!("no variants defined for this enumeration"),
+			}
+
+			::core::result::Result::Ok(())
+		}
+	}
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	ContainerAttrs,
+	Discriminants,
+	FieldAttrs,
+	Repr,
+	VariantAttrs,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DataEnum;
+
+#[must_use]
+pub fn fingerprint_enum(data: DataEnum, repr: Repr, attrs: &ContainerAttrs) -> TokenStream {
+	// Variants capturing unknown variants do not have an
+	// encoding of their own.
+	let (variants, discriminants): (Vec<_>, Vec<_>) = data
+		.variants
+		.iter()
+		.zip(Discriminants::new(&data.variants))
+		.filter(|&(variant, _)| !VariantAttrs::get(&variant.attrs).other)
+		.unzip();
+
+	let fingerprints: Vec<Vec<_>> = variants
+		.iter()
+		.map(|variant| {
+			variant
+				.fields
+				.iter()
+				.filter_map(|field| {
+					let attrs = FieldAttrs::get(&field.attrs);
+
+					(!attrs.skip).then(|| attrs.fingerprint(&field.ty))
+				})
+				.collect()
+		})
+		.collect();
+
+	let field_counts = fingerprints
+		.iter()
+		.map(|fingerprints| fingerprints.len() as u64);
+
+	let kind = attrs.extensible.then(|| quote! { .write(b"extensible") });
+
+	let magic = attrs.magic.as_ref().map(|magic| quote! { .write(b"magic").write(#magic) });
+
+	quote! {
+		const FINGERPRINT: u64 = ::oct::fingerprint::FingerprintHasher::new()
+			.write(b"enum")
+			#kind
+			#magic
+			.write_u64(<#repr as ::oct::fingerprint::Fingerprint>::FINGERPRINT)
+			#(
+				.write_u128((#discriminants) as u128)
+				.write_u64(#field_counts)
+				#(.write_u64(#fingerprints))*
+			)*
+			.finish();
+	}
+}
@@ -0,0 +1,77 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ContainerAttrs, FieldAttrs};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DataStruct;
+
+#[must_use]
+pub fn fingerprint_struct(data: DataStruct, attrs: &ContainerAttrs) -> TokenStream {
+	let versioned = attrs.is_versioned(&data.fields);
+
+	// Versioned fields are included together with their
+	// versions.
+	let fingerprints: Vec<_> = data.fields
+		.into_iter()
+		.filter_map(|field| {
+			let attrs = FieldAttrs::get(&field.attrs);
+
+			if attrs.skip {
+				return None;
+			}
+
+			let fingerprint = attrs.fingerprint(&field.ty);
+
+			let Some(since) = attrs.since else {
+				return Some(fingerprint);
+			};
+
+			Some(quote! {
+				::oct::fingerprint::FingerprintHasher::new()
+					.write(b"since")
+					.write_u64(#since as u64)
+					.write_u64(#fingerprint)
+					.finish()
+			})
+		})
+		.collect();
+
+	// Unversioned structures share the fingerprint of the
+	// equivalent tuple, as they are encoded identically.
+
+	let kind = if versioned {
+		quote! { b"versioned" }
+	} else {
+		quote! { b"tuple" }
+	};
+
+	let magic = attrs.magic.as_ref().map(|magic| quote! { .write(b"magic").write(#magic) });
+
+	quote! {
+		const FINGERPRINT: u64 = ::oct::fingerprint::FingerprintHasher::new()
+			.write(#kind)
+			#magic
+			#(.write_u64(#fingerprints))*
+			.finish();
+	}
+}
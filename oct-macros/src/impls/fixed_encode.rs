@@ -0,0 +1,131 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ContainerAttrs, FieldAttrs, IntRepr};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	Data,
+	DeriveInput,
+	Field,
+	WherePredicate,
+	parse_quote,
+};
+
+/// Generates the bounds on the types of all encoded fields.
+///
+/// A type is only fixed-size if all of its fields are, so the bounds apply to concrete types as well.
+#[must_use]
+pub fn fixed_encode_bounds(input: &DeriveInput) -> Vec<WherePredicate> {
+	let attrs = ContainerAttrs::get(&input.attrs);
+
+	if let Some(bound) = attrs.bound {
+		return bound.into_iter().collect();
+	}
+
+	if let Some(proxy) = attrs.into {
+		return vec![parse_quote! { #proxy: ::oct::encode::FixedEncode }];
+	}
+
+	let fields: Vec<&Field> = match input.data {
+		Data::Struct(ref data) => data.fields.iter().collect(),
+
+		Data::Enum(ref data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+
+		Data::Union(..) => Vec::new(),
+	};
+
+	let mut predicates = Vec::new();
+
+	for field in fields {
+		let attrs = FieldAttrs::get(&field.attrs);
+
+		if let Some(bound) = attrs.bound {
+			predicates.extend(bound);
+			continue;
+		}
+
+		// Bit fields are packed into groups of fixed sizes,
+		// and byte-width fields have fixed sizes themselves.
+		if attrs.skip || attrs.bits.is_some() || matches!(attrs.int_repr, Some(IntRepr::Bytes { .. })) {
+			continue;
+		}
+
+		let ty = match attrs.int_repr {
+			Some(IntRepr::Cast(ref target)) => &**target,
+
+			_ => &field.ty,
+		};
+
+		predicates.push(parse_quote! { #ty: ::oct::encode::FixedEncode });
+	}
+
+	predicates
+}
+
+/// Generates an assertion that all variants of an enumeration have the same size.
+///
+/// The tag (and, for extensible enumerations, the length prefix) is shared between all variants and is therefore not included.
+#[must_use]
+pub fn fixed_encode_assertion(input: &DeriveInput) -> Option<TokenStream> {
+	let Data::Enum(ref data) = input.data else {
+		return None;
+	};
+
+	if ContainerAttrs::get(&input.attrs).into.is_some() {
+		return None;
+	}
+
+	let sizes: Vec<Vec<_>> = data
+		.variants
+		.iter()
+		.map(|variant| {
+			variant
+				.fields
+				.iter()
+				.filter_map(|field| {
+					let attrs = FieldAttrs::get(&field.attrs);
+
+					(!attrs.skip).then(|| attrs.max_encoded_size(&field.ty))
+				})
+				.collect()
+		})
+		.collect();
+
+	let message = format!("all variants of `{}` must have the same encoded size to implement `FixedEncode`", input.ident);
+
+	let output = quote! {
+		const _: () = {
+			let sizes: &[usize] = &[#(0x0 #(+ #sizes)*),*];
+
+			let mut i = 0x0;
+
+			while i < sizes.len() {
+				::core::assert!(sizes[i] == sizes[0x0], #message);
+
+				i += 0x1;
+			}
+		};
+	};
+
+	Some(output)
+}
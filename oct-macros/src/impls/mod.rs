@@ -19,10 +19,16 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use crate::use_mod;
 use_mod!(pub decode_enum);
 use_mod!(pub decode_struct);
+use_mod!(pub decode_tagged_enum);
 use_mod!(pub encode_enum);
 use_mod!(pub encode_struct);
+use_mod!(pub encode_tagged_enum);
+use_mod!(pub fingerprint_enum);
+use_mod!(pub fingerprint_struct);
+use_mod!(pub fixed_encode);
+use_mod!(pub message);
+use_mod!(pub proxy);
 use_mod!(pub sized_encode_enum);
 use_mod!(pub sized_encode_struct);
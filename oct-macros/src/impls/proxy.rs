@@ -0,0 +1,114 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::ContainerAttrs;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the body of `Decode` for types with the `from` or `try_from` attributes.
+#[must_use]
+pub fn decode_proxy(attrs: &ContainerAttrs) -> Option<TokenStream> {
+	let body = match (attrs.from.as_ref(), attrs.try_from.as_ref()) {
+		(Some(proxy), _) => quote! {
+			type Error = <#proxy as ::oct::decode::Decode>::Error;
+
+			#[inline]
+			fn decode(stream: &mut ::oct::decode::Input) -> ::core::result::Result<Self, Self::Error> {
+				let proxy = <#proxy as ::oct::decode::Decode>::decode(stream)?;
+
+				let this = <Self as ::core::convert::From<#proxy>>::from(proxy);
+				::core::result::Result::Ok(this)
+			}
+		},
+
+		(None, Some(proxy)) => quote! {
+			type Error = ::oct::error::ProxyDecodeError<
+				<#proxy as ::oct::decode::Decode>::Error,
+				<Self as ::core::convert::TryFrom<#proxy>>::Error,
+			>;
+
+			#[inline]
+			fn decode(stream: &mut ::oct::decode::Input) -> ::core::result::Result<Self, Self::Error> {
+				let proxy = <#proxy as ::oct::decode::Decode>::decode(stream)
+					.map_err(::oct::error::ProxyDecodeError::BadProxy)?;
+
+				let this = <Self as ::core::convert::TryFrom<#proxy>>::try_from(proxy)
+					.map_err(::oct::error::ProxyDecodeError::BadConversion)?;
+
+				::core::result::Result::Ok(this)
+			}
+		},
+
+		(None, None) => return None,
+	};
+
+	Some(body)
+}
+
+/// Generates the body of `Encode` for types with the `into` attribute.
+#[must_use]
+pub fn encode_proxy(attrs: &ContainerAttrs) -> Option<TokenStream> {
+	let proxy = attrs.into.as_ref()?;
+
+	let body = quote! {
+		type Error = <#proxy as ::oct::encode::Encode>::Error;
+
+		#[inline]
+		fn encode(&self, stream: &mut ::oct::encode::Output) -> ::core::result::Result<(), Self::Error> {
+			let proxy: #proxy = ::core::convert::Into::into(::core::clone::Clone::clone(self));
+
+			<#proxy as ::oct::encode::Encode>::encode(&proxy, stream)
+		}
+	};
+
+	Some(body)
+}
+
+/// Generates the body of `SizedEncode` for types with the `into` attribute.
+#[must_use]
+pub fn sized_encode_proxy(attrs: &ContainerAttrs) -> Option<TokenStream> {
+	let proxy = attrs.into.as_ref()?;
+
+	let body = quote! {
+		const MAX_ENCODED_SIZE: usize = <#proxy as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE;
+	};
+
+	Some(body)
+}
+
+/// Generates the body of `Fingerprint` for types with proxies.
+///
+/// The fingerprint is that of the `into` type, or otherwise that of the `from` or `try_from` type.
+#[must_use]
+pub fn fingerprint_proxy(attrs: &ContainerAttrs) -> Option<TokenStream> {
+	let proxy = attrs
+		.into
+		.as_ref()
+		.or(attrs.from.as_ref())
+		.or(attrs.try_from.as_ref())?;
+
+	let body = quote! {
+		const FINGERPRINT: u64 = <#proxy as ::oct::fingerprint::Fingerprint>::FINGERPRINT;
+	};
+
+	Some(body)
+}
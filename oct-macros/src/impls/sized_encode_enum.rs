@@ -19,7 +19,11 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use crate::Repr;
+use crate::{
+	ContainerAttrs,
+	FieldAttrs,
+	Repr,
+};
 
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
@@ -27,20 +31,42 @@ use std::iter;
 use syn::DataEnum;
 
 #[must_use]
-pub fn sized_encode_enum(data: DataEnum, repr: Repr) -> TokenStream {
-	let tys: Vec<Vec<_>> = data
+pub fn sized_encode_enum(data: DataEnum, repr: Repr, attrs: &ContainerAttrs) -> TokenStream {
+	// Extensible variants are additionally prefixed with
+	// their length.
+	let repr_size = {
+		let repr = repr.to_type(Span::call_site());
+
+		if attrs.extensible {
+			quote! { <#repr as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE + <usize as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE }
+		} else {
+			quote! { <#repr as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE }
+		}
+	};
+
+	let sizes: Vec<Vec<_>> = data
 		.variants
 		.iter()
 		.map(|variant| {
 			variant
 				.fields
 				.iter()
-				.map(|field| field.ty.clone())
-				.chain(iter::once(repr.to_type(Span::call_site())))
+				.filter_map(|field| {
+					let attrs = FieldAttrs::get(&field.attrs);
+
+					(!attrs.skip).then(|| attrs.max_encoded_size(&field.ty))
+				})
+				.chain(iter::once(repr_size.clone()))
 				.collect()
 		})
 		.collect();
 
+	let magic_size = attrs.magic.as_ref().map(|magic| {
+		let len = magic.value().len();
+
+		quote! { + #len }
+	});
+
 	quote! {
 		const MAX_ENCODED_SIZE: usize = {
 			let mut total_size = 0x0usize;
@@ -48,12 +74,12 @@ pub fn sized_encode_enum(data: DataEnum, repr: Repr) -> TokenStream {
 			let mut current_size = 0x0usize;
 
 			#(
-				current_size = 0x0 #(+ <#tys as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE)*;
+				current_size = 0x0 #(+ #sizes)*;
 
 				if current_size > total_size { total_size = current_size };
 			)*
 
-			total_size
+			total_size #magic_size
 		};
 	}
 }
@@ -19,18 +19,40 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
+use crate::{ContainerAttrs, FieldAttrs, bits_size};
+
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::DataStruct;
 
 #[must_use]
-pub fn sized_encode_struct(data: DataStruct) -> TokenStream {
-	let tys: Vec<_> = data.fields
+pub fn sized_encode_struct(data: DataStruct, attrs: &ContainerAttrs) -> TokenStream {
+	let versioned = attrs.is_versioned(&data.fields);
+
+	// Bit fields are packed into groups, the sizes of
+	// which are known up front.
+	let bits_size = bits_size(&data.fields);
+
+	let sizes: Vec<_> = data.fields
 		.into_iter()
-		.map(|field| field.ty)
+		.filter_map(|field| {
+			let attrs = FieldAttrs::get(&field.attrs);
+
+			(!attrs.skip && attrs.bits.is_none()).then(|| attrs.max_encoded_size(&field.ty))
+		})
 		.collect();
 
+	// Versioned structures are prefixed with the length
+	// of their body.
+	let prefix = versioned.then(|| quote! { + <usize as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE });
+
+	let magic_size = attrs.magic.as_ref().map(|magic| {
+		let len = magic.value().len();
+
+		quote! { + #len }
+	});
+
 	quote! {
-		const MAX_ENCODED_SIZE: usize = 0x0 #( + <#tys as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE)*;
+		const MAX_ENCODED_SIZE: usize = 0x0 #magic_size #prefix + #bits_size #( + #sizes)*;
 	}
 }
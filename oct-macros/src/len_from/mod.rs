@@ -0,0 +1,55 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Type};
+
+/// Decodes a collection using the value of its length field.
+///
+/// The expression evaluates to a `Result`, the error type of which is that of the field's `Decode` implementation.
+#[must_use]
+pub fn decode_unprefixed(ty: &Type, len: &Ident) -> TokenStream {
+	quote! {
+		<#ty as ::oct::decode::DecodeUnprefixed>::decode_unprefixed(
+			::core::convert::Into::<usize>::into(::core::clone::Clone::clone(&#len)),
+			stream,
+		)
+	}
+}
+
+/// Encodes a collection without its length.
+///
+/// The length of `value` is first checked against the value of its length field (the capture `len`), with mismatches being converted using `map_err`.
+/// The expression evaluates to a `Result`, the error type of which is that of the field's `Encode` implementation.
+#[must_use]
+pub fn encode_unprefixed(ty: &Type, value: &Ident, len: &Ident, map_err: &TokenStream) -> TokenStream {
+	quote! {
+		{
+			::oct::error::LengthMismatchError::check(
+				::core::convert::Into::<usize>::into(::core::clone::Clone::clone(#len)),
+				<#ty as ::oct::encode::EncodeUnprefixed>::encoded_len(#value),
+			)#map_err?;
+
+			<#ty as ::oct::encode::EncodeUnprefixed>::encode_unprefixed(#value, stream)
+		}
+	}
+}
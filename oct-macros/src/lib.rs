@@ -32,63 +32,238 @@ macro_rules! use_mod {
 		$vis use $name::*;
 	};
 }
-pub(crate) use use_mod;
 
+use_mod!(bits);
+use_mod!(check);
+use_mod!(condition);
+use_mod!(container_attrs);
 use_mod!(discriminants);
+use_mod!(error_enum);
+use_mod!(field_attrs);
+use_mod!(field_ref);
 use_mod!(generic_name);
 use_mod!(impl_derive_macro);
+use_mod!(len_from);
+use_mod!(magic);
+use_mod!(padding);
+use_mod!(prefix);
 use_mod!(repr);
+use_mod!(tag_from);
+use_mod!(variant_attrs);
 
 mod impls;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, parse2};
+use syn::{Data, DeriveInput, parse2};
 
-#[proc_macro_derive(Decode)]
+#[proc_macro_derive(Decode, attributes(oct))]
 pub fn derive_decode(input: TokenStream) -> TokenStream {
 	let input = syn::parse_macro_input!(input as DeriveInput);
 
+	if let Err(e) = check(&input, Derive::Decode) {
+		return e.into_compile_error().into();
+	}
+
+	let attrs = ContainerAttrs::get(&input.attrs);
+	let error = ErrorEnum::new(&input, ErrorKind::Decode);
+
+	let definition = error
+		.as_ref()
+		.map(|error| error.definition(&input));
+
+	// Enumerations can additionally be decoded with an
+	// external tag, unless they are proxied.
+	let tagged = (matches!(input.data, Data::Enum(_)) && attrs.from.is_none() && attrs.try_from.is_none()).then(|| impl_derive_macro(
+		input.clone(),
+		Derive::Decode,
+		parse2(quote! { ::oct::decode::DecodeTagged }).unwrap(),
+		None,
+		Some(quote! { ::oct::decode::Decode<Error: ::core::convert::Into<::oct::error::GenericDecodeError>> }),
+		|_data| unreachable!(),
+		|data, repr, attrs| impls::decode_tagged_enum(data, repr, attrs, error.as_ref()),
+	));
+
 	let output = impl_derive_macro(
 		input,
+		Derive::Decode,
 		parse2(quote! { ::oct::decode::Decode }).unwrap(),
 		None,
-		impls::decode_struct,
-		impls::decode_enum,
+		Some(quote! { ::oct::decode::Decode<Error: ::core::convert::Into<::oct::error::GenericDecodeError>> }),
+		|data| impls::decode_proxy(&attrs).unwrap_or_else(|| impls::decode_struct(data, &attrs, error.as_ref())),
+		|_data, repr, attrs| impls::decode_proxy(attrs).unwrap_or_else(|| impls::decode_enum(repr, attrs, error.as_ref())),
 	);
 
+	let output = quote! {
+		#definition
+
+		#output
+
+		#tagged
+	};
+
 	//panic!("{output}");
 
 	output.into()
 }
 
-#[proc_macro_derive(Encode)]
+#[proc_macro_derive(Encode, attributes(oct))]
 pub fn derive_encode(input: TokenStream) -> TokenStream {
 	let input = syn::parse_macro_input!(input as DeriveInput);
 
+	if let Err(e) = check(&input, Derive::Encode) {
+		return e.into_compile_error().into();
+	}
+
+	let attrs = ContainerAttrs::get(&input.attrs);
+	let error = ErrorEnum::new(&input, ErrorKind::Encode);
+
+	let definition = error
+		.as_ref()
+		.map(|error| error.definition(&input));
+
+	// Enumerations can additionally be encoded without
+	// their tag, unless they are proxied.
+	let tagged = (matches!(input.data, Data::Enum(_)) && attrs.into.is_none()).then(|| impl_derive_macro(
+		input.clone(),
+		Derive::Encode,
+		parse2(quote! { ::oct::encode::EncodeTagged }).unwrap(),
+		None,
+		Some(quote! { ::oct::encode::Encode<Error: ::core::convert::Into<::oct::error::GenericEncodeError>> }),
+		|_data| unreachable!(),
+		|data, repr, attrs| impls::encode_tagged_enum(data, repr, attrs, error.as_ref()),
+	));
+
 	let output = impl_derive_macro(
 		input,
+		Derive::Encode,
 		parse2(quote! { ::oct::encode::Encode }).unwrap(),
 		None,
-		impls::encode_struct,
-		impls::encode_enum,
+		Some(quote! { ::oct::encode::Encode<Error: ::core::convert::Into<::oct::error::GenericEncodeError>> }),
+		|data| impls::encode_proxy(&attrs).unwrap_or_else(|| impls::encode_struct(data, &attrs, error.as_ref())),
+		|_data, repr, attrs| impls::encode_proxy(attrs).unwrap_or_else(|| impls::encode_enum(repr, attrs, error.as_ref())),
+	);
+
+	let output = quote! {
+		#definition
+
+		#output
+
+		#tagged
+	};
+
+	//panic!("{output}");
+
+	output.into()
+}
+
+#[proc_macro_derive(Fingerprint, attributes(oct))]
+pub fn derive_fingerprint(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as DeriveInput);
+
+	if let Err(e) = check(&input, Derive::Fingerprint) {
+		return e.into_compile_error().into();
+	}
+
+	let attrs = ContainerAttrs::get(&input.attrs);
+
+	let output = impl_derive_macro(
+		input,
+		Derive::Fingerprint,
+		parse2(quote! { ::oct::fingerprint::Fingerprint }).unwrap(),
+		None,
+		Some(quote! { ::oct::fingerprint::Fingerprint }),
+		|data| impls::fingerprint_proxy(&attrs).unwrap_or_else(|| impls::fingerprint_struct(data, &attrs)),
+		|data, repr, attrs| impls::fingerprint_proxy(attrs).unwrap_or_else(|| impls::fingerprint_enum(data, repr, attrs)),
+	);
+
+	//panic!("{output}");
+
+	output.into()
+}
+
+#[proc_macro_derive(FixedEncode, attributes(oct))]
+pub fn derive_fixed_encode(input: TokenStream) -> TokenStream {
+	let mut input = syn::parse_macro_input!(input as DeriveInput);
+
+	if let Err(e) = check(&input, Derive::FixedEncode) {
+		return e.into_compile_error().into();
+	}
+
+	let assertion = impls::fixed_encode_assertion(&input);
+
+	// The bounds are additionally applied to the field
+	// types themselves, rather than only to the generic
+	// parameters.
+	let predicates = impls::fixed_encode_bounds(&input);
+	input.generics.make_where_clause().predicates.extend(predicates);
+
+	let output = impl_derive_macro(
+		input,
+		Derive::FixedEncode,
+		parse2(quote! { ::oct::encode::FixedEncode }).unwrap(),
+		None,
+		Some(quote! { ::oct::encode::FixedEncode<Error: ::core::convert::Into<::oct::error::GenericEncodeError>> }),
+		|_data| quote! { },
+		|_data, _repr, _attrs| quote! { },
 	);
 
+	let output = quote! {
+		#output
+
+		#assertion
+	};
+
 	//panic!("{output}");
 
 	output.into()
 }
 
-#[proc_macro_derive(SizedEncode)]
+#[proc_macro_derive(Message, attributes(oct))]
+pub fn derive_message(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as DeriveInput);
+
+	if let Err(e) = check(&input, Derive::Message) {
+		return e.into_compile_error().into();
+	}
+
+	let message_id = ContainerAttrs::get(&input.attrs)
+		.message_id
+		.expect("`message_id` attribute should have been checked");
+
+	let output = impl_derive_macro(
+		input,
+		Derive::Message,
+		parse2(quote! { ::oct::message::Message }).unwrap(),
+		None,
+		None,
+		|_data| impls::message(message_id.clone()),
+		|_data, _repr, _attrs| impls::message(message_id.clone()),
+	);
+
+	//panic!("{output}");
+
+	output.into()
+}
+
+#[proc_macro_derive(SizedEncode, attributes(oct))]
 pub fn derive_sized_encode(input: TokenStream) -> TokenStream {
 	let input = syn::parse_macro_input!(input as DeriveInput);
 
+	if let Err(e) = check(&input, Derive::SizedEncode) {
+		return e.into_compile_error().into();
+	}
+
+	let attrs = ContainerAttrs::get(&input.attrs);
+
 	let output = impl_derive_macro(
 		input,
+		Derive::SizedEncode,
 		parse2(quote! { ::oct::encode::SizedEncode }).unwrap(),
 		None,
-		impls::sized_encode_struct,
-		impls::sized_encode_enum,
+		Some(quote! { ::oct::encode::SizedEncode<Error: ::core::convert::Into<::oct::error::GenericEncodeError>> }),
+		|data| impls::sized_encode_proxy(&attrs).unwrap_or_else(|| impls::sized_encode_struct(data, &attrs)),
+		|data, repr, attrs| impls::sized_encode_proxy(attrs).unwrap_or_else(|| impls::sized_encode_enum(data, repr, attrs)),
 	);
 
 	//panic!("{output}");
@@ -0,0 +1,71 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, LitByteStr, Type};
+
+/// Writes the given magic value.
+#[must_use]
+pub fn encode_magic(magic: &LitByteStr) -> TokenStream {
+	quote! {
+		stream.write(#magic).unwrap();
+	}
+}
+
+/// Reads and checks the given magic value.
+///
+/// Mismatches are yielded as `MagicError`, which is converted using `map_err`.
+#[must_use]
+pub fn decode_magic(magic: &LitByteStr, map_err: &TokenStream) -> TokenStream {
+	let len = magic.value().len();
+
+	quote! {
+		::oct::error::MagicError::check(#magic, stream.read(#len).unwrap())#map_err?;
+	}
+}
+
+/// Reads and checks the encoding of the given constant.
+///
+/// The constant is encoded at runtime for comparison, and the expression evaluates to the constant itself.
+/// Mismatches, as well as constants that cannot be encoded, are yielded as `MagicError`, which is converted using `map_err`.
+#[must_use]
+pub fn decode_constant(ty: &Type, constant: &Expr, map_err: &TokenStream) -> TokenStream {
+	quote! {
+		{
+			const _: () = ::core::assert!(
+				<#ty as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE <= ::oct::error::MagicError::MAX_LEN,
+				"constant fields cannot be longer than `MagicError::MAX_LEN`",
+			);
+
+			let mut buf = [0x00; <#ty as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE];
+			let mut expected = ::oct::encode::Output::new(&mut buf);
+
+			<#ty as ::oct::encode::Encode>::encode(&(#constant), &mut expected)
+				.map_err(|_| ::oct::error::MagicError::unencodable())#map_err?;
+
+			let expected = expected.as_slice();
+			::oct::error::MagicError::check(expected, stream.read(expected.len()).unwrap())#map_err?;
+
+			#constant
+		}
+	}
+}
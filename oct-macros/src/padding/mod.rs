@@ -0,0 +1,118 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::FieldAttrs;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, Type};
+
+/// Records the start of the fields for alignment.
+///
+/// This is only generated if any field is aligned, binding the current position of `stream` to `start`.
+#[must_use]
+pub fn alignment_start(fields: &Fields) -> Option<TokenStream> {
+	fields
+		.iter()
+		.any(|field| FieldAttrs::get(&field.attrs).align.is_some())
+		.then(|| quote! { let start = stream.position(); })
+}
+
+/// Writes the padding before a field.
+///
+/// Alignment is relative to the start of the fields, as bound by [`alignment_start`].
+#[must_use]
+pub fn encode_padding(attrs: &FieldAttrs) -> TokenStream {
+	let pad = attrs.pad.as_ref().map(|pad| quote! {
+		stream.write(&[0x00; #pad]).unwrap();
+	});
+
+	let align = attrs.align.as_ref().map(|align| quote! {
+		{
+			let len = ((#align) - (stream.position() - start) % (#align)) % (#align);
+			stream.write(&[0x00; #align][..len]).unwrap();
+		}
+	});
+
+	quote! { #pad #align }
+}
+
+/// Wraps the given decoding command with the reading of the field's padding.
+///
+/// If the field is `zeroed`, then non-zero padding is yielded as `PaddingError`, which is converted using `map_err`.
+#[must_use]
+pub fn decode_padded(attrs: &FieldAttrs, command: TokenStream, map_err: &TokenStream) -> TokenStream {
+	if attrs.pad.is_none() && attrs.align.is_none() {
+		return command;
+	}
+
+	let pad = attrs.pad.as_ref().map(|pad| {
+		let read = quote! { stream.read(#pad).unwrap() };
+
+		check_padding(attrs, &read, map_err)
+	});
+
+	let align = attrs.align.as_ref().map(|align| {
+		let read = quote! { stream.read(len).unwrap() };
+		let read = check_padding(attrs, &read, map_err);
+
+		quote! {
+			let len = ((#align) - (stream.position() - start) % (#align)) % (#align);
+			#read
+		}
+	});
+
+	quote! {
+		{
+			#pad
+			#align
+
+			#command
+		}
+	}
+}
+
+/// Reads a reserved field.
+///
+/// The expression evaluates to the default value of the field.
+#[must_use]
+pub fn decode_reserved(attrs: &FieldAttrs, ty: &Type, map_err: &TokenStream) -> TokenStream {
+	let read = quote! { stream.read(<#ty as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE).unwrap() };
+	let read = check_padding(attrs, &read, map_err);
+
+	let default = attrs.default_value();
+
+	quote! {
+		{
+			#read
+
+			#default
+		}
+	}
+}
+
+fn check_padding(attrs: &FieldAttrs, read: &TokenStream, map_err: &TokenStream) -> TokenStream {
+	if attrs.zeroed {
+		quote! { ::oct::error::PaddingError::check(#read)#map_err?; }
+	} else {
+		quote! { #read; }
+	}
+}
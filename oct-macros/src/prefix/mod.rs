@@ -0,0 +1,67 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Wraps the given encoding commands with a length prefix.
+///
+/// The prefix is encoded as a `usize` and is reserved before `body` is run, after which it is written in place.
+/// `body` is run in its own block, so that its bindings (such as that of [`alignment_start`](crate::alignment_start)) do not shadow those of the prefix.
+/// Errors from encoding the prefix are mapped using `map_err`.
+#[must_use]
+pub fn encode_prefixed(body: TokenStream, map_err: &TokenStream) -> TokenStream {
+	quote! {
+		let start = stream.position();
+		stream.write(&[0x00; <usize as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE]).unwrap();
+
+		{
+			#body
+		}
+
+		let len = stream.position() - start - <usize as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE;
+
+		let mut buf = [0x00; <usize as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE];
+		let mut prefix = ::oct::encode::Output::new(&mut buf);
+
+		<usize as ::oct::encode::Encode>::encode(&len, &mut prefix)
+			.map_err(::core::convert::Into::<::oct::error::GenericEncodeError>::into)
+			#map_err?;
+
+		stream.write_at(start, prefix.as_slice()).unwrap();
+	}
+}
+
+/// Reads a length-prefixed payload.
+///
+/// This shadows `stream` with a new stream over only the payload, so that any unread data is skipped.
+/// The raw payload is also bound to `payload`.
+#[must_use]
+pub fn decode_prefixed() -> TokenStream {
+	quote! {
+		let ::core::result::Result::Ok(len) = <usize as ::oct::decode::Decode>::decode(stream);
+
+		let payload = stream.read(len).unwrap();
+
+		#[allow(unused_variables)]
+		let stream = &mut ::oct::decode::Input::new(payload);
+	}
+}
@@ -19,17 +19,24 @@
 // er General Public License along with Oct. If
 // not, see <https://www.gnu.org/licenses/>.
 
-use proc_macro2::{Span, TokenStream};
+use crate::{ContainerAttrs, Discriminants};
+
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use std::iter;
 use syn::{
 	Attribute,
+	DataEnum,
+	Expr,
+	ExprLit,
 	Ident,
+	Lit,
 	Path,
 	PathSegment,
 	Type,
 	TypePath,
 };
+use syn::token::Paren;
 
 /// A derivable enumeration representation.
 ///
@@ -53,9 +60,14 @@ pub enum Repr {
 }
 
 impl Repr {
-	#[inline]
-	#[must_use]
-	pub fn get(attrs: &[Attribute]) -> Option<Self> {
+	/// Parses the `repr` attribute.
+	///
+	/// Items that do not name a primitive type (such as `C`, `transparent`, `align`, and `packed`) are ignored.
+	///
+	/// # Errors
+	///
+	/// If the attribute is malformed, then an error is returned.
+	pub fn parse(attrs: &[Attribute]) -> syn::Result<Option<Self>> {
 		let mut this = None;
 
 		for attr in attrs {
@@ -63,28 +75,94 @@ impl Repr {
 				attr.parse_nested_meta(|meta| {
 					let ident = meta.path.require_ident()?;
 
-					if      ident == "u8"    { this = Some(Self::U8) }
-					else if ident == "i8"    { this = Some(Self::I8) }
-					else if ident == "u16"   { this = Some(Self::U16) }
-					else if ident == "i16"   { this = Some(Self::I16) }
-					else if ident == "u32"   { this = Some(Self::U32) }
-					else if ident == "i32"   { this = Some(Self::I32) }
-					else if ident == "u64"   { this = Some(Self::U64) }
-					else if ident == "i64"   { this = Some(Self::I64) }
-					else if ident == "u128"  { this = Some(Self::U128) }
-					else if ident == "i128"  { this = Some(Self::I128) }
-					else if ident == "usize" { this = Some(Self::Usize) }
-					else if ident == "isize" { this = Some(Self::Isize) }
-					else                    { panic!("`{ident}` is not a derivable enumeration representation") };
+					if let Ok(repr) = Self::from_ident(ident) {
+						this = Some(repr);
+					} else if meta.input.peek(Paren) {
+						// Skip arguments, e.g. those of `align(N)`.
+						meta.input.parse::<TokenTree>()?;
+					}
 
 					Ok(())
-				}).unwrap();
+				})?;
 			}
 
 			// Ignore all other attributes.
 		}
 
-		this
+		Ok(this)
+	}
+
+	/// Retrieves the representation given by the `repr` attribute.
+	///
+	/// # Panics
+	///
+	/// If the attribute is invalid, then this method will panic.
+	/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+	#[inline]
+	#[must_use]
+	pub fn get(attrs: &[Attribute]) -> Option<Self> {
+		Self::parse(attrs).expect("`repr` attribute should have been checked")
+	}
+
+	/// Converts a primitive type identifier to a representation.
+	///
+	/// # Errors
+	///
+	/// If the identifier does not name a derivable representation, then an error spanned to it is returned.
+	#[inline]
+	pub fn from_ident(ident: &Ident) -> syn::Result<Self> {
+		if      ident == "u8"    { Ok(Self::U8) }
+		else if ident == "i8"    { Ok(Self::I8) }
+		else if ident == "u16"   { Ok(Self::U16) }
+		else if ident == "i16"   { Ok(Self::I16) }
+		else if ident == "u32"   { Ok(Self::U32) }
+		else if ident == "i32"   { Ok(Self::I32) }
+		else if ident == "u64"   { Ok(Self::U64) }
+		else if ident == "i64"   { Ok(Self::I64) }
+		else if ident == "u128"  { Ok(Self::U128) }
+		else if ident == "i128"  { Ok(Self::I128) }
+		else if ident == "usize" { Ok(Self::Usize) }
+		else if ident == "isize" { Ok(Self::Isize) }
+		else                    { Err(syn::Error::new_spanned(ident, format!("`{ident}` is not a derivable enumeration representation"))) }
+	}
+
+	/// Resolves the representation of a derived enumeration.
+	///
+	/// This is given by the `tag` attribute, the `repr` attribute, or -- if neither is present -- the [smallest](Self::smallest_for) representation that fits all discriminants.
+	#[must_use]
+	pub fn resolve(attrs: &[Attribute], data: &DataEnum) -> Self {
+		ContainerAttrs::get(attrs)
+			.tag
+			.or_else(|| Self::get(attrs))
+			.unwrap_or_else(|| {
+				let discriminants: Vec<_> = Discriminants::new(&data.variants).collect();
+
+				Self::smallest_for(&discriminants)
+			})
+	}
+
+	/// Determines the smallest unsigned representation that fits the given discriminants.
+	///
+	/// If any discriminant is not an integer literal, then [`Isize`](Self::Isize) is returned, as this is the type of such discriminants by default.
+	#[must_use]
+	pub fn smallest_for(discriminants: &[Expr]) -> Self {
+		let mut max = 0x0u128;
+
+		for discriminant in discriminants {
+			let Expr::Lit(ExprLit { lit: Lit::Int(ref lit), .. }) = *discriminant else {
+				return Self::Isize;
+			};
+
+			let value: u128 = lit.base10_parse().unwrap();
+
+			max = max.max(value);
+		}
+
+		if      max <= u8::MAX.into()  { Self::U8 }
+		else if max <= u16::MAX.into() { Self::U16 }
+		else if max <= u32::MAX.into() { Self::U32 }
+		else if max <= u64::MAX.into() { Self::U64 }
+		else                           { Self::U128 }
 	}
 
 	#[inline]
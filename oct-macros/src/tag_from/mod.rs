@@ -0,0 +1,55 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Type};
+
+/// Decodes an externally-tagged field using the value of its tag field.
+///
+/// The expression evaluates to a `Result`, the error type of which is that of the field's `Decode` implementation.
+#[must_use]
+pub fn decode_tagged(ty: &Type, tag: &Ident) -> TokenStream {
+	quote! {
+		<#ty as ::oct::decode::DecodeTagged>::decode_tagged(
+			::core::convert::Into::<<#ty as ::oct::decode::DecodeTagged>::Tag>::into(::core::clone::Clone::clone(&#tag)),
+			stream,
+		)
+	}
+}
+
+/// Encodes an externally-tagged field without its tag.
+///
+/// The tag of `value` is first checked against the value of its tag field (the capture `tag`), with mismatches being converted using `map_err`.
+/// The expression evaluates to a `Result`, the error type of which is that of the field's `Encode` implementation.
+#[must_use]
+pub fn encode_tagged(ty: &Type, value: &Ident, tag: &Ident, map_err: &TokenStream) -> TokenStream {
+	quote! {
+		{
+			::oct::error::TagMismatchError::check(
+				::core::convert::Into::<<#ty as ::oct::encode::EncodeTagged>::Tag>::into(::core::clone::Clone::clone(#tag)),
+				<#ty as ::oct::encode::EncodeTagged>::tag(#value),
+			)#map_err?;
+
+			<#ty as ::oct::encode::EncodeTagged>::encode_untagged(#value, stream)
+		}
+	}
+}
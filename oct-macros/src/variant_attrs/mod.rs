@@ -0,0 +1,77 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use syn::{Attribute, Expr};
+
+/// The `oct` attributes of a derived enumeration variant.
+///
+/// These are set using the `#[oct(...)]` attribute on the individual variants of an enumeration.
+#[derive(Default)]
+pub struct VariantAttrs {
+	/// The tag value of the variant, as set by `tag_value`.
+	pub tag_value: Option<Expr>,
+
+	/// Whether the variant captures unknown variants, as set by `other`.
+	pub other: bool,
+}
+
+impl VariantAttrs {
+	/// Parses the `oct` attributes of a variant.
+	///
+	/// # Errors
+	///
+	/// If any attribute is malformed or unknown, then an error spanned to the offending attribute is returned.
+	pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+		let mut this = Self::default();
+
+		for attr in attrs {
+			if attr.path().is_ident("oct") {
+				attr.parse_nested_meta(|meta| {
+					if meta.path.is_ident("other") {
+						this.other = true;
+					} else if meta.path.is_ident("tag_value") {
+						this.tag_value = Some(meta.value()?.parse()?);
+					} else {
+						return Err(meta.error("unknown variant attribute"));
+					}
+
+					Ok(())
+				})?;
+			}
+
+			// Ignore all other attributes.
+		}
+
+		Ok(this)
+	}
+
+	/// Retrieves the `oct` attributes of a variant.
+	///
+	/// # Panics
+	///
+	/// If the attributes are invalid, then this method will panic.
+	/// Derive macros [`check`](crate::check) their input before expansion, so this does not happen in practice.
+	#[inline]
+	#[must_use]
+	pub fn get(attrs: &[Attribute]) -> Self {
+		Self::parse(attrs).expect("variant attributes should have been checked")
+	}
+}
@@ -0,0 +1,247 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Adapters for [`Buf`] and [`BufMut`] buffers.
+//!
+//! The [`put`] and [`get`] functions allow for encoding to any [`BufMut`] buffer and decoding from any [`Buf`] buffer, respectively.
+//! Values are encoded as-is, i.e. identically to [`Encode`].
+//! If the buffer is a [`Bytes`](bytes::Bytes) object, then decoding does not copy, and any `Bytes` fields in the decoded value will reference the original buffer.
+//!
+//! As the end of an encoding is only known after it has been decoded, values that span several chunks of a non-contiguous buffer (such as a [`Chain`](bytes::buf::Chain)) can only be decoded together with all data after them.
+//! The [`put_framed`] and [`get_framed`] functions instead prefix each value with the length of its encoding (which is itself encoded as a [`usize`]), just as the [`io`](crate::io) adapters do.
+//! This allows for each encoding to be extracted from the buffer before it is decoded.
+//!
+//! # Examples
+//!
+//! ```
+//! use bytes::{Buf, Bytes, BytesMut};
+//! use oct::buf::{get, get_framed, put, put_framed};
+//! use oct::decode::Decode;
+//! use oct::encode::{Encode, SizedEncode};
+//!
+//! #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+//! struct Header {
+//!     id:  u32,
+//!     len: u16,
+//! }
+//!
+//! let mut buf = BytesMut::new();
+//!
+//! put(&mut buf, &Header { id: 0x2A, len: 0x100 }).unwrap();
+//! put(&mut buf, &true).unwrap();
+//!
+//! assert_eq!(buf, b"\x2A\x00\x00\x00\x00\x01\x01".as_slice());
+//!
+//! let mut buf = Bytes::from(buf);
+//!
+//! assert_eq!(get::<Header, _>(&mut buf).unwrap(), Header { id: 0x2A, len: 0x100 });
+//! assert!(get::<bool, _>(&mut buf).unwrap());
+//! assert!(!buf.has_remaining());
+//!
+//! let mut buf = BytesMut::new();
+//!
+//! put_framed(&mut buf, &Header { id: 0x2A, len: 0x100 }).unwrap();
+//!
+//! let mut buf = Bytes::from(buf).chain(Bytes::from_static(b"\x01\x00\x00"));
+//!
+//! assert_eq!(get_framed::<Header, _>(&mut buf).unwrap(), Header { id: 0x2A, len: 0x100 });
+//! assert!(!get_framed::<bool, _>(&mut buf).unwrap());
+//! assert!(!buf.has_remaining());
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use crate::decode::{Decode, Input};
+use crate::encode::{Encode, Output, SizedEncode};
+use crate::error::{StreamDecodeError, StreamEncodeError};
+
+use alloc::vec;
+use bytes::{Buf, BufMut};
+use core::convert::Infallible;
+use core::slice;
+
+/// Encodes a value into a buffer.
+///
+/// If the current chunk of `buf` can hold any encoding of `T` (as specified by [`MAX_ENCODED_SIZE`](SizedEncode::MAX_ENCODED_SIZE)), then the value is encoded directly into the buffer.
+/// Otherwise, the value is first encoded into a temporary buffer.
+///
+/// # Errors
+///
+/// If the value could not be encoded, then an error is returned.
+///
+/// # Panics
+///
+/// If `buf` does not have enough capacity for the encoding, then this function will panic.
+#[inline]
+pub fn put<T: SizedEncode, B: BufMut + ?Sized>(buf: &mut B, value: &T) -> Result<(), T::Error> {
+	put_with(buf, T::MAX_ENCODED_SIZE, |data| {
+		let mut output = Output::new(data);
+
+		value.encode(&mut output)?;
+
+		Ok(output.position())
+	})
+}
+
+/// Decodes a value from a buffer.
+///
+/// The buffer is advanced past the value's encoding.
+/// If the current chunk of `buf` holds all of its remaining data, then the value is decoded directly from the chunk.
+///
+/// Otherwise, as the encoding may span several chunks, all remaining data is first extracted using [`Buf::copy_to_bytes`], and any data following the value is discarded.
+/// Use [`get_framed`] to decode several values from such buffers.
+///
+/// # Errors
+///
+/// If the value could not be decoded, then an error is returned.
+///
+/// # Panics
+///
+/// If `buf` ended before the entire encoding could be read, then this function will panic.
+#[inline]
+pub fn get<T: Decode, B: Buf + ?Sized>(buf: &mut B) -> Result<T, T::Error> {
+	let remaining = buf.remaining();
+
+	if buf.chunk().len() >= remaining {
+		let mut input = Input::new(buf.chunk());
+
+		let value = T::decode(&mut input)?;
+
+		let len = input.position();
+		buf.advance(len);
+
+		Ok(value)
+	} else {
+		let data = buf.copy_to_bytes(remaining);
+
+		T::decode(&mut Input::from_bytes(&data))
+	}
+}
+
+/// Encodes a value into a buffer, prefixed with the length of its encoding.
+///
+/// See [`put`] for how the value is encoded.
+///
+/// # Errors
+///
+/// If the value could not be encoded, then an error is returned.
+///
+/// # Panics
+///
+/// If `buf` does not have enough capacity for the encoding, then this function will panic.
+#[inline]
+pub fn put_framed<T: SizedEncode, B: BufMut + ?Sized>(buf: &mut B, value: &T) -> Result<(), StreamEncodeError<T::Error, Infallible>> {
+	put_with(buf, usize::MAX_ENCODED_SIZE + T::MAX_ENCODED_SIZE, |data| encode_framed(value, data))
+}
+
+/// Decodes a value from a buffer, prefixed with the length of its encoding.
+///
+/// The value is expected to be prefixed with the length of its encoding, as is done by [`put_framed`].
+/// The encoding is extracted using [`Buf::copy_to_bytes`] before it is decoded.
+///
+/// # Errors
+///
+/// If `buf` ended before the entire encoding could be read, or if the value could not be decoded, then an error is returned.
+///
+/// In the case of the buffer ending early, any length prefix will already have been consumed.
+#[inline]
+pub fn get_framed<T: Decode, B: Buf + ?Sized>(buf: &mut B) -> Result<T, StreamDecodeError<T::Error, Infallible>> {
+	let mut len_buf = [0x00; usize::MAX_ENCODED_SIZE];
+
+	if buf.remaining() < len_buf.len() {
+		return Err(StreamDecodeError::UnexpectedEnd);
+	}
+
+	buf.copy_to_slice(&mut len_buf);
+
+	let Ok(len) = usize::decode(&mut Input::new(&len_buf));
+
+	if buf.remaining() < len {
+		return Err(StreamDecodeError::UnexpectedEnd);
+	}
+
+	let data = buf.copy_to_bytes(len);
+
+	T::decode(&mut Input::from_bytes(&data)).map_err(StreamDecodeError::BadValue)
+}
+
+/// Writes at most `size` bytes into a buffer.
+///
+/// The bytes are written by `write`, which returns the amount of bytes actually written.
+#[inline(always)]
+fn put_with<B, F, E>(buf: &mut B, size: usize, write: F) -> Result<(), E>
+where
+	B: BufMut + ?Sized,
+	F: FnOnce(&mut [u8]) -> Result<usize, E>,
+{
+	let chunk = buf.chunk_mut();
+
+	if chunk.len() >= size {
+		let data = unsafe {
+			let ptr = chunk.as_mut_ptr();
+
+			// SAFETY: The chunk is valid for at least `size`
+			// bytes, all of which we initialise here.
+			ptr.write_bytes(0x00, size);
+			slice::from_raw_parts_mut(ptr, size)
+		};
+
+		let len = write(data)?;
+
+		// SAFETY: We have initialised the first `len` bytes
+		// of the chunk.
+		unsafe { buf.advance_mut(len) };
+	} else {
+		let mut data = vec![0x00; size];
+
+		let len = write(&mut data)?;
+		data.truncate(len);
+
+		buf.put_slice(&data);
+	}
+
+	Ok(())
+}
+
+/// Encodes a value prefixed with its length.
+///
+/// The total amount of written bytes is returned.
+#[inline(always)]
+fn encode_framed<T: Encode + ?Sized>(value: &T, buf: &mut [u8]) -> Result<usize, StreamEncodeError<T::Error, Infallible>> {
+	let mut output = Output::new(buf);
+
+	output.write(&[0x00; usize::MAX_ENCODED_SIZE]).unwrap();
+
+	value.encode(&mut output).map_err(StreamEncodeError::BadValue)?;
+
+	let len = output.position() - usize::MAX_ENCODED_SIZE;
+
+	let mut len_buf = [0x00; usize::MAX_ENCODED_SIZE];
+
+	len
+		.encode(&mut Output::new(&mut len_buf))
+		.map_err(StreamEncodeError::BadLength)?;
+
+	output.write_at(0x0, &len_buf).unwrap();
+
+	Ok(output.position())
+}
@@ -0,0 +1,91 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use bytes::{Buf, Bytes, BytesMut};
+use oct::buf::{get, get_framed, put, put_framed};
+use oct::decode::{Decode, Input};
+use oct::error::StreamDecodeError;
+use oct::Slot;
+
+#[test]
+fn test_buf() {
+	let mut buf = BytesMut::new();
+
+	put(&mut buf, &0x01020304u32).unwrap();
+	put(&mut buf, &true).unwrap();
+
+	assert_eq!(buf, b"\x04\x03\x02\x01\x01".as_slice());
+
+	let mut buf = buf.freeze();
+
+	assert_eq!(get::<u32, _>(&mut buf).unwrap(), 0x01020304);
+	assert!(get::<bool, _>(&mut buf).unwrap());
+	assert!(!buf.has_remaining());
+}
+
+#[test]
+fn test_buf_chain() {
+	let mut buf = Bytes::from_static(b"\x04\x03").chain(Bytes::from_static(b"\x02\x01"));
+
+	assert_eq!(get::<u32, _>(&mut buf).unwrap(), 0x01020304);
+	assert!(!buf.has_remaining());
+}
+
+#[test]
+fn test_buf_framed() {
+	let mut buf = BytesMut::new();
+
+	put_framed(&mut buf, &0x01020304u32).unwrap();
+	put_framed(&mut buf, &true).unwrap();
+
+	assert_eq!(buf, b"\x04\x00\x04\x03\x02\x01\x01\x00\x01".as_slice());
+}
+
+#[test]
+fn test_buf_framed_chain() {
+	let mut buf = Bytes::from_static(b"\x04\x00\x04\x03").chain(Bytes::from_static(b"\x02\x01\x01\x00"));
+
+	assert_eq!(get_framed::<u32, _>(&mut buf).unwrap(), 0x01020304);
+	assert!(matches!(get_framed::<u8, _>(&mut buf), Err(StreamDecodeError::UnexpectedEnd)));
+}
+
+#[test]
+fn test_bytes_zero_copy() {
+	let data = Bytes::from_static(b"\x03\x00foo");
+
+	let value = Bytes::decode(&mut Input::from_bytes(&data)).unwrap();
+
+	assert_eq!(value, b"foo".as_slice());
+	assert_eq!(value.as_ptr(), data[0x2..].as_ptr());
+
+	let value = Bytes::decode(&mut Input::new(&data)).unwrap();
+
+	assert_eq!(value, b"foo".as_slice());
+	assert_ne!(value.as_ptr(), data[0x2..].as_ptr());
+}
+
+#[test]
+fn test_slot_to_bytes() {
+	let mut slot = Slot::<u16>::new();
+	slot.write(0x0102).unwrap();
+
+	assert_eq!(Bytes::from(slot), b"\x02\x01".as_slice());
+}
@@ -0,0 +1,233 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::checksum::{Checksum, Crc32};
+use crate::decode::{Decode, Input};
+use crate::encode::{Encode, Output, SizedEncode};
+use crate::error::{CheckedDecodeError, ChecksumError};
+use crate::fingerprint::{Fingerprint, FingerprintHasher};
+
+use core::borrow::{Borrow, BorrowMut};
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// Checksummed value.
+///
+/// This type encodes the contained value followed by a checksum of the value's encoding.
+/// The checksum algorithm is specified by `A`, which defaults to [`Crc32`].
+/// See the [`checksum`](crate::checksum) module for other algorithms.
+///
+/// When decoding, the checksum is recomputed and compared to the one found in the stream.
+///
+/// # Examples
+///
+/// ```
+/// use oct::{Checked, Slot};
+/// use oct::checksum::Crc16;
+/// use oct::decode::Decode;
+/// use oct::encode::{Encode, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Sample {
+///     channel: u8,
+///     value:   i16,
+/// }
+///
+/// let mut buf = Slot::<Checked<Sample, Crc16>>::new();
+///
+/// buf.write(Checked::new(Sample { channel: 0x1, value: -0x2 })).unwrap();
+/// assert_eq!(buf.read().unwrap().into_inner(), Sample { channel: 0x1, value: -0x2 });
+///
+/// // Corrupt the value:
+/// buf[0x0] = 0x2;
+/// assert!(buf.read().is_err());
+/// ```
+#[must_use]
+pub struct Checked<T, A = Crc32> {
+	value: T,
+
+	_algo: PhantomData<fn() -> A>,
+}
+
+impl<T, A> Checked<T, A> {
+	/// Wraps a value.
+	#[inline(always)]
+	pub const fn new(value: T) -> Self {
+		Self { value, _algo: PhantomData }
+	}
+
+	/// Unwraps the contained value.
+	#[inline(always)]
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+}
+
+impl<T, A> AsMut<T> for Checked<T, A> {
+	#[inline(always)]
+	fn as_mut(&mut self) -> &mut T {
+		&mut self.value
+	}
+}
+
+impl<T, A> AsRef<T> for Checked<T, A> {
+	#[inline(always)]
+	fn as_ref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T, A> Borrow<T> for Checked<T, A> {
+	#[inline(always)]
+	fn borrow(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T, A> BorrowMut<T> for Checked<T, A> {
+	#[inline(always)]
+	fn borrow_mut(&mut self) -> &mut T {
+		&mut self.value
+	}
+}
+
+impl<T: Clone, A> Clone for Checked<T, A> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		Self::new(self.value.clone())
+	}
+}
+
+impl<T: Copy, A> Copy for Checked<T, A> { }
+
+impl<T: Debug, A> Debug for Checked<T, A> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_tuple("Checked").field(&self.value).finish()
+	}
+}
+
+impl<T: Decode, A: Checksum> Decode for Checked<T, A> {
+	type Error = CheckedDecodeError<T::Error>;
+
+	/// Decodes the value and verifies its checksum.
+	#[inline]
+	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
+		let start = input.position();
+
+		let value = Decode::decode(input)
+			.map_err(CheckedDecodeError::BadValue)?;
+
+		let mut state = A::new();
+		state.update(&input.as_slice()[start..]);
+
+		let expected = state.finish();
+
+		let Ok(found) = A::Digest::decode(input);
+
+		if found != expected {
+			return Err(CheckedDecodeError::BadChecksum(ChecksumError {
+				expected: expected.into(),
+				found:    found.into(),
+			}));
+		}
+
+		let this = Self::new(value);
+		Ok(this)
+	}
+}
+
+impl<T: Default, A> Default for Checked<T, A> {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new(Default::default())
+	}
+}
+
+impl<T, A> Deref for Checked<T, A> {
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl<T, A> DerefMut for Checked<T, A> {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.value
+	}
+}
+
+impl<T: Encode, A: Checksum> Encode for Checked<T, A> {
+	type Error = T::Error;
+
+	/// Encodes the value followed by its checksum.
+	#[inline]
+	fn encode(&self, output: &mut Output) -> Result<(), Self::Error> {
+		let start = output.position();
+
+		self.value.encode(output)?;
+
+		let mut state = A::new();
+		state.update(&output.as_slice()[start..]);
+
+		let Ok(()) = state.finish().encode(output);
+
+		Ok(())
+	}
+}
+
+impl<T: Eq, A> Eq for Checked<T, A> { }
+
+impl<T: Fingerprint, A: Checksum> Fingerprint for Checked<T, A> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"Checked")
+		.write(A::NAME.as_bytes())
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+impl<T, A> From<T> for Checked<T, A> {
+	#[inline(always)]
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T: PartialEq, A> PartialEq for Checked<T, A> {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		self.value == other.value
+	}
+}
+
+impl<T: SizedEncode, A: Checksum> SizedEncode for Checked<T, A> {
+	const MAX_ENCODED_SIZE: usize =
+		T::MAX_ENCODED_SIZE
+		+ A::Digest::MAX_ENCODED_SIZE;
+}
@@ -0,0 +1,63 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::Checked;
+use oct::checksum::{Adler32, Crc16, Crc32};
+use oct::decode::{Decode, Input};
+use oct::encode::{Encode, Output, SizedEncode};
+use oct::error::{CheckedDecodeError, ChecksumError};
+
+#[test]
+fn test_checked() {
+	let mut buf = [0x00; Checked::<[u8; 0x9], Crc16>::MAX_ENCODED_SIZE];
+	assert_eq!(buf.len(), 0xB);
+
+	let mut output = Output::new(&mut buf);
+	Checked::<_, Crc16>::new(*b"123456789").encode(&mut output).unwrap();
+
+	assert_eq!(output.as_slice(), b"123456789\xB1\x29");
+
+	let value = Checked::<[u8; 0x9], Crc16>::decode(&mut Input::new(&buf)).unwrap();
+	assert_eq!(value.into_inner(), *b"123456789");
+
+	buf[0x4] = b'x';
+
+	let Err(CheckedDecodeError::BadChecksum(e)) = Checked::<[u8; 0x9], Crc16>::decode(&mut Input::new(&buf)) else {
+		panic!("checksum mismatch not detected");
+	};
+
+	assert_eq!(e.found, 0x29B1);
+	assert_ne!(e, ChecksumError { expected: 0x29B1, found: 0x29B1 });
+}
+
+#[test]
+fn test_checked_algo() {
+	let mut buf = [0x00; 0x10];
+
+	let mut output = Output::new(&mut buf);
+	Checked::<_, Crc32>::new(0x01020304u32).encode(&mut output).unwrap();
+	Checked::<_, Adler32>::new(0x01020304u32).encode(&mut output).unwrap();
+
+	let mut input = Input::new(&buf);
+
+	assert_eq!(*Checked::<u32>::decode(&mut input).unwrap(), 0x01020304);
+	assert_eq!(*Checked::<u32, Adler32>::decode(&mut input).unwrap(), 0x01020304);
+}
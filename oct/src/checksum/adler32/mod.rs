@@ -0,0 +1,81 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::checksum::Checksum;
+
+/// The Adler-32 checksum.
+///
+/// This algorithm is faster but weaker than [CRC-32](crate::checksum::Crc32), especially for short inputs.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Adler32 {
+	a: u32,
+	b: u32,
+}
+
+impl Adler32 {
+	const MODULUS: u32 = 0xFFF1;
+
+	/// The maximum amount of bytes that can be summed before the sums may overflow.
+	const BLOCK_SIZE: usize = 0x15B0;
+}
+
+impl Checksum for Adler32 {
+	const NAME: &'static str = "Adler-32";
+
+	type Digest = u32;
+
+	#[inline(always)]
+	fn new() -> Self {
+		Self { a: 0x1, b: 0x0 }
+	}
+
+	#[inline]
+	fn update(&mut self, data: &[u8]) {
+		let mut a = self.a;
+		let mut b = self.b;
+
+		for chunk in data.chunks(Self::BLOCK_SIZE) {
+			for &c in chunk {
+				a += u32::from(c);
+				b += a;
+			}
+
+			a %= Self::MODULUS;
+			b %= Self::MODULUS;
+		}
+
+		self.a = a;
+		self.b = b;
+	}
+
+	#[inline(always)]
+	fn finish(&self) -> Self::Digest {
+		(self.b << 0x10) | self.a
+	}
+}
+
+impl Default for Adler32 {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new()
+	}
+}
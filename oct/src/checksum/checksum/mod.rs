@@ -0,0 +1,62 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::decode::Decode;
+use crate::encode::SizedEncode;
+
+use core::convert::Infallible;
+
+/// Denotes a checksum algorithm.
+///
+/// Implementors are fed with data using [`update`](Self::update), after which the final checksum is retrieved using [`finish`](Self::finish).
+///
+/// # Examples
+///
+/// ```
+/// use oct::checksum::{Checksum, Crc32};
+///
+/// let mut crc = Crc32::new();
+///
+/// crc.update(b"1234");
+/// crc.update(b"56789");
+///
+/// assert_eq!(crc.finish(), 0xCBF43926);
+/// ```
+pub trait Checksum: Sized {
+	/// The name of the algorithm.
+	///
+	/// This is mainly used for [fingerprinting](crate::fingerprint::Fingerprint) of [`Checked`](crate::Checked).
+	const NAME: &'static str;
+
+	/// The type of the final checksum.
+	type Digest: Copy + Decode<Error = Infallible> + Eq + Into<u32> + SizedEncode<Error = Infallible>;
+
+	/// Constructs a new, empty state.
+	#[must_use]
+	fn new() -> Self;
+
+	/// Feeds data into the state.
+	fn update(&mut self, data: &[u8]);
+
+	/// Retrieves the checksum of all data fed so far.
+	#[must_use]
+	fn finish(&self) -> Self::Digest;
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::checksum::Checksum;
+
+/// The CRC-32 checksum.
+///
+/// This implements the common (ISO-HDLC) variant as used by e.g. Ethernet and Zip, i.e. using the reflected polynomial `0x04C11DB7` with an initial value and final XOR of `0xFFFFFFFF`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[must_use]
+pub struct Crc32(u32);
+
+impl Crc32 {
+	const TABLE: [u32; 0x100] = {
+		let mut table = [0x0; 0x100];
+
+		let mut i = 0x0;
+		while i < table.len() {
+			let mut value = i as u32;
+
+			let mut j = 0x0;
+			while j < 0x8 {
+				value = if value & 0x1 == 0x0 { value >> 0x1 } else { (value >> 0x1) ^ 0xEDB88320 };
+
+				j += 0x1;
+			}
+
+			table[i] = value;
+
+			i += 0x1;
+		}
+
+		table
+	};
+}
+
+impl Checksum for Crc32 {
+	const NAME: &'static str = "CRC-32";
+
+	type Digest = u32;
+
+	#[inline(always)]
+	fn new() -> Self {
+		Self(0x0)
+	}
+
+	#[inline]
+	fn update(&mut self, data: &[u8]) {
+		let mut value = !self.0;
+
+		for &c in data {
+			let index = (value as u8 ^ c) as usize;
+			value = (value >> 0x8) ^ Self::TABLE[index];
+		}
+
+		self.0 = !value;
+	}
+
+	#[inline(always)]
+	fn finish(&self) -> Self::Digest {
+		self.0
+	}
+}
@@ -0,0 +1,33 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Checksum algorithms.
+//!
+//! This module defines the [`Checksum`] trait along with a few common implementations of it.
+//! These are used by the [`Checked`](crate::Checked) type for verifying the integrity of encodings.
+
+#[cfg(test)]
+mod tests;
+
+use_mod!(pub adler32);
+use_mod!(pub checksum);
+use_mod!(pub crc16);
+use_mod!(pub crc32);
@@ -0,0 +1,62 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::checksum::{Adler32, Checksum, Crc16, Crc32};
+
+macro_rules! test {
+	($ty:ty: $data:expr => $value:expr) => {{
+		let mut state = <$ty as Checksum>::new();
+		state.update($data);
+
+		assert_eq!(state.finish(), $value);
+	}};
+}
+
+#[test]
+fn test_checksum() {
+	test!(Adler32: b""          => 0x00000001);
+	test!(Adler32: b"123456789" => 0x091E01DE);
+	test!(Adler32: b"Wikipedia" => 0x11E60398);
+
+	test!(Crc16: b""          => 0xFFFF);
+	test!(Crc16: b"123456789" => 0x29B1);
+
+	test!(Crc32: b""          => 0x00000000);
+	test!(Crc32: b"123456789" => 0xCBF43926);
+}
+
+#[test]
+fn test_checksum_long() {
+	let data = [0xFF; 0x10000];
+
+	let mut state = Adler32::new();
+	state.update(&data);
+
+	let mut a = 0x1u64;
+	let mut b = 0x0u64;
+
+	for &c in &data {
+		a = (a + u64::from(c)) % 0xFFF1;
+		b = (b + a) % 0xFFF1;
+	}
+
+	assert_eq!(u64::from(state.finish()), (b << 0x10) | a);
+}
@@ -22,20 +22,16 @@
 #[cfg(test)]
 mod tests;
 
-use crate::decode::{DecodeBorrowed, Input};
+use crate::decode::Input;
 use crate::error::{
-	CStringDecodeError,
 	CharDecodeError,
 	CollectionDecodeError,
 	EnumDecodeError,
 	ItemDecodeError,
-	SystemTimeDecodeError,
-	Utf8Error,
 };
 
 use core::cell::{Cell, RefCell};
 use core::convert::Infallible;
-use core::hash::Hash;
 use core::marker::{PhantomData, PhantomPinned};
 use core::mem::MaybeUninit;
 use core::net::{
@@ -56,10 +52,23 @@ use core::ops::{
 	RangeTo,
 	RangeToInclusive,
 };
-use core::ptr::copy_nonoverlapping;
-use core::str;
 use core::time::Duration;
 
+#[cfg(feature = "alloc")]
+use crate::decode::{DecodeBorrowed, DecodeUnprefixed};
+
+#[cfg(feature = "alloc")]
+use crate::error::CStringDecodeError;
+
+#[cfg(feature = "alloc")]
+use crate::error::Utf8Error;
+
+#[cfg(feature = "std")]
+use crate::error::SystemTimeDecodeError;
+
+#[cfg(feature = "alloc")]
+use core::ptr::copy_nonoverlapping;
+
 #[cfg(feature = "alloc")]
 use alloc::borrow::{Cow, ToOwned};
 
@@ -84,11 +93,14 @@ use alloc::rc::Rc;
 #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 use alloc::sync::Arc;
 
+#[cfg(feature = "bytes")]
+use bytes::{Bytes, BytesMut};
+
 #[cfg(feature = "std")]
 use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "std")]
-use std::hash::BuildHasher;
+use std::hash::{BuildHasher, Hash};
 
 #[cfg(feature = "std")]
 use std::sync::{Mutex, RwLock};
@@ -225,6 +237,40 @@ impl<T: Decode> Decode for Box<T> {
 	}
 }
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl Decode for Bytes {
+	type Error = Infallible;
+
+	/// Decodes the buffer identically to a [`Vec<u8>`](Vec) object.
+	///
+	/// If `input` was constructed using [`Input::from_bytes`], then the returned buffer references the input buffer directly.
+	#[inline(always)]
+	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
+		let Ok(len) = Decode::decode(input);
+
+		let this = input.read_bytes(len).unwrap();
+		Result::Ok(this)
+	}
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl Decode for BytesMut {
+	type Error = Infallible;
+
+	/// Decodes the buffer identically to a [`Vec<u8>`](Vec) object.
+	#[inline(always)]
+	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
+		let Ok(len) = Decode::decode(input);
+
+		let data = input.read(len).unwrap();
+
+		let this = Self::from(data);
+		Result::Ok(this)
+	}
+}
+
 impl<T: Decode> Decode for Cell<T> {
 	type Error = T::Error;
 
@@ -475,7 +521,7 @@ impl<T: Decode> Decode for Mutex<T> {
 impl<T: Decode> Decode for Option<T> {
 	type Error = T::Error;
 
-	#[expect(clippy::if_then_some_else_none)] // ???
+	#[allow(clippy::if_then_some_else_none)] // ???
 	#[inline]
 	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
 		let sign = bool::decode(input)
@@ -705,32 +751,7 @@ impl Decode for String {
 	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
 		let Ok(len) = Decode::decode(input);
 
-		let data = input.read(len).unwrap();
-
-		if let Err(e) = str::from_utf8(data) {
-			let i = e.valid_up_to();
-			let c = data[i];
-
-			return Err(
-				CollectionDecodeError::BadItem(
-					Utf8Error { value: c, index: i },
-				),
-			);
-		};
-
-		let mut v = Vec::with_capacity(len);
-
-		unsafe {
-			let src = data.as_ptr();
-			let dst = v.as_mut_ptr();
-
-			copy_nonoverlapping(src, dst, len);
-			v.set_len(len);
-		}
-
-		// SAFETY: We have already tested the raw data.
-		let this = unsafe { Self::from_utf8_unchecked(v) };
-		Result::Ok(this)
+		Self::decode_unprefixed(len, input)
 	}
 }
 
@@ -781,26 +802,11 @@ impl Decode for usize {
 impl<T: Decode> Decode for Vec<T> {
 	type Error = CollectionDecodeError<Infallible, ItemDecodeError<usize, T::Error>>;
 
-	#[inline]
+	#[inline(always)]
 	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
 		let Ok(len) = Decode::decode(input);
 
-		let mut this = Self::with_capacity(len);
-
-		let buf = this.as_mut_ptr();
-		for i in 0x0..len {
-			let value = Decode::decode(input)
-				.map_err(|e| CollectionDecodeError::BadItem(ItemDecodeError { index: i, error: e }))?;
-
-			// SAFETY: Each index is within bounds (i.e. capac-
-			// ity).
-			unsafe { buf.add(i).write(value) };
-		}
-
-		// SAFETY: We have initialised the buffer.
-		unsafe { this.set_len(len); }
-
-		Result::Ok(this)
+		Self::decode_unprefixed(len, input)
 	}
 }
 
@@ -1043,65 +1049,65 @@ impl_non_zero!(usize);
 impl_atomic! {
 	width: "8",
 	ty: bool,
-	atomic_ty: std::sync::atomic::AtomicBool,
+	atomic_ty: core::sync::atomic::AtomicBool,
 }
 
 impl_atomic! {
 	width: "16",
 	ty: i16,
-	atomic_ty: std::sync::atomic::AtomicI16,
+	atomic_ty: core::sync::atomic::AtomicI16,
 }
 
 impl_atomic! {
 	width: "32",
 	ty: i32,
-	atomic_ty: std::sync::atomic::AtomicI32,
+	atomic_ty: core::sync::atomic::AtomicI32,
 }
 
 impl_atomic! {
 	width: "64",
 	ty: i64,
-	atomic_ty: std::sync::atomic::AtomicI64,
+	atomic_ty: core::sync::atomic::AtomicI64,
 }
 
 impl_atomic! {
 	width: "8",
 	ty: i8,
-	atomic_ty: std::sync::atomic::AtomicI8,
+	atomic_ty: core::sync::atomic::AtomicI8,
 }
 
 impl_atomic! {
 	width: "ptr",
 	ty: isize,
-	atomic_ty: std::sync::atomic::AtomicIsize,
+	atomic_ty: core::sync::atomic::AtomicIsize,
 }
 
 impl_atomic! {
 	width: "16",
 	ty: u16,
-	atomic_ty: std::sync::atomic::AtomicU16,
+	atomic_ty: core::sync::atomic::AtomicU16,
 }
 
 impl_atomic! {
 	width: "32",
 	ty: u32,
-	atomic_ty: std::sync::atomic::AtomicU32,
+	atomic_ty: core::sync::atomic::AtomicU32,
 }
 
 impl_atomic! {
 	width: "64",
 	ty: u64,
-	atomic_ty: std::sync::atomic::AtomicU64,
+	atomic_ty: core::sync::atomic::AtomicU64,
 }
 
 impl_atomic! {
 	width: "8",
 	ty: u8,
-	atomic_ty: std::sync::atomic::AtomicU8,
+	atomic_ty: core::sync::atomic::AtomicU8,
 }
 
 impl_atomic! {
 	width: "ptr",
 	ty: usize,
-	atomic_ty: std::sync::atomic::AtomicUsize,
+	atomic_ty: core::sync::atomic::AtomicUsize,
 }
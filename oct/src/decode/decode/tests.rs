@@ -20,10 +20,13 @@
 // not, see <https://www.gnu.org/licenses/>.
 
 use oct::decode::{Decode, Input};
-use oct::encode::{Encode, SizedEncode};
+use oct::encode::{Encode, Output, SizedEncode};
 use std::char;
+use std::convert::Infallible;
+use std::vec;
 use std::vec::Vec;
 use std::string::String;
+use std::time::Duration;
 
 macro_rules! test {
 	($ty:ty: $data:expr => $value:expr) => {{
@@ -112,18 +115,743 @@ fn test_decode_derive() {
 
 	test!(Unit: [] => Unit);
 
-	test!(UnitOrFields: [
-		0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-		0x00, 0x00,
-	] => UnitOrFields::Unit);
+	test!(UnitOrFields: [0x00] => UnitOrFields::Unit);
 
-	test!(UnitOrFields: [
-		0x01, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
-		0x00, 0x00,
-	] => UnitOrFields::Unnamed(-0x1));
+	test!(UnitOrFields: [0x01, 0xFF, 0xFF, 0xFF, 0xFF] => UnitOrFields::Unnamed(-0x1));
 
 	test!(UnitOrFields: [
-		0x02, 0x00, 0x4C, 0xC8, 0xC5, 0x66, 0x00, 0x00,
-		0x00, 0x00,
+		0x02, 0x4C, 0xC8, 0xC5, 0x66, 0x00, 0x00, 0x00,
+		0x00,
 	] => UnitOrFields::Named { timestamp: 1724237900 });
 }
+
+#[test]
+fn test_decode_derive_skip() {
+	#[derive(Debug, Default, PartialEq)]
+	struct Handle(usize);
+
+	fn default_name() -> String {
+		String::from("unnamed")
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Session {
+		id: u16,
+
+		#[oct(skip)]
+		handle: Handle,
+
+		#[oct(skip, default = "default_name")]
+		name: String,
+
+		flags: u8,
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[repr(u8)]
+	enum Event {
+		Open(#[oct(skip)] Handle, u16),
+	}
+
+	test!(Session: [0x2A, 0x00, 0x03] => Session {
+		id:     0x2A,
+		handle: Handle(0x0),
+		name:   String::from("unnamed"),
+		flags:  0x3,
+	});
+
+	test!(Event: [0x00, 0x2A, 0x00] => Event::Open(Handle(0x0), 0x2A));
+}
+
+#[test]
+fn test_decode_derive_with() {
+	mod millis {
+		use oct::decode::{Decode, Input};
+		use std::convert::Infallible;
+		use std::time::Duration;
+
+		pub fn decode(input: &mut Input) -> Result<Duration, Infallible> {
+			u32::decode(input).map(|value| Duration::from_millis(value.into()))
+		}
+	}
+
+	fn decode_flag(input: &mut Input) -> Result<bool, Infallible> {
+		u8::decode(input).map(|value| value != 0x0)
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Timeout {
+		#[oct(with = "millis")]
+		delay: Duration,
+
+		#[oct(decode_with = "decode_flag")]
+		retry: bool,
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[repr(u8)]
+	enum Event {
+		Sleep(#[oct(with = "millis")] Duration),
+	}
+
+	test!(Timeout: [0xE8, 0x03, 0x00, 0x00, 0x02] => Timeout {
+		delay: Duration::from_secs(0x1),
+		retry: true,
+	});
+
+	test!(Event: [0x00, 0xFA, 0x00, 0x00, 0x00] => Event::Sleep(Duration::from_millis(0xFA)));
+}
+
+#[test]
+fn test_decode_derive_generic() {
+	#[derive(Debug, Decode, PartialEq)]
+	struct Wrapper<T>(T);
+
+	#[derive(Debug, Decode, PartialEq)]
+	enum Either<L, R> {
+		Left(L),
+
+		Right(R),
+	}
+
+	#[expect(clippy::unnecessary_wraps)]
+	fn encode_nothing<T>(_value: &T, _output: &mut Output) -> Result<(), Infallible> {
+		Ok(())
+	}
+
+	#[derive(Debug, Decode, Encode, PartialEq)]
+	struct Opaque<T> {
+		#[oct(encode_with = "encode_nothing")]
+		value: T,
+	}
+
+	test!(Wrapper<u16>: [0x34, 0x12] => Wrapper(0x1234));
+
+	test!(Either<u8, u16>: [0x01, 0x34, 0x12] => Either::<u8, u16>::Right(0x1234));
+
+	test!(Opaque<u16>: [0x34, 0x12] => Opaque { value: 0x1234 });
+}
+
+#[test]
+fn test_decode_derive_tag() {
+	const BASE: u8 = 0x10;
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(tag = u8)]
+	enum Command {
+		#[oct(tag_value = BASE)]
+		Start,
+
+		Stop,
+
+		#[oct(tag_value = BASE * 0x2)]
+		Reset(u16),
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(tag = u16)]
+	#[repr(C, align(0x4))]
+	enum Status {
+		Idle,
+
+		Busy(u8),
+	}
+
+	test!(Command: [0x10] => Command::Start);
+
+	test!(Command: [0x11] => Command::Stop);
+
+	test!(Command: [0x20, 0x34, 0x12] => Command::Reset(0x1234));
+
+	test!(Status: [0x00, 0x00] => Status::Idle);
+
+	test!(Status: [0x01, 0x00, 0xFF] => Status::Busy(0xFF));
+
+	assert!(matches!(
+		Command::decode(&mut Input::new(&[0x12])),
+		Err(oct::error::EnumDecodeError::UnassignedDiscriminant { value: 0x12 }),
+	));
+}
+
+#[test]
+fn test_decode_derive_extensible() {
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(extensible, tag = u8)]
+	enum Old {
+		Ping(u8),
+
+		#[oct(other)]
+		Unknown(u8, Vec<u8>),
+	}
+
+	#[derive(Encode)]
+	#[oct(extensible, tag = u8)]
+	enum New {
+		Ping(u8, u16),
+
+		_Reserved,
+
+		Pong(u32),
+	}
+
+	let mut buf = [0x00; 0x10];
+
+	let mut output = oct::encode::Output::new(&mut buf);
+	New::Ping(0x2A, 0xFFFF).encode(&mut output).unwrap();
+	New::Pong(0x12345678).encode(&mut output).unwrap();
+
+	let data = output.as_slice();
+	assert_eq!(data, [
+		0x00, 0x03, 0x00, 0x2A, 0xFF, 0xFF, 0x02, 0x04,
+		0x00, 0x78, 0x56, 0x34, 0x12,
+	]);
+
+	let mut input = Input::new(data);
+	assert_eq!(Old::decode(&mut input).unwrap(), Old::Ping(0x2A));
+	assert_eq!(Old::decode(&mut input).unwrap(), Old::Unknown(0x2, vec![0x78, 0x56, 0x34, 0x12]));
+	assert_eq!(input.remaining(), 0x0);
+}
+
+#[test]
+fn test_decode_derive_versioned() {
+	fn default_retries() -> u8 {
+		0x3
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Config {
+		id: u16,
+
+		#[oct(since = 1)]
+		flags: u8,
+
+		#[oct(since = 2, default = "default_retries")]
+		retries: u8,
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(versioned)]
+	struct Initial {
+		id: u16,
+	}
+
+	// Version 0:
+	test!(Config: [0x02, 0x00, 0x2A, 0x00] => Config { id: 0x2A, flags: 0x0, retries: 0x3 });
+
+	// Version 1:
+	test!(Config: [0x03, 0x00, 0x2A, 0x00, 0x01] => Config { id: 0x2A, flags: 0x1, retries: 0x3 });
+
+	// Version 2:
+	test!(Config: [0x04, 0x00, 0x2A, 0x00, 0x01, 0x05] => Config { id: 0x2A, flags: 0x1, retries: 0x5 });
+
+	// Version 3 (with an unknown field):
+	let mut input = Input::new(&[0x06, 0x00, 0x2A, 0x00, 0x01, 0x05, 0xFF, 0xFF, 0x7F]);
+	assert_eq!(Config::decode(&mut input).unwrap(), Config { id: 0x2A, flags: 0x1, retries: 0x5 });
+	assert_eq!(u8::decode(&mut input).unwrap(), 0x7F);
+
+	// Initial version (with a field from version 1):
+	let mut input = Input::new(&[0x03, 0x00, 0x2A, 0x00, 0x01, 0x7F]);
+	assert_eq!(Initial::decode(&mut input).unwrap(), Initial { id: 0x2A });
+	assert_eq!(u8::decode(&mut input).unwrap(), 0x7F);
+}
+
+#[test]
+fn test_decode_derive_error() {
+	use core::num::NonZeroU16;
+	use std::string::ToString;
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(error = "generate")]
+	struct Header(u8, NonZeroU16);
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(error = "generate")]
+	enum Packet {
+		Ping { header: Header },
+
+		Pong(u8),
+	}
+
+	test!(Packet: [0x00, 0x01, 0x02, 0x00] => Packet::Ping { header: Header(0x1, NonZeroU16::new(0x2).unwrap()) });
+
+	let error = Packet::decode(&mut Input::new(&[0x00, 0x01, 0x00, 0x00])).unwrap_err();
+	assert!(matches!(error, PacketDecodeError::PingHeader(HeaderDecodeError::Field1(..))));
+	assert_eq!(error.to_string(), "`Packet::Ping.header.1`: expected non-zero integer but found `0`");
+
+	let error = Packet::decode(&mut Input::new(&[0x02])).unwrap_err();
+	assert!(matches!(error, PacketDecodeError::UnassignedDiscriminant { value: 0x2 }));
+	assert_eq!(error.to_string(), "`Packet`: `2` is not an assigned discriminant");
+}
+
+#[test]
+fn test_decode_derive_custom_error() {
+	use core::fmt::{self, Display, Formatter};
+	use oct::error::{CustomError, GenericDecodeError};
+	use std::string::ToString;
+
+	#[derive(Debug)]
+	struct ParityError;
+
+	impl Display for ParityError {
+		fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+			write!(f, "odd value")
+		}
+	}
+
+	impl core::error::Error for ParityError { }
+
+	#[derive(Debug, PartialEq)]
+	struct Even(u8);
+
+	impl Decode for Even {
+		type Error = CustomError;
+
+		fn decode(input: &mut Input) -> Result<Self, Self::Error> {
+			let Ok(value) = u8::decode(input);
+
+			if value % 0x2 != 0x0 {
+				return Err(CustomError::from_error(ParityError));
+			}
+
+			Ok(Self(value))
+		}
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Pair(Even, Even);
+
+	test!(Pair: [0x02, 0x04] => Pair(Even(0x2), Even(0x4)));
+
+	let error = Pair::decode(&mut Input::new(&[0x02, 0x03])).unwrap_err();
+
+	let GenericDecodeError::Custom(ref e) = error else { panic!("expected custom error") };
+	assert!(e.get_ref().unwrap().is::<ParityError>());
+	assert_eq!(e.code(), None);
+	assert_eq!(error.to_string(), "odd value");
+}
+
+#[test]
+fn test_decode_derive_validate() {
+	use oct::error::{EnumDecodeError, GenericDecodeError, ValidationError};
+	use std::string::ToString;
+
+	fn validate_name(name: &str) -> Result<(), ValidationError> {
+		if name.is_empty() {
+			return Err(ValidationError::Invalid { message: "name is empty" });
+		}
+
+		Ok(())
+	}
+
+	fn validate_user(user: &User) -> Result<(), ValidationError> {
+		if user.age < 0x12 && user.admin {
+			return Err(ValidationError::Invalid { message: "admins must be adults" });
+		}
+
+		Ok(())
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(validate = "validate_user")]
+	struct User {
+		#[oct(max_len = 0x4, validate = "validate_name")]
+		name: String,
+
+		#[oct(range = ..=0x7F)]
+		age: u8,
+
+		admin: bool,
+	}
+
+	test!(User: [0x03, 0x00, b'B', b'o', b'b', 0x20, 0x01] => User { name: String::from("Bob"), age: 0x20, admin: true });
+
+	let error = User::decode(&mut Input::new(&[0x05, 0x00, b'A', b'l', b'i', b'c', b'e', 0x20, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::Invalid(ValidationError::TooLong { max_len: 0x4, len: 0x5 })));
+
+	let error = User::decode(&mut Input::new(&[0x00, 0x00, 0x20, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::Invalid(ValidationError::Invalid { message: "name is empty" })));
+
+	let error = User::decode(&mut Input::new(&[0x03, 0x00, b'B', b'o', b'b', 0x80, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::Invalid(ValidationError::OutOfRange)));
+
+	let error = User::decode(&mut Input::new(&[0x03, 0x00, b'B', b'o', b'b', 0x10, 0x01])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::Invalid(ValidationError::Invalid { message: "admins must be adults" })));
+
+	#[derive(Debug, Decode, PartialEq)]
+	enum Shape {
+		Circle(#[oct(range = 0x1..)] u16),
+	}
+
+	test!(Shape: [0x00, 0x02, 0x00] => Shape::Circle(0x2));
+
+	let error = Shape::decode(&mut Input::new(&[0x00, 0x00, 0x00])).unwrap_err();
+	assert!(matches!(error, EnumDecodeError::BadField(GenericDecodeError::Invalid(ValidationError::OutOfRange))));
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(error = "generate")]
+	struct Percentage(#[oct(range = 0x0..=0x64)] u8);
+
+	let error = Percentage::decode(&mut Input::new(&[0x65])).unwrap_err();
+	assert!(matches!(error, PercentageDecodeError::InvalidField0(ValidationError::OutOfRange)));
+	assert_eq!(error.to_string(), "`Percentage.0`: value is out of range");
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(error = "generate")]
+	struct Header {
+		#[oct(range = 0x1..)]
+		n: u8,
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(error = "generate")]
+	enum Packet {
+		Data { header: Header },
+
+		Ack(#[oct(range = ..0x10)] u8),
+	}
+
+	let error = Packet::decode(&mut Input::new(&[0x00, 0x00])).unwrap_err();
+	assert!(matches!(error, PacketDecodeError::DataHeader(HeaderDecodeError::InvalidN(ValidationError::OutOfRange))));
+	assert_eq!(error.to_string(), "`Packet::Data.header.n`: value is out of range");
+
+	let error = Packet::decode(&mut Input::new(&[0x01, 0x10])).unwrap_err();
+	assert!(matches!(error, PacketDecodeError::InvalidAck0(ValidationError::OutOfRange)));
+	assert_eq!(error.to_string(), "`Packet::Ack.0`: value is out of range");
+}
+
+#[test]
+fn test_decode_derive_proxy() {
+	use core::num::{NonZeroU64, TryFromIntError};
+	use oct::error::ProxyDecodeError;
+
+	#[derive(Decode)]
+	struct RawTemperature {
+		kelvin: u16,
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(from = "RawTemperature")]
+	struct Temperature(f32);
+
+	impl From<RawTemperature> for Temperature {
+		fn from(value: RawTemperature) -> Self {
+			Self(f32::from(value.kelvin) - 273.0)
+		}
+	}
+
+	test!(Temperature: [0x2A, 0x01] => Temperature(25.0));
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(try_from = "u64")]
+	struct UserId(NonZeroU64);
+
+	impl TryFrom<u64> for UserId {
+		type Error = TryFromIntError;
+
+		fn try_from(value: u64) -> Result<Self, Self::Error> {
+			NonZeroU64::try_from(value).map(Self)
+		}
+	}
+
+	test!(UserId: [0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00] => UserId(NonZeroU64::new(0x2A).unwrap()));
+
+	let error = UserId::decode(&mut Input::new(&[0x00; 0x8])).unwrap_err();
+	assert!(matches!(error, ProxyDecodeError::BadConversion(..)));
+}
+
+#[test]
+fn test_decode_derive_magic() {
+	use oct::error::{GenericDecodeError, MagicError};
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(magic = b"OCT1")]
+	struct Header {
+		#[oct(constant = 0xCAFEu16)]
+		version: u16,
+
+		len: u8,
+	}
+
+	test!(Header: [b'O', b'C', b'T', b'1', 0xFE, 0xCA, 0x03] => Header { version: 0xCAFE, len: 0x3 });
+
+	let error = Header::decode(&mut Input::new(&[b'O', b'C', b'T', b'2', 0xFE, 0xCA, 0x03])).unwrap_err();
+
+	let GenericDecodeError::BadMagic(ref e) = error else { panic!("expected magic error") };
+	assert_eq!(e.expected(), b"OCT1");
+	assert_eq!(e.found(), b"OCT2");
+
+	let error = Header::decode(&mut Input::new(&[b'O', b'C', b'T', b'1', 0xEF, 0xBE, 0x03])).unwrap_err();
+
+	let GenericDecodeError::BadMagic(ref e) = error else { panic!("expected magic error") };
+	assert_eq!(e.expected(), [0xFE, 0xCA]);
+	assert_eq!(e.found(), [0xEF, 0xBE]);
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[oct(magic = b"\x7FELF", error = "generate")]
+	enum Packet {
+		Hello(#[oct(constant = 0x1u8)] u8),
+	}
+
+	test!(Packet: [0x7F, b'E', b'L', b'F', 0x00, 0x01] => Packet::Hello(0x1));
+
+	let error = Packet::decode(&mut Input::new(&[0x7F, b'E', b'L', b'F', 0x00, 0x02])).unwrap_err();
+	assert!(matches!(error, PacketDecodeError::BadMagic(MagicError { .. })));
+
+	#[derive(Debug, Decode)]
+	struct Overflow {
+		#[oct(constant = 0x10000usize)]
+		_len: usize,
+	}
+
+	let error = Overflow::decode(&mut Input::new(&[0x00, 0x00])).unwrap_err();
+
+	let GenericDecodeError::BadMagic(ref e) = error else { panic!("expected magic error") };
+	assert!(e.expected().is_empty());
+	assert!(e.found().is_empty());
+}
+
+#[test]
+fn test_decode_derive_padding() {
+	use oct::error::{EnumDecodeError, GenericDecodeError, PaddingError};
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Register {
+		id: u8,
+
+		#[oct(align = 0x4)]
+		value: u32,
+
+		#[oct(pad = 0x2)]
+		flags: u8,
+
+		#[oct(reserved)]
+		reserved: [u8; 0x3],
+	}
+
+	test!(Register: [
+		0x01, 0xFF, 0xFF, 0xFF, 0x78, 0x56, 0x34, 0x12,
+		0xFF, 0xFF, 0x03, 0xFF, 0xFF, 0xFF,
+	] => Register { id: 0x1, value: 0x12345678, flags: 0x3, reserved: [0x00; 0x3] });
+
+	#[derive(Debug, Decode, PartialEq)]
+	enum Packet {
+		Data {
+			#[oct(pad = 0x1, zeroed)]
+			len: u8,
+
+			#[oct(reserved, zeroed)]
+			reserved: u16,
+		},
+	}
+
+	test!(Packet: [0x00, 0x00, 0x04, 0x00, 0x00] => Packet::Data { len: 0x4, reserved: 0x0 });
+
+	let error = Packet::decode(&mut Input::new(&[0x00, 0x00, 0x04, 0x00, 0x01])).unwrap_err();
+	assert!(matches!(error, EnumDecodeError::BadField(GenericDecodeError::BadPadding(PaddingError { offset: 0x1, value: 0x1 }))));
+
+	#[derive(Debug, Decode, Encode, PartialEq)]
+	struct Versioned {
+		id: u8,
+
+		#[oct(align = 0x4)]
+		value: u32,
+
+		#[oct(since = 1)]
+		flags: u16,
+	}
+
+	#[derive(Debug, Decode, Encode, PartialEq)]
+	#[oct(extensible, tag = u8)]
+	enum Message {
+		Data {
+			id: u8,
+
+			#[oct(align = 0x4)]
+			value: u32,
+		},
+	}
+
+	let mut buf = [0x00; 0x20];
+
+	let mut output = oct::encode::Output::new(&mut buf);
+	Versioned { id: 0x1, value: 0x12345678, flags: 0x3 }.encode(&mut output).unwrap();
+	Message::Data { id: 0x2, value: 0x9ABCDEF0 }.encode(&mut output).unwrap();
+
+	let data = output.as_slice();
+	assert_eq!(data, [
+		0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x78, 0x56,
+		0x34, 0x12, 0x03, 0x00, 0x00, 0x08, 0x00, 0x02,
+		0x00, 0x00, 0x00, 0xF0, 0xDE, 0xBC, 0x9A,
+	]);
+
+	let mut input = Input::new(data);
+	assert_eq!(Versioned::decode(&mut input).unwrap(), Versioned { id: 0x1, value: 0x12345678, flags: 0x3 });
+	assert_eq!(Message::decode(&mut input).unwrap(), Message::Data { id: 0x2, value: 0x9ABCDEF0 });
+	assert_eq!(input.remaining(), 0x0);
+}
+
+#[test]
+fn test_decode_derive_tag_from() {
+	use oct::error::{EnumDecodeError, GenericDecodeError};
+
+	#[derive(Debug, Decode, PartialEq)]
+	#[repr(u8)]
+	enum Body {
+		Ping = 0x1,
+
+		Data(u16) = 0x2,
+	}
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Frame {
+		kind: u8,
+
+		len: u8,
+
+		#[oct(tag_from = "kind")]
+		body: Body,
+	}
+
+	test!(Frame: [0x01, 0x00] => Frame { kind: 0x1, len: 0x0, body: Body::Ping });
+	test!(Frame: [0x02, 0x02, 0x34, 0x12] => Frame { kind: 0x2, len: 0x2, body: Body::Data(0x1234) });
+
+	let error = Frame::decode(&mut Input::new(&[0x03, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::UnassignedDiscriminant { value: 0x3 }));
+
+	let error = <Body as oct::decode::DecodeTagged>::decode_tagged(0x4, &mut Input::new(&[])).unwrap_err();
+	assert!(matches!(error, EnumDecodeError::UnassignedDiscriminant { value: 0x4 }));
+}
+
+#[test]
+fn test_decode_derive_len_from() {
+	use oct::SizedSlice;
+	use oct::error::{GenericDecodeError, LengthError};
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Table {
+		count: u16,
+
+		name_len: u8,
+
+		#[oct(len_from = "count")]
+		entries: Vec<u16>,
+
+		#[oct(len_from = "name_len")]
+		name: String,
+	}
+
+	test!(Table: [
+		0x02, 0x00, 0x03, 0x01, 0x00, 0x02, 0x00, b'f',
+		b'o', b'o',
+	] => Table { count: 0x2, name_len: 0x3, entries: vec![0x1, 0x2], name: String::from("foo") });
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Bounded(u8, #[oct(len_from = "0")] SizedSlice<u8, 0x2>);
+
+	test!(Bounded: [0x01, 0xFF] => Bounded(0x1, SizedSlice::new(&[0xFF]).unwrap()));
+
+	let error = Bounded::decode(&mut Input::new(&[0x03, 0x00, 0x00, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::SmallBuffer(LengthError { capacity: 0x2, len: 0x3 })));
+}
+
+#[test]
+fn test_decode_derive_condition() {
+	#[derive(Debug, Decode, PartialEq)]
+	struct Packet {
+		flags: u8,
+
+		#[oct(if = "*flags & 0x1 != 0x0")]
+		checksum: Option<u16>,
+
+		#[oct(if = "*flags & 0x2 != 0x0")]
+		timestamp: Option<u32>,
+	}
+
+	test!(Packet: [0x00] => Packet { flags: 0x0, checksum: None, timestamp: None });
+	test!(Packet: [0x01, 0x34, 0x12] => Packet { flags: 0x1, checksum: Some(0x1234), timestamp: None });
+	test!(Packet: [0x02, 0x78, 0x56, 0x34, 0x12] => Packet { flags: 0x2, checksum: None, timestamp: Some(0x12345678) });
+}
+
+#[test]
+fn test_decode_derive_repr() {
+	use oct::error::{BitFieldError, CastError, GenericDecodeError, VarintDecodeError};
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Header {
+		#[oct(be)]
+		magic: u32,
+
+		#[oct(le)]
+		version: u16,
+
+		#[oct(varint)]
+		len: u8,
+
+		#[oct(as = "u8")]
+		kind: u32,
+	}
+
+	test!(Header: [
+		0xCA, 0xFE, 0xBA, 0xBE, 0x01, 0x00, 0x7F, 0x03,
+	] => Header { magic: 0xCAFEBABE, version: 0x1, len: 0x7F, kind: 0x3 });
+
+	let error = Header::decode(&mut Input::new(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xAC, 0x02, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::BadVarint(VarintDecodeError { target: "u8" })));
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Message {
+		#[oct(varint)]
+		len: usize,
+	}
+
+	test!(Message: [0xAC, 0x02] => Message { len: 0x12C });
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Narrow(#[oct(as = "u16")] u8);
+
+	test!(Narrow: [0xFF, 0x00] => Narrow(0xFF));
+
+	let error = Narrow::decode(&mut Input::new(&[0x00, 0x01])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::BadCast(CastError { target: "u8" })));
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Packed {
+		#[oct(bytes = 3)]
+		len: u32,
+
+		#[oct(be, bytes = 3)]
+		offset: i32,
+	}
+
+	test!(Packed: [0x56, 0x34, 0x12, 0xFF, 0xFF, 0xFE] => Packed { len: 0x123456, offset: -0x2 });
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Short(#[oct(bytes = 3)] u16);
+
+	let error = Short::decode(&mut Input::new(&[0x00, 0x00, 0x01])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::BadBits(BitFieldError { width: 0x18 })));
+}
+
+#[test]
+fn test_decode_derive_bits() {
+	use oct::error::{BitFieldError, GenericDecodeError};
+
+	#[derive(Debug, Decode, PartialEq)]
+	struct Status {
+		#[oct(bits = 2)]
+		enabled: bool,
+
+		#[oct(bits = 3)]
+		level: u8,
+
+		#[oct(bits = 5)]
+		offset: i8,
+
+		id: u16,
+	}
+
+	test!(Status: [0b1111_1001, 0b0000_0011, 0x34, 0x12] => Status { enabled: true, level: 0x6, offset: -0x1, id: 0x1234 });
+
+	let error = Status::decode(&mut Input::new(&[0b0000_0010, 0x00, 0x00, 0x00])).unwrap_err();
+	assert!(matches!(error, GenericDecodeError::BadBits(BitFieldError { width: 0x2 })));
+}
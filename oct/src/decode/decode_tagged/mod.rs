@@ -0,0 +1,43 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrimitiveDiscriminant;
+use crate::decode::{Decode, Input};
+
+/// Denotes an enumeration that can be decoded with an externally-provided tag.
+///
+/// Where [`Decode`] reads the discriminant of an enumeration from the input, this trait instead takes it as an argument.
+/// This is used by the `tag_from` field attribute of the [`Decode`](derive@Decode) derive macro, which selects the variant of an enumeration using an earlier field of the same structure.
+///
+/// The decoded payload must be equivalent to what [`EncodeTagged::encode_untagged`](crate::encode::EncodeTagged::encode_untagged) would produce.
+///
+/// This trait is automatically implemented for enumerations that derive [`Decode`](derive@Decode).
+pub trait DecodeTagged: Decode {
+	/// The type of the tag.
+	type Tag: PrimitiveDiscriminant;
+
+	/// Decodes the variant denoted by `tag` from the provided input.
+	///
+	/// # Errors
+	///
+	/// If `tag` does not denote any variant, or if any of the fields could not be decoded, then an error should be returned.
+	fn decode_tagged(tag: Self::Tag, input: &mut Input) -> Result<Self, Self::Error>;
+}
@@ -0,0 +1,112 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::decode::{Decode, Input};
+
+#[cfg(feature = "alloc")]
+use crate::error::{CollectionDecodeError, ItemDecodeError, Utf8Error};
+
+#[cfg(feature = "alloc")]
+use core::ptr::copy_nonoverlapping;
+
+#[cfg(feature = "alloc")]
+use core::str;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Denotes a collection that can be decoded with an externally-provided length.
+///
+/// Collections such as [`Vec`] normally decode their length (as a [`usize`]) before their items.
+/// This trait instead takes the length as an argument, which is used by the `len_from` field attribute of the [`Decode`](derive@Decode) derive macro.
+///
+/// The decoded items must be equivalent to what [`EncodeUnprefixed::encode_unprefixed`](crate::encode::EncodeUnprefixed::encode_unprefixed) would produce.
+pub trait DecodeUnprefixed: Decode {
+	/// Decodes `len` items from the provided input.
+	///
+	/// For strings, `len` denotes the amount of bytes.
+	///
+	/// # Errors
+	///
+	/// If the collection cannot hold `len` items, or if any of the items could not be decoded, then an error should be returned.
+	fn decode_unprefixed(len: usize, input: &mut Input) -> Result<Self, Self::Error>;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl DecodeUnprefixed for String {
+	#[inline]
+	fn decode_unprefixed(len: usize, input: &mut Input) -> Result<Self, <Self as Decode>::Error> {
+		let data = input.read(len).unwrap();
+
+		if let Err(e) = str::from_utf8(data) {
+			let i = e.valid_up_to();
+			let c = data[i];
+
+			return Err(
+				CollectionDecodeError::BadItem(
+					Utf8Error { value: c, index: i },
+				),
+			);
+		};
+
+		let mut v = Vec::with_capacity(len);
+
+		unsafe {
+			let src = data.as_ptr();
+			let dst = v.as_mut_ptr();
+
+			copy_nonoverlapping(src, dst, len);
+			v.set_len(len);
+		}
+
+		// SAFETY: We have already tested the raw data.
+		let this = unsafe { Self::from_utf8_unchecked(v) };
+		Result::Ok(this)
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Decode> DecodeUnprefixed for Vec<T> {
+	#[inline]
+	fn decode_unprefixed(len: usize, input: &mut Input) -> Result<Self, <Self as Decode>::Error> {
+		let mut this = Self::with_capacity(len);
+
+		let buf = this.as_mut_ptr();
+		for i in 0x0..len {
+			let value = Decode::decode(input)
+				.map_err(|e| CollectionDecodeError::BadItem(ItemDecodeError { index: i, error: e }))?;
+
+			// SAFETY: Each index is within bounds (i.e. capac-
+			// ity).
+			unsafe { buf.add(i).write(value) };
+		}
+
+		// SAFETY: We have initialised the buffer.
+		unsafe { this.set_len(len); }
+
+		Result::Ok(this)
+	}
+}
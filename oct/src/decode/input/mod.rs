@@ -24,10 +24,16 @@ use crate::error::InputError;
 use core::ptr::copy_nonoverlapping;
 use core::slice;
 
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+
 /// Byte stream suitable for reading.
 pub struct Input<'a> {
 	buf: &'a [u8],
 	pos: usize,
+
+	#[cfg(feature = "bytes")]
+	src: Option<&'a Bytes>,
 }
 
 impl<'a> Input<'a> {
@@ -35,7 +41,29 @@ impl<'a> Input<'a> {
 	#[inline(always)]
 	#[must_use]
 	pub fn new(buf: &'a [u8]) -> Self {
-		Self { buf, pos: 0x0 }
+		Self {
+			buf,
+			pos: 0x0,
+
+			#[cfg(feature = "bytes")]
+			src: None,
+		}
+	}
+
+	/// Constructs a new input stream from a shared buffer.
+	///
+	/// Contrary to [`new`](Self::new), streams constructed using this method allow for [`read_bytes`](Self::read_bytes) to reference `buf` directly instead of copying from it.
+	#[cfg(feature = "bytes")]
+	#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+	#[inline(always)]
+	#[must_use]
+	pub fn from_bytes(buf: &'a Bytes) -> Self {
+		Self {
+			buf,
+			pos: 0x0,
+
+			src: Some(buf),
+		}
 	}
 
 	/// Reads bytes from the stream.
@@ -68,6 +96,30 @@ impl<'a> Input<'a> {
 		Ok(data)
 	}
 
+	/// Reads bytes from the stream into a shared buffer.
+	///
+	/// If the stream was constructed using [`from_bytes`](Self::from_bytes), then the returned buffer references the source buffer without copying.
+	/// Otherwise, the read bytes are copied into a new buffer.
+	///
+	/// # Panics
+	///
+	/// If the requested amount of bytes could not exactly be read, then this method will panic.
+	#[cfg(feature = "bytes")]
+	#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+	#[inline]
+	pub fn read_bytes(&mut self, count: usize) -> Result<Bytes, InputError> {
+		let start = self.pos;
+		let data  = self.read(count)?;
+
+		let bytes = match self.src {
+			Some(src) => src.slice(start..self.pos),
+
+			None => Bytes::copy_from_slice(data),
+		};
+
+		Ok(bytes)
+	}
+
 	/// Reads bytes from the stream into a predefined buffer.
 	///
 	/// This method may be preferred over [`read`](Self::read) if the read data **is** directly needed, e.g. if all required transformations can be done in-place.
@@ -100,6 +152,13 @@ impl<'a> Input<'a> {
 		Ok(())
 	}
 
+	/// Gets a slice of the already-read bytes in the input stream.
+	#[inline(always)]
+	#[must_use]
+	pub const fn as_slice(&self) -> &'a [u8] {
+		unsafe { slice::from_raw_parts(self.buf.as_ptr(), self.pos) }
+	}
+
 	/// Retrieves the maximum capacity of the input stream.
 	#[inline(always)]
 	#[must_use]
@@ -21,15 +21,61 @@
 
 //! Decoding-related facilities.
 
-use crate::use_mod;
-
 use_mod!(pub decode);
 use_mod!(pub decode_borrowed);
+use_mod!(pub decode_tagged);
+use_mod!(pub decode_unprefixed);
 use_mod!(pub input);
 
 /// Implements [`Decode`] for the provided type.
 ///
 /// This macro assumes the same format used by the equivalent [`Encode`](derive@crate::encode::Encode) macro.
+/// This includes the field attributes that are documented for the latter.
+///
+/// # Validation
+///
+/// Decoded values may be validated using the following attributes:
+///
+/// * `range = ...` on a field rejects values outside of the given range.
+/// * `max_len = ...` on a field rejects values with a greater length (as given by their `len` method).
+/// * `validate = "path"` on a field or on the structure or enumeration itself calls the given function with a reference to the decoded value.
+///   The function must return <code>[Result]&lt;(), [ValidationError]&gt;</code>.
+///
+/// Fields are validated immediately after being decoded, whilst the container is validated after all of its fields.
+/// Failed validations are converted into [`GenericDecodeError::Invalid`](crate::error::GenericDecodeError::Invalid):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::error::{GenericDecodeError, ValidationError};
+///
+/// fn validate_range(this: &Range) -> Result<(), ValidationError> {
+///     if this.start > this.end {
+///         return Err(ValidationError::Invalid { message: "start is after end" });
+///     }
+///
+///     Ok(())
+/// }
+///
+/// #[derive(Debug, Decode)]
+/// #[oct(validate = "validate_range")]
+/// struct Range {
+///     #[oct(range = 0..=100)]
+///     start: u8,
+///
+///     #[oct(range = 0..=100)]
+///     end: u8,
+/// }
+///
+/// let error = Range::decode(&mut Input::new(&[0x00, 0xFF])).unwrap_err();
+/// assert!(matches!(error, GenericDecodeError::Invalid(ValidationError::OutOfRange)));
+///
+/// let error = Range::decode(&mut Input::new(&[0x02, 0x01])).unwrap_err();
+/// assert!(matches!(error, GenericDecodeError::Invalid(ValidationError::Invalid { .. })));
+/// ```
+///
+/// [Generated error types](derive@crate::encode::Encode#error-types) instead have an `Invalid` variant for the container and a variant for each validated field, prefixed with `Invalid` (e.g. `InvalidStart`), so that the path to the field is kept.
+///
+/// [ValidationError]: crate::error::ValidationError
 #[cfg(feature = "proc-macro")]
 #[cfg_attr(doc, doc(cfg(feature = "proc-macro")))]
 #[doc(inline)]
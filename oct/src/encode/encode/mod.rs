@@ -22,7 +22,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::encode::Output;
+use crate::encode::{EncodeUnprefixed, Output};
 use crate::error::{
 	CollectionEncodeError,
 	EnumEncodeError,
@@ -35,7 +35,6 @@ use crate::error::{
 use core::cell::{Cell, LazyCell, RefCell};
 use core::convert::Infallible;
 use core::ffi::CStr;
-use core::hash::BuildHasher;
 use core::hint::unreachable_unchecked;
 use core::marker::{PhantomData, PhantomPinned};
 use core::net::{
@@ -82,9 +81,15 @@ use alloc::rc::Rc;
 #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 use alloc::sync::Arc;
 
+#[cfg(feature = "bytes")]
+use bytes::{Bytes, BytesMut};
+
 #[cfg(feature = "std")]
 use std::collections::{HashMap, HashSet};
 
+#[cfg(feature = "std")]
+use std::hash::BuildHasher;
+
 #[cfg(feature = "std")]
 use std::sync::{LazyLock, Mutex, RwLock};
 
@@ -204,13 +209,7 @@ impl<T: Encode> Encode for [T] {
 			.encode(output)
 			.map_err(CollectionEncodeError::BadLength)?;
 
-		for (i,v) in self.iter().enumerate() {
-			v
-				.encode(output)
-				.map_err(|e| CollectionEncodeError::BadItem(ItemEncodeError { index: i, error: e }))?;
-		}
-
-		Result::Ok(())
+		self.encode_unprefixed(output)
 	}
 }
 
@@ -271,6 +270,28 @@ impl<T: Encode + ?Sized> Encode for Box<T> {
 	}
 }
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl Encode for Bytes {
+	type Error = <[u8] as Encode>::Error;
+
+	#[inline(always)]
+	fn encode(&self, output: &mut Output) -> Result<(), Self::Error> {
+		<[u8]>::encode(self, output)
+	}
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl Encode for BytesMut {
+	type Error = <[u8] as Encode>::Error;
+
+	#[inline(always)]
+	fn encode(&self, output: &mut Output) -> Result<(), Self::Error> {
+		<[u8]>::encode(self, output)
+	}
+}
+
 impl<T: Copy + Encode> Encode for Cell<T> {
 	type Error = T::Error;
 
@@ -919,7 +940,7 @@ macro_rules! impl_atomic {
 			/// The atomic object itself is read with the [`Relaxed`](core::sync::atomic::Ordering) ordering scheme.
 			#[inline(always)]
 			fn encode(&self, output: &mut ::oct::encode::Output) -> ::core::result::Result<(), Self::Error> {
-				use ::std::sync::atomic::Ordering;
+				use ::core::sync::atomic::Ordering;
 
 				self.load(Ordering::Relaxed).encode(output)
 			}
@@ -1068,65 +1089,65 @@ impl_non_zero!(usize);
 impl_atomic! {
 	width: "8",
 	ty: bool,
-	atomic_ty: std::sync::atomic::AtomicBool,
+	atomic_ty: core::sync::atomic::AtomicBool,
 }
 
 impl_atomic! {
 	width: "16",
 	ty: i16,
-	atomic_ty: std::sync::atomic::AtomicI16,
+	atomic_ty: core::sync::atomic::AtomicI16,
 }
 
 impl_atomic! {
 	width: "32",
 	ty: i32,
-	atomic_ty: std::sync::atomic::AtomicI32,
+	atomic_ty: core::sync::atomic::AtomicI32,
 }
 
 impl_atomic! {
 	width: "64",
 	ty: i64,
-	atomic_ty: std::sync::atomic::AtomicI64,
+	atomic_ty: core::sync::atomic::AtomicI64,
 }
 
 impl_atomic! {
 	width: "8",
 	ty: i8,
-	atomic_ty: std::sync::atomic::AtomicI8,
+	atomic_ty: core::sync::atomic::AtomicI8,
 }
 
 impl_atomic! {
 	width: "ptr",
 	ty: isize,
-	atomic_ty: std::sync::atomic::AtomicIsize,
+	atomic_ty: core::sync::atomic::AtomicIsize,
 }
 
 impl_atomic! {
 	width: "16",
 	ty: u16,
-	atomic_ty: std::sync::atomic::AtomicU16,
+	atomic_ty: core::sync::atomic::AtomicU16,
 }
 
 impl_atomic! {
 	width: "32",
 	ty: u32,
-	atomic_ty: std::sync::atomic::AtomicU32,
+	atomic_ty: core::sync::atomic::AtomicU32,
 }
 
 impl_atomic! {
 	width: "64",
 	ty: u64,
-	atomic_ty: std::sync::atomic::AtomicU64,
+	atomic_ty: core::sync::atomic::AtomicU64,
 }
 
 impl_atomic! {
 	width: "8",
 	ty: u8,
-	atomic_ty: std::sync::atomic::AtomicU8,
+	atomic_ty: core::sync::atomic::AtomicU8,
 }
 
 impl_atomic! {
 	width: "ptr",
 	ty: usize,
-	atomic_ty: std::sync::atomic::AtomicUsize,
+	atomic_ty: core::sync::atomic::AtomicUsize,
 }
@@ -20,7 +20,9 @@
 // not, see <https://www.gnu.org/licenses/>.
 
 use oct::SizedStr;
+use oct::decode::{Decode, Input};
 use oct::encode::{Encode, Output, SizedEncode};
+use std::convert::Infallible;
 use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::vec;
@@ -113,3 +115,472 @@ fn test_encode_derive() {
 		0x00, 0x00, 0x00, 0x00, 0x00,
 	]);
 }
+
+#[test]
+fn test_encode_derive_skip() {
+	struct Handle;
+
+	#[derive(Encode)]
+	struct Session {
+		id: u16,
+
+		#[oct(skip)]
+		_handle: Handle,
+
+		flags: u8,
+	}
+
+	#[derive(Encode)]
+	#[repr(u8)]
+	enum Event {
+		Open(#[oct(skip)] Handle, u16),
+	}
+
+	test!(Session: Session { id: 0x2A, _handle: Handle, flags: 0x3 } => [0x2A, 0x00, 0x03]);
+
+	test!(Event: Event::Open(Handle, 0x2A) => [0x00, 0x2A, 0x00]);
+}
+
+#[test]
+fn test_encode_derive_with() {
+	mod millis {
+		use oct::encode::{Encode, Output};
+		use std::convert::Infallible;
+		use std::time::Duration;
+
+		pub fn encode(value: &Duration, output: &mut Output) -> Result<(), Infallible> {
+			let value = u32::try_from(value.as_millis()).unwrap();
+
+			value.encode(output)
+		}
+	}
+
+	fn encode_flag(value: &bool, output: &mut Output) -> Result<(), Infallible> {
+		u8::from(*value).encode(output)
+	}
+
+	#[derive(Encode)]
+	struct Timeout {
+		#[oct(with = "millis")]
+		delay: Duration,
+
+		#[oct(encode_with = "encode_flag")]
+		retry: bool,
+	}
+
+	#[derive(Encode)]
+	#[repr(u8)]
+	enum Event {
+		Sleep(#[oct(with = "millis")] Duration),
+	}
+
+	test!(Timeout: Timeout { delay: Duration::from_secs(0x1), retry: true } => [0xE8, 0x03, 0x00, 0x00, 0x01]);
+
+	test!(Event: Event::Sleep(Duration::from_millis(0xFA)) => [0x00, 0xFA, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_encode_derive_generic() {
+	use std::marker::PhantomData;
+
+	struct Unit;
+
+	#[derive(Encode)]
+	struct Wrapper<T>(T, PhantomData<Unit>);
+
+	#[derive(Encode)]
+	enum Either<L, R> {
+		Left(L),
+
+		Right(R),
+	}
+
+	#[derive(Encode)]
+	struct Bounded<T: Copy> {
+		#[oct(bound = "T: Encode<Error = Infallible> + Copy")]
+		value: T,
+	}
+
+	#[derive(Encode)]
+	#[oct(bound = "")]
+	struct Tagged<T> {
+		#[oct(skip)]
+		_marker: T,
+
+		id: u8,
+	}
+
+	#[expect(clippy::unnecessary_wraps)]
+	fn decode_none<T>(_input: &mut Input) -> Result<Option<T>, Infallible> {
+		Ok(None)
+	}
+
+	#[derive(Decode, Encode)]
+	struct Hint<T> {
+		#[oct(decode_with = "decode_none")]
+		value: Option<T>,
+	}
+
+	test!(Wrapper<u16>: Wrapper(0x1234, PhantomData) => [0x34, 0x12]);
+
+	test!(Either<u8, char>: Either::Left(0x2A) => [0x00, 0x2A]);
+
+	test!(Either<u8, char>: Either::Right('A') => [0x01, 0x41, 0x00, 0x00, 0x00]);
+
+	test!(Bounded<u8>: Bounded { value: 0xFF } => [0xFF]);
+
+	test!(Tagged<Unit>: Tagged { _marker: Unit, id: 0x2 } => [0x02]);
+
+	test!(Hint<u16>: Hint { value: Some(0x1234) } => [0x01, 0x34, 0x12]);
+}
+
+#[test]
+fn test_encode_derive_tag() {
+	const BASE: u8 = 0x10;
+
+	#[derive(Encode)]
+	#[oct(tag = u8)]
+	#[repr(u32)]
+	enum Command {
+		#[oct(tag_value = BASE)]
+		Start,
+
+		Stop,
+
+		#[oct(tag_value = BASE * 0x2)]
+		Reset(u16),
+
+		Halt = 0x40,
+	}
+
+	#[derive(Encode)]
+	#[oct(tag = u16)]
+	#[repr(C, align(0x4))]
+	enum Status {
+		Idle,
+
+		Busy(u8),
+	}
+
+	test!(Command: Command::Start => [0x10]);
+
+	test!(Command: Command::Stop => [0x11]);
+
+	test!(Command: Command::Reset(0x1234) => [0x20, 0x34, 0x12]);
+
+	test!(Command: Command::Halt => [0x40]);
+
+	test!(Status: Status::Idle => [0x00, 0x00]);
+
+	test!(Status: Status::Busy(0xFF) => [0x01, 0x00, 0xFF]);
+}
+
+#[test]
+fn test_encode_derive_extensible() {
+	#[derive(Encode)]
+	#[oct(extensible, tag = u8)]
+	enum Message {
+		Ping(u8),
+
+		#[oct(other)]
+		Unknown { tag: u8, payload: Vec<u8> },
+	}
+
+	test!(Message: Message::Ping(0x2A) => [0x00, 0x01, 0x00, 0x2A]);
+
+	test!(Message: Message::Unknown { tag: 0x7, payload: vec![0x1, 0x2] } => [0x07, 0x02, 0x00, 0x01, 0x02]);
+}
+
+#[test]
+fn test_encode_derive_versioned() {
+	#[derive(Encode)]
+	struct Config {
+		id: u16,
+
+		#[oct(since = 1)]
+		flags: u8,
+	}
+
+	#[derive(Encode)]
+	#[oct(versioned)]
+	struct Initial {
+		id: u16,
+	}
+
+	test!(Config: Config { id: 0x2A, flags: 0x1 } => [0x03, 0x00, 0x2A, 0x00, 0x01]);
+
+	test!(Initial: Initial { id: 0x2A } => [0x02, 0x00, 0x2A, 0x00]);
+}
+
+#[test]
+fn test_encode_derive_proxy() {
+	#[derive(Encode)]
+	struct RawTemperature {
+		kelvin: u16,
+	}
+
+	#[derive(Clone, Encode)]
+	#[oct(into = "RawTemperature")]
+	struct Temperature(f32);
+
+	impl From<Temperature> for RawTemperature {
+		fn from(value: Temperature) -> Self {
+			let kelvin = (value.0 + 273.0) as u16;
+
+			Self { kelvin }
+		}
+	}
+
+	test!(Temperature: Temperature(25.0) => [0x2A, 0x01]);
+}
+
+#[test]
+fn test_encode_derive_magic() {
+	#[derive(Encode)]
+	#[oct(magic = b"OCT1")]
+	struct Header {
+		#[oct(constant = 0xCAFEu16)]
+		version: u16,
+
+		len: u8,
+	}
+
+	test!(Header: Header { version: 0x0, len: 0x3 } => [b'O', b'C', b'T', b'1', 0xFE, 0xCA, 0x03]);
+}
+
+#[test]
+fn test_encode_derive_padding() {
+	#[derive(Encode)]
+	struct Register {
+		id: u8,
+
+		#[oct(align = 0x4)]
+		value: u32,
+
+		#[oct(pad = 0x2)]
+		flags: u8,
+
+		#[oct(reserved)]
+		reserved: [u8; 0x3],
+	}
+
+	test!(Register: Register { id: 0x1, value: 0x12345678, flags: 0x3, reserved: [0xFF; 0x3] } => [
+		0x01, 0x00, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12,
+		0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+	]);
+}
+
+#[test]
+fn test_encode_derive_error() {
+	use oct::error::UsizeEncodeError;
+	use std::string::ToString;
+
+	#[derive(Encode)]
+	#[oct(error = "generate")]
+	struct Chunk {
+		id:  u8,
+		len: usize,
+	}
+
+	#[derive(Encode)]
+	#[oct(error = "generate")]
+	enum Message {
+		Data(Chunk),
+	}
+
+	test!(Message: Message::Data(Chunk { id: 0x1, len: 0x2 }) => [0x00, 0x01, 0x02, 0x00]);
+
+	let mut buf = [0x00; 0x4];
+
+	let error = Message::Data(Chunk { id: 0x1, len: 0x10000 }).encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, MessageEncodeError::Data0(ChunkEncodeError::Len(UsizeEncodeError(0x10000)))));
+	assert_eq!(error.to_string(), "`Message::Data.0.len`: unsigned size value (65536) cannot be serialised: must be at most (65535)");
+}
+
+#[test]
+fn test_encode_derive_tag_from() {
+	use oct::encode::EncodeTagged;
+	use oct::error::{GenericEncodeError, TagMismatchError};
+
+	#[derive(Encode)]
+	#[repr(u8)]
+	enum Body {
+		Ping = 0x1,
+
+		Data(u16) = 0x2,
+	}
+
+	#[derive(Encode)]
+	struct Frame {
+		kind: u8,
+
+		#[oct(tag_from = "kind")]
+		body: Body,
+	}
+
+	#[derive(Encode)]
+	#[oct(error = "generate")]
+	struct CheckedFrame(u8, #[oct(tag_from = "0")] Body);
+
+	assert_eq!(Body::Data(0x0).tag(), 0x2);
+
+	test!(Frame: Frame { kind: 0x1, body: Body::Ping } => [0x01]);
+	test!(Frame: Frame { kind: 0x2, body: Body::Data(0x1234) } => [0x02, 0x34, 0x12]);
+
+	let mut buf = [0x00; 0x3];
+
+	let error = Frame { kind: 0x1, body: Body::Data(0x1234) }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadTag(TagMismatchError { expected: 0x1, found: 0x2 })));
+
+	let error = CheckedFrame(0x2, Body::Ping).encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, CheckedFrameEncodeError::BadTag(TagMismatchError { expected: 0x2, found: 0x1 })));
+}
+
+#[test]
+fn test_encode_derive_len_from() {
+	use oct::encode::EncodeUnprefixed;
+	use oct::error::{GenericEncodeError, LengthMismatchError};
+	use std::string::String;
+
+	#[derive(Encode)]
+	struct Table {
+		count: u16,
+
+		name_len: u8,
+
+		#[oct(len_from = "count")]
+		entries: Vec<u16>,
+
+		#[oct(len_from = "name_len")]
+		name: String,
+	}
+
+	#[derive(Encode)]
+	#[oct(error = "generate")]
+	struct CheckedTable(u8, #[oct(len_from = "0")] Vec<u8>);
+
+	assert_eq!("foo".encoded_len(), 0x3);
+
+	test!(Table: Table { count: 0x2, name_len: 0x3, entries: vec![0x1, 0x2], name: String::from("foo") } => [
+		0x02, 0x00, 0x03, 0x01, 0x00, 0x02, 0x00, b'f',
+		b'o', b'o',
+	]);
+
+	let mut buf = [0x00; 0xA];
+
+	let error = Table { count: 0x1, name_len: 0x3, entries: vec![0x1, 0x2], name: String::from("foo") }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadLength(LengthMismatchError { expected: 0x1, found: 0x2 })));
+
+	let error = CheckedTable(0x0, vec![0xFF]).encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, CheckedTableEncodeError::BadLength(LengthMismatchError { expected: 0x0, found: 0x1 })));
+}
+
+#[test]
+fn test_encode_derive_condition() {
+	use oct::error::{GenericEncodeError, PresenceError};
+
+	#[derive(Encode, SizedEncode)]
+	struct Packet {
+		flags: u8,
+
+		#[oct(if = "*flags & 0x1 != 0x0")]
+		checksum: Option<u16>,
+	}
+
+	assert_eq!(Packet::MAX_ENCODED_SIZE, 0x3);
+
+	test!(Packet: Packet { flags: 0x0, checksum: None } => [0x00]);
+	test!(Packet: Packet { flags: 0x1, checksum: Some(0x1234) } => [0x01, 0x34, 0x12]);
+
+	let mut buf = [0x00; 0x3];
+
+	let error = Packet { flags: 0x1, checksum: None }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadPresence(PresenceError { present: false })));
+
+	let error = Packet { flags: 0x0, checksum: Some(0x1234) }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadPresence(PresenceError { present: true })));
+}
+
+#[test]
+fn test_encode_derive_repr() {
+	use oct::error::{BitFieldError, CastError, GenericEncodeError};
+
+	#[derive(Encode, SizedEncode)]
+	struct Header {
+		#[oct(be)]
+		magic: u32,
+
+		#[oct(le)]
+		version: u16,
+
+		#[oct(varint)]
+		len: u64,
+
+		#[oct(varint)]
+		offset: i32,
+
+		#[oct(as = "u8")]
+		kind: u32,
+	}
+
+	assert_eq!(Header::MAX_ENCODED_SIZE, 0x16);
+
+	test!(Header: Header { magic: 0xCAFEBABE, version: 0x1, len: 0x12C, offset: -0x2, kind: 0x3 } => [
+		0xCA, 0xFE, 0xBA, 0xBE, 0x01, 0x00, 0xAC, 0x02,
+		0x03, 0x03,
+	]);
+
+	let mut buf = [0x00; 0x16];
+
+	let error = Header { magic: 0x0, version: 0x0, len: 0x0, offset: 0x0, kind: 0x100 }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadCast(CastError { target: "u8" })));
+
+	#[derive(Encode, SizedEncode)]
+	struct Packed {
+		#[oct(bytes = 3)]
+		len: u32,
+
+		#[oct(be, bytes = 3)]
+		offset: i32,
+	}
+
+	assert_eq!(Packed::MAX_ENCODED_SIZE, 0x6);
+
+	test!(Packed: Packed { len: 0x123456, offset: -0x2 } => [0x56, 0x34, 0x12, 0xFF, 0xFF, 0xFE]);
+
+	let error = Packed { len: 0x1000000, offset: 0x0 }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadBits(BitFieldError { width: 0x18 })));
+}
+
+#[test]
+fn test_encode_derive_bits() {
+	use oct::error::{BitFieldError, GenericEncodeError};
+
+	#[derive(Encode, SizedEncode)]
+	struct Status {
+		#[oct(bits = 1)]
+		enabled: bool,
+
+		#[oct(bits = 3)]
+		level: u8,
+
+		#[oct(bits = 5)]
+		offset: i8,
+
+		id: u16,
+
+		#[oct(bits = 2)]
+		mode: u8,
+	}
+
+	assert_eq!(Status::MAX_ENCODED_SIZE, 0x5);
+
+	test!(Status: Status { enabled: true, level: 0x5, offset: -0x1, id: 0x1234, mode: 0x2 } => [
+		0b1111_1011, 0b0000_0001, 0x34, 0x12, 0b0000_0010,
+	]);
+
+	let mut buf = [0x00; 0x5];
+
+	let error = Status { enabled: false, level: 0x8, offset: 0x0, id: 0x0, mode: 0x0 }.encode(&mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadBits(BitFieldError { width: 0x3 })));
+}
@@ -0,0 +1,46 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrimitiveDiscriminant;
+use crate::encode::{Encode, Output};
+
+/// Denotes an enumeration that can be encoded without its tag.
+///
+/// This is the counterpart to [`DecodeTagged`](crate::decode::DecodeTagged) and is used by the `tag_from` field attribute of the [`Encode`](derive@Encode) derive macro.
+///
+/// This trait is automatically implemented for enumerations that derive [`Encode`](derive@Encode).
+pub trait EncodeTagged: Encode {
+	/// The type of the tag.
+	type Tag: PrimitiveDiscriminant;
+
+	/// Gets the tag of the current variant.
+	#[must_use]
+	fn tag(&self) -> Self::Tag;
+
+	/// Encodes the fields of the current variant into the provided output.
+	///
+	/// Contrary to [`Encode::encode`], the tag itself is not encoded.
+	///
+	/// # Errors
+	///
+	/// If any of the fields could not be encoded, then an error should be returned.
+	fn encode_untagged(&self, output: &mut Output) -> Result<(), Self::Error>;
+}
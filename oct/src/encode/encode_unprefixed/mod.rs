@@ -0,0 +1,107 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::encode::{Encode, Output};
+use crate::error::{CollectionEncodeError, ItemEncodeError};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Denotes a collection that can be encoded without its length.
+///
+/// This is the counterpart to [`DecodeUnprefixed`](crate::decode::DecodeUnprefixed) and is used by the `len_from` field attribute of the [`Encode`](derive@Encode) derive macro.
+pub trait EncodeUnprefixed: Encode {
+	/// Gets the length that would otherwise prefix the collection.
+	///
+	/// For strings, this is the amount of bytes.
+	#[must_use]
+	fn encoded_len(&self) -> usize;
+
+	/// Encodes the items of the collection into the provided output.
+	///
+	/// Contrary to [`Encode::encode`], the length of the collection is not encoded.
+	///
+	/// # Errors
+	///
+	/// If any of the items could not be encoded, then an error should be returned.
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error>;
+}
+
+impl<T: Encode> EncodeUnprefixed for [T] {
+	#[inline(always)]
+	fn encoded_len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error> {
+		for (i,v) in self.iter().enumerate() {
+			v
+				.encode(output)
+				.map_err(|e| CollectionEncodeError::BadItem(ItemEncodeError { index: i, error: e }))?;
+		}
+
+		Result::Ok(())
+	}
+}
+
+impl EncodeUnprefixed for str {
+	#[inline(always)]
+	fn encoded_len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error> {
+		self.as_bytes().encode_unprefixed(output)
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl EncodeUnprefixed for String {
+	#[inline(always)]
+	fn encoded_len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error> {
+		self.as_str().encode_unprefixed(output)
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Encode> EncodeUnprefixed for Vec<T> {
+	#[inline(always)]
+	fn encoded_len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error> {
+		self.as_slice().encode_unprefixed(output)
+	}
+}
@@ -0,0 +1,377 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::encode::SizedEncode;
+
+use core::cell::{Cell, RefCell};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use core::net::{
+	Ipv4Addr,
+	Ipv6Addr,
+	SocketAddrV4,
+	SocketAddrV6,
+};
+use core::num::{Saturating, Wrapping};
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+/// Denotes a fixed-size, encodable type.
+///
+/// Whereas [`SizedEncode`] only defines an upper bound for the sizes of encodings, this trait additionally guarantees that *every* successful encoding of the type consumes exactly [`MAX_ENCODED_SIZE`](SizedEncode::MAX_ENCODED_SIZE) bytes.
+/// Sequences of such values can therefore be treated as records, allowing for random access without decoding the preceding values.
+///
+/// The trait itself does not define any items.
+///
+/// Also note that -- like with `SizedEncode` -- this trait is **not** strictly enforceable.
+/// Implementors should take care to only implement it for types whose encodings never vary in size.
+///
+/// # Examples
+///
+/// Locating the third element of an encoded sequence:
+///
+/// ```rust
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, FixedEncode, Output, SizedEncode};
+/// use std::fmt::Debug;
+///
+/// fn nth<T: Decode<Error: Debug> + FixedEncode>(buf: &[u8], index: usize) -> T {
+///     let start = index * T::MAX_ENCODED_SIZE;
+///     let stop  = start + T::MAX_ENCODED_SIZE;
+///
+///     let mut stream = Input::new(&buf[start..stop]);
+///     T::decode(&mut stream).unwrap()
+/// }
+///
+/// let values: [(u16, u32); 0x4] = [
+///     (0x0001, 0x61),
+///     (0x0002, 0x62),
+///     (0x0003, 0x63),
+///     (0x0004, 0x64),
+/// ];
+///
+/// let mut buf = [0x0; <[(u16, u32); 0x4]>::MAX_ENCODED_SIZE];
+///
+/// let mut stream = Output::new(&mut buf);
+/// values.encode(&mut stream).unwrap();
+///
+/// assert_eq!(nth::<(u16, u32)>(&buf, 0x2), (0x0003, 0x63));
+/// ```
+pub trait FixedEncode: SizedEncode { }
+
+impl<T: FixedEncode> FixedEncode for &T { }
+
+impl<T: FixedEncode> FixedEncode for &mut T { }
+
+/// Implemented for tuples with up to twelve members.
+#[cfg_attr(doc, doc(fake_variadic))]
+impl<T: FixedEncode> FixedEncode for (T, ) { }
+
+impl<T: FixedEncode, const N: usize> FixedEncode for [T; N] { }
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(doc, doc(cfg(all(feature = "alloc", target_has_atomic = "ptr"))))]
+impl<T: FixedEncode> FixedEncode for Arc<T> { }
+
+impl FixedEncode for bool { }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: FixedEncode> FixedEncode for Box<T> { }
+
+impl<T: Copy + FixedEncode> FixedEncode for Cell<T> { }
+
+impl FixedEncode for char { }
+
+impl FixedEncode for Duration { }
+
+impl FixedEncode for Infallible { }
+
+impl FixedEncode for Ipv4Addr { }
+
+impl FixedEncode for Ipv6Addr { }
+
+impl FixedEncode for isize { }
+
+impl<T> FixedEncode for PhantomData<T> { }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: FixedEncode> FixedEncode for Rc<T> { }
+
+impl<T: FixedEncode> FixedEncode for RefCell<T> { }
+
+impl<T: FixedEncode> FixedEncode for Saturating<T> { }
+
+impl FixedEncode for SocketAddrV4 { }
+
+impl FixedEncode for SocketAddrV6 { }
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl FixedEncode for SystemTime { }
+
+impl FixedEncode for () { }
+
+impl FixedEncode for usize { }
+
+impl<T: FixedEncode> FixedEncode for Wrapping<T> { }
+
+macro_rules! impl_numeric {
+	($ty:ty$(,)?) => {
+		impl ::oct::encode::FixedEncode for $ty { }
+	};
+}
+
+macro_rules! impl_tuple {
+	{
+		$($tys:ident),+$(,)?
+	} => {
+		#[doc(hidden)]
+		impl<$($tys, )* E> ::oct::encode::FixedEncode for ($($tys, )*)
+		where
+			$($tys: FixedEncode + ::oct::encode::Encode<Error = E>, )* { }
+	};
+}
+
+macro_rules! impl_non_zero {
+	($ty:ty$(,)?) => {
+		impl ::oct::encode::FixedEncode for ::core::num::NonZero<$ty> { }
+	};
+}
+
+macro_rules! impl_atomic {
+	{
+		width: $width:literal,
+		atomic_ty: $atomic_ty:ty$(,)?
+	} => {
+		#[cfg(target_has_atomic = $width)]
+		#[cfg_attr(doc, doc(cfg(target_has_atomic = $width)))]
+		impl ::oct::encode::FixedEncode for $atomic_ty { }
+	};
+}
+
+//impl_numeric!(f128);
+//impl_numeric!(f16);
+impl_numeric!(f32);
+impl_numeric!(f64);
+impl_numeric!(i128);
+impl_numeric!(i16);
+impl_numeric!(i32);
+impl_numeric!(i64);
+impl_numeric!(i8);
+impl_numeric!(u128);
+impl_numeric!(u16);
+impl_numeric!(u32);
+impl_numeric!(u64);
+impl_numeric!(u8);
+
+impl_tuple! {
+	T0,
+	T1,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+	T9,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+	T9,
+	T10,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+	T9,
+	T10,
+	T11,
+}
+
+impl_non_zero!(i128);
+impl_non_zero!(i16);
+impl_non_zero!(i32);
+impl_non_zero!(i64);
+impl_non_zero!(i8);
+impl_non_zero!(isize);
+impl_non_zero!(u128);
+impl_non_zero!(u16);
+impl_non_zero!(u32);
+impl_non_zero!(u64);
+impl_non_zero!(u8);
+impl_non_zero!(usize);
+
+impl_atomic! {
+	width: "8",
+	atomic_ty: core::sync::atomic::AtomicBool,
+}
+
+impl_atomic! {
+	width: "16",
+	atomic_ty: core::sync::atomic::AtomicI16,
+}
+
+impl_atomic! {
+	width: "32",
+	atomic_ty: core::sync::atomic::AtomicI32,
+}
+
+impl_atomic! {
+	width: "64",
+	atomic_ty: core::sync::atomic::AtomicI64,
+}
+
+impl_atomic! {
+	width: "8",
+	atomic_ty: core::sync::atomic::AtomicI8,
+}
+
+impl_atomic! {
+	width: "ptr",
+	atomic_ty: core::sync::atomic::AtomicIsize,
+}
+
+impl_atomic! {
+	width: "16",
+	atomic_ty: core::sync::atomic::AtomicU16,
+}
+
+impl_atomic! {
+	width: "32",
+	atomic_ty: core::sync::atomic::AtomicU32,
+}
+
+impl_atomic! {
+	width: "64",
+	atomic_ty: core::sync::atomic::AtomicU64,
+}
+
+impl_atomic! {
+	width: "8",
+	atomic_ty: core::sync::atomic::AtomicU8,
+}
+
+impl_atomic! {
+	width: "ptr",
+	atomic_ty: core::sync::atomic::AtomicUsize,
+}
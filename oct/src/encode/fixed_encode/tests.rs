@@ -0,0 +1,123 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::encode::{Encode, FixedEncode, Output, SizedEncode};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::net::{Ipv4Addr, SocketAddrV6};
+use std::num::NonZero;
+use std::time::Duration;
+use std::vec::Vec;
+
+macro_rules! assert_fixed_size {
+	($value:expr$(,)?) => {{
+		fn encoded_len<T: FixedEncode<Error: Debug>>(value: &T) -> usize {
+			let mut buf = [0x00; 0x40];
+
+			let mut stream = Output::new(&mut buf);
+			value.encode(&mut stream).unwrap();
+
+			assert_eq!(stream.position(), T::MAX_ENCODED_SIZE);
+			stream.position()
+		}
+
+		encoded_len(&$value)
+	}};
+}
+
+#[test]
+fn test_fixed_encode() {
+	assert_fixed_size!(false);
+	assert_fixed_size!('\0');
+	assert_fixed_size!('\u{10FFFF}');
+	assert_fixed_size!(0.0f32);
+	assert_fixed_size!(-0x1i64);
+	assert_fixed_size!(0x0u128);
+	assert_fixed_size!(0x0usize);
+	assert_fixed_size!(-0x1isize);
+	assert_fixed_size!(Duration::ZERO);
+	assert_fixed_size!(Duration::MAX);
+	assert_fixed_size!(NonZero::<u16>::MIN);
+	assert_fixed_size!(Ipv4Addr::LOCALHOST);
+	assert_fixed_size!(SocketAddrV6::new([0x0; 0x8].into(), 0x0, 0x0, 0x0));
+	assert_fixed_size!([0x0u32; 0x4]);
+	assert_fixed_size!((0x0u8, 'a', true));
+	assert_fixed_size!(PhantomData::<Vec<u8>>);
+	assert_fixed_size!(());
+}
+
+#[test]
+fn test_fixed_encode_derive() {
+	#[derive(Encode, FixedEncode, SizedEncode)]
+	struct Foo {
+		_value: char,
+
+		#[oct(skip)]
+		_cache: Vec<u8>,
+	}
+
+	#[derive(Encode, FixedEncode, SizedEncode)]
+	struct Bar<T> {
+		#[oct(be)]
+		id: u32,
+
+		value: T,
+
+		#[oct(as = "u8")]
+		kind: u32,
+
+		#[oct(bits = 3)]
+		x: u8,
+
+		#[oct(bits = 5)]
+		y: u8,
+
+		#[oct(pad = 0x2)]
+		flags: u8,
+	}
+
+	#[derive(Encode, FixedEncode, SizedEncode)]
+	#[oct(magic = b"OCT1", tag = u8)]
+	enum Baz {
+		Left(u16),
+
+		Right { value: [u8; 0x2] },
+	}
+
+	#[derive(Encode, FixedEncode, SizedEncode)]
+	#[oct(extensible, tag = u8)]
+	enum Qux {
+		Unit,
+
+		Other,
+	}
+
+	assert_eq!(assert_fixed_size!(Foo { _value: 'a', _cache: Vec::from([0x0; 0x10]) }), 0x4);
+
+	let bar = Bar { id: 0x1, value: 'b', kind: 0x2, x: 0x3, y: 0x4, flags: 0x5 };
+	assert_eq!(assert_fixed_size!(bar), 0xD);
+
+	assert_eq!(assert_fixed_size!(Baz::Left(0x0)), 0x7);
+	assert_eq!(assert_fixed_size!(Baz::Right { value: [0x0; 0x2] }), 0x7);
+
+	assert_eq!(assert_fixed_size!(Qux::Unit), 0x3);
+	assert_eq!(assert_fixed_size!(Qux::Other), 0x3);
+}
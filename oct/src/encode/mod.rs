@@ -21,9 +21,10 @@
 
 //! Encoding-related facilities.
 
-use crate::use_mod;
-
 use_mod!(pub encode);
+use_mod!(pub encode_tagged);
+use_mod!(pub encode_unprefixed);
+use_mod!(pub fixed_encode);
 use_mod!(pub output);
 use_mod!(pub sized_encode);
 
@@ -62,7 +63,7 @@ use_mod!(pub sized_encode);
 ///
 /// Enumerations encode like structures except that each variant additionally encodes a unique discriminant.
 ///
-/// By default, each discriminant is assigned from the range 0 to infinite, to the extend allowed by the [`isize`] type and its encoding (as which **all** discriminants are encoded).
+/// By default, each discriminant is assigned from the range 0 to infinite.
 /// A custom discriminant may be set instead by assigning the variant an integer constant.
 /// Unspecified discriminants then increment the previous variant's discriminant:
 ///
@@ -81,21 +82,95 @@ use_mod!(pub sized_encode);
 ///     One,
 /// }
 ///
-/// let mut buf = Slot::with_capacity(size_of::<i16>());
+/// let mut buf = Slot::with_capacity(size_of::<u8>());
 ///
 /// buf.write(Num::Zero).unwrap();
-/// assert_eq!(buf, [0x00, 0x00].as_slice());
+/// assert_eq!(buf, [0x00].as_slice());
 ///
 /// buf.write(Num::One).unwrap();
-/// assert_eq!(buf, [0x01, 0x00].as_slice());
+/// assert_eq!(buf, [0x01].as_slice());
 ///
 /// buf.write(Num::Two).unwrap();
-/// assert_eq!(buf, [0x02, 0x00].as_slice());
+/// assert_eq!(buf, [0x02].as_slice());
 ///
 /// buf.write(Num::Three).unwrap();
-/// assert_eq!(buf, [0x03, 0x00].as_slice());
+/// assert_eq!(buf, [0x03].as_slice());
+/// ```
+///
+/// Unless otherwise specified (see below), **all** discriminants are encoded as the smallest unsigned integer type that can hold every discriminant of the enumeration -- usually [`u8`].
+/// If any discriminant is not an integer literal, then [`isize`] is used instead.
+///
+/// If the enumeration has a `repr` attribute naming a primitive integer type, then discriminants are instead encoded as the given type.
+/// Other representations -- such as `C` or `align` -- do not affect the encoding.
+/// As this also affects the in-memory layout of the enumeration, a different type may instead be chosen using the `tag` attribute.
+/// Encodings from before the introduction of automatic tag widths can be kept compatible by specifying `#[oct(tag = isize)]`.
+/// Individual discriminants may likewise be set using the `tag_value` attribute, which -- unlike plain discriminants -- accepts any constant expression:
+///
+/// ```
+/// use oct::Slot;
+/// use oct::encode::Encode;
+///
+/// const BASE: u8 = 0x10;
+///
+/// #[derive(Encode)]
+/// #[oct(tag = u8)]
+/// #[repr(u32)]
+/// enum Command {
+///     #[oct(tag_value = BASE)]
+///     Start,
+///
+///     Stop,
+///
+///     #[oct(tag_value = BASE * 0x2)]
+///     Reset,
+/// }
+///
+/// let mut buf = Slot::with_capacity(size_of::<u8>());
+///
+/// buf.write(Command::Stop).unwrap();
+/// assert_eq!(buf, [0x11].as_slice());
+///
+/// buf.write(Command::Reset).unwrap();
+/// assert_eq!(buf, [0x20].as_slice());
 /// ```
 ///
+/// Discriminants are checked for uniqueness at compile-time.
+///
+/// ## Extensible enumerations
+///
+/// Enumerations marked with the `extensible` attribute additionally prefix the fields of each variant with their total length (encoded as a [`usize`]).
+/// This allows older decoders to skip variants and fields that were added by newer encoders.
+///
+/// A variant marked with the `other` attribute captures any variant with an unassigned discriminant.
+/// Such a variant must have exactly two fields: the first receives the discriminant, and the second receives the raw payload (using <code>[From]&lt;&amp;[u8]&gt;</code>).
+/// When encoded, the variant is written back using the same discriminant and payload (using <code>[AsRef]&lt;[u8]&gt;</code>):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq)]
+/// #[oct(extensible, tag = u8)]
+/// enum Event {
+///     Click { x: u16, y: u16 },
+///
+///     #[oct(other)]
+///     Unknown(u8, Vec<u8>),
+/// }
+///
+/// let data = [0x05, 0x03, 0x00, 0x01, 0x02, 0x03];
+///
+/// let event = Event::decode(&mut Input::new(&data)).unwrap();
+/// assert_eq!(event, Event::Unknown(0x5, vec![0x01, 0x02, 0x03]));
+///
+/// let mut buf = [0x00; 0x6];
+/// event.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, data);
+/// ```
+///
+/// As the tag of unknown variants must be stored as-is, the `tag` attribute should in general be specified on extensible enumerations.
+/// Enumerations with an `other` variant cannot derive [`SizedEncode`](derive@SizedEncode).
+///
 /// Variants with fields are encoded exactly like structures.
 /// That is, each field is chained in order of declaration.
 ///
@@ -107,6 +182,534 @@ use_mod!(pub sized_encode);
 ///
 /// wherein `Repr` is the enumeration's representation.
 ///
+/// # Magic values
+///
+/// Structures and enumerations may be prefixed with a fixed byte string using the `magic` attribute.
+/// Likewise, fields may be given a fixed value using the `constant` attribute, in which case the constant is encoded instead of the field's actual value.
+///
+/// When decoding, the magic value and constants are checked against the input, with any mismatch yielding [`MagicError`](crate::error::MagicError) (converted into [`GenericDecodeError::BadMagic`](crate::error::GenericDecodeError::BadMagic)).
+/// Constant fields are decoded as their constant value.
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// #[oct(magic = b"OCT1")]
+/// struct Header {
+///     #[oct(constant = 0xCAFEu16)]
+///     version: u16,
+///
+///     len: u8,
+/// }
+///
+/// assert_eq!(Header::MAX_ENCODED_SIZE, 0x7);
+///
+/// let mut input = Input::new(&[b'O', b'C', b'T', b'1', 0xFE, 0xCA, 0x03]);
+/// assert_eq!(Header::decode(&mut input).unwrap(), Header { version: 0xCAFE, len: 0x3 });
+///
+/// let mut input = Input::new(&[b'O', b'C', b'T', b'2', 0xFE, 0xCA, 0x03]);
+/// assert!(Header::decode(&mut input).is_err());
+/// ```
+///
+/// Both magic values and the encodings of constants may be at most [`MagicError::MAX_LEN`](crate::error::MagicError::MAX_LEN) bytes long.
+/// The magic value is encoded before anything else (including enumeration discriminants), and is counted by [`SizedEncode`](trait@SizedEncode).
+/// Decoding constant fields requires their types to implement [`SizedEncode`](trait@SizedEncode).
+///
+/// # Proxies
+///
+/// Structures and enumerations may instead be encoded through another type using the `into` attribute.
+/// The value is then cloned and converted into the given type before being encoded, thus requiring <code>Self: [Clone] + [Into]&lt;Proxy&gt;</code>.
+/// The `Error` type and the [`SizedEncode`](trait@SizedEncode) implementation are likewise those of the proxy type.
+///
+/// Conversely, [`Decode`](derive@crate::decode::Decode) supports the `from` and `try_from` attributes, which decode the proxy type and convert it using [`From`] or [`TryFrom`].
+/// Failed conversions yield [`ProxyDecodeError::BadConversion`](crate::error::ProxyDecodeError::BadConversion):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output};
+/// use std::num::{NonZeroU64, TryFromIntError};
+///
+/// #[derive(Clone, Debug, Decode, Encode, PartialEq)]
+/// #[oct(try_from = "u64", into = "u64")]
+/// struct UserId(NonZeroU64);
+///
+/// impl TryFrom<u64> for UserId {
+///     type Error = TryFromIntError;
+///
+///     fn try_from(value: u64) -> Result<Self, Self::Error> {
+///         NonZeroU64::try_from(value).map(Self)
+///     }
+/// }
+///
+/// impl From<UserId> for u64 {
+///     fn from(value: UserId) -> Self {
+///         value.0.get()
+///     }
+/// }
+///
+/// let mut buf = [0x00; 0x8];
+/// UserId(NonZeroU64::new(0x2A).unwrap()).encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0x2A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+///
+/// assert!(UserId::decode(&mut Input::new(&[0x00; 0x8])).is_err());
+/// ```
+///
+/// Types with proxies share the [fingerprint](derive@crate::fingerprint::Fingerprint) of their `into` type, or otherwise that of their `from` or `try_from` type.
+///
+/// # Field attributes
+///
+/// The encoding of individual fields -- in both structures and enumeration variants -- can be altered using the `oct` attribute.
+///
+/// ## `skip`
+///
+/// The `skip` attribute omits the field from the encoding entirely.
+/// This is useful for fields that only hold runtime state, such as caches or handles:
+///
+/// ```
+/// use oct::decode::Decode;
+/// use oct::encode::{Encode, SizedEncode};
+/// use oct::Slot;
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Sensor {
+///     id: u16,
+///
+///     #[oct(skip)]
+///     hits: u32,
+///
+///     #[oct(skip, default = "unknown_name")]
+///     name: String,
+/// }
+///
+/// fn unknown_name() -> String {
+///     String::from("unknown")
+/// }
+///
+/// let mut buf = Slot::<Sensor>::new();
+///
+/// buf.write(Sensor { id: 0x2, hits: 0x10, name: String::from("barometer") }).unwrap();
+/// assert_eq!(buf, [0x02, 0x00].as_slice());
+///
+/// assert_eq!(buf.read().unwrap(), Sensor { id: 0x2, hits: 0x0, name: String::from("unknown") });
+/// ```
+///
+/// When decoding, skipped fields are constructed using [`Default`], or using the function specified by the `default` attribute.
+/// The `default` attribute is also accepted on versioned and reserved fields (see below), but is rejected on any other field, as its value would never be used.
+/// Skipped fields are also not required to implement `Encode` or `Decode`.
+///
+/// ## `with`
+///
+/// The `with` attribute replaces the field's own implementations of `Encode` and `Decode` with the `encode` and `decode` functions of the given module:
+///
+/// ```
+/// use oct::decode::Decode;
+/// use oct::encode::{Encode, SizedEncode};
+/// use oct::Slot;
+/// use std::time::Duration;
+///
+/// mod millis {
+///     use oct::decode::{Decode, Input};
+///     use oct::encode::{Encode, Output};
+///     use oct::error::{GenericDecodeError, GenericEncodeError};
+///     use std::time::Duration;
+///
+///     pub fn encode(value: &Duration, output: &mut Output) -> Result<(), GenericEncodeError> {
+///         let value = u32::try_from(value.as_millis()).unwrap();
+///
+///         value.encode(output).map_err(Into::into)
+///     }
+///
+///     pub fn decode(input: &mut Input) -> Result<Duration, GenericDecodeError> {
+///         let Ok(value) = u32::decode(input);
+///
+///         Ok(Duration::from_millis(value.into()))
+///     }
+/// }
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Timeout {
+///     #[oct(with = "millis", max_size = u32::MAX_ENCODED_SIZE)]
+///     delay: Duration,
+/// }
+///
+/// let mut buf = Slot::<Timeout>::new();
+///
+/// buf.write(Timeout { delay: Duration::from_secs(0x1) }).unwrap();
+/// assert_eq!(buf, [0xE8, 0x03, 0x00, 0x00].as_slice());
+///
+/// assert_eq!(buf.read().unwrap(), Timeout { delay: Duration::from_secs(0x1) });
+/// ```
+///
+/// The encoder and decoder may also be specified individually using the `encode_with` and `decode_with` attributes.
+/// Encoders have the signature <code>fn(&T, &mut [Output]) -> Result<(), E></code> and decoders <code>fn(&mut [Input]) -> Result<T, E></code>, wherein `E` must be convertible into [`GenericEncodeError`] or [`GenericDecodeError`], respectively.
+///
+/// As the encoded size of such fields cannot be known, deriving [`SizedEncode`](derive@SizedEncode) additionally requires the `max_size` attribute, which denotes the field's maximum encoded size.
+///
+/// [`GenericEncodeError`]: crate::error::GenericEncodeError
+/// [`GenericDecodeError`]: crate::error::GenericDecodeError
+/// [Input]: crate::decode::Input
+///
+/// ## `since` and `versioned`
+///
+/// The `since` attribute marks a field as having been added in a later version of the structure.
+/// Structures with such fields are *versioned*: their fields are prefixed with their total length (encoded as a [`usize`]).
+///
+/// As the first version of a structure has no such fields, it should instead be marked with the `versioned` container attribute, which enables the versioned layout on its own.
+/// Otherwise, adding the first versioned field would change the encoding of the existing fields.
+///
+/// When decoding, versioned fields that are missing from the body (as it was encoded by an older version) are constructed in the same way as skipped fields.
+/// Any trailing data from newer versions is likewise skipped:
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output};
+///
+/// #[derive(Encode)]
+/// #[oct(versioned)]
+/// struct OldConfig {
+///     id: u16,
+/// }
+///
+/// #[derive(Debug, Decode, PartialEq)]
+/// #[oct(versioned)]
+/// struct Config {
+///     id: u16,
+///
+///     #[oct(since = 1)]
+///     flags: u8,
+/// }
+///
+/// // Version 0:
+/// let mut buf = [0x00; 0x4];
+///
+/// let mut output = Output::new(&mut buf);
+/// OldConfig { id: 0x2A }.encode(&mut output).unwrap();
+/// assert_eq!(output.as_slice(), [0x02, 0x00, 0x2A, 0x00]);
+///
+/// let mut input = Input::new(output.as_slice());
+/// assert_eq!(Config::decode(&mut input).unwrap(), Config { id: 0x2A, flags: 0x0 });
+///
+/// // Version 2:
+/// let mut input = Input::new(&[0x04, 0x00, 0x2A, 0x00, 0x01, 0xFF]);
+/// assert_eq!(Config::decode(&mut input).unwrap(), Config { id: 0x2A, flags: 0x1 });
+/// ```
+///
+/// Versioned fields must be declared after all unversioned fields and in order of their versions.
+/// Neither attribute is supported in enumerations (see [extensible enumerations](#extensible-enumerations) instead).
+///
+/// ## `pad`, `align`, and `reserved`
+///
+/// For mirroring fixed binary layouts, the `pad` attribute inserts the given amount of zero bytes before a field.
+/// Similarly, the `align` attribute inserts zero bytes until the field is aligned to the given amount of bytes, relative to the start of the structure's (or variant's) fields.
+///
+/// Fields marked with `reserved` are not encoded at all, but are instead replaced by as many zero bytes as the field's [`SizedEncode`](trait@SizedEncode) size.
+/// When decoded, such fields are constructed in the same way as skipped fields.
+///
+/// Padding and reserved bytes are skipped when decoding, unless the `zeroed` attribute is also specified, in which case non-zero bytes yield [`PaddingError`](crate::error::PaddingError) (converted into [`GenericDecodeError::BadPadding`](crate::error::GenericDecodeError::BadPadding)):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Register {
+///     id: u8,
+///
+///     #[oct(align = 0x4)]
+///     value: u32,
+///
+///     #[oct(reserved, zeroed)]
+///     reserved: [u8; 0x2],
+/// }
+///
+/// assert_eq!(Register::MAX_ENCODED_SIZE, 0xA);
+///
+/// let mut input = Input::new(&[0x01, 0x00, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12, 0x00, 0x00]);
+/// assert_eq!(Register::decode(&mut input).unwrap(), Register { id: 0x1, value: 0x12345678, reserved: [0x00; 0x2] });
+/// ```
+///
+/// [`SizedEncode`](trait@SizedEncode) counts padding as its maximum amount, i.e. one less than the alignment for aligned fields.
+///
+/// ## `tag_from`
+///
+/// Some formats store the tag of an enumeration separately from its fields, e.g. as a `kind` field in a header.
+/// The `tag_from` attribute on a structure field of an enumeration type encodes the enumeration without its tag (using [`EncodeTagged`]), instead taking it from the named field (or tuple index) of the same structure.
+/// The tag field must be declared before the tagged field and must be convertible into the enumeration's tag type using [`Into`].
+///
+/// When encoding, a tag field that disagrees with the tagged value yields [`TagMismatchError`](crate::error::TagMismatchError) (converted into [`GenericEncodeError::BadTag`](crate::error::GenericEncodeError::BadTag)).
+/// When decoding, the variant is selected using the already-decoded tag field (using [`DecodeTagged`](crate::decode::DecodeTagged)):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq)]
+/// #[repr(u8)]
+/// enum Body {
+///     Ping = 0x1,
+///
+///     Data(u16) = 0x2,
+/// }
+///
+/// #[derive(Debug, Decode, Encode, PartialEq)]
+/// struct Frame {
+///     kind: u8,
+///
+///     #[oct(tag_from = "kind")]
+///     body: Body,
+/// }
+///
+/// let mut buf = [0x00; 0x3];
+/// Frame { kind: 0x2, body: Body::Data(0x1234) }.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0x02, 0x34, 0x12]);
+///
+/// let mut input = Input::new(&buf);
+/// assert_eq!(Frame::decode(&mut input).unwrap(), Frame { kind: 0x2, body: Body::Data(0x1234) });
+///
+/// assert!(Frame { kind: 0x1, body: Body::Data(0x1234) }.encode(&mut Output::new(&mut buf)).is_err());
+/// ```
+///
+/// The attribute is only supported on structure fields.
+///
+/// ## `len_from`
+///
+/// Similarly, the `len_from` attribute on a collection field (e.g. [`Vec`](alloc::vec::Vec), [`String`](alloc::string::String), [`SizedSlice`](crate::SizedSlice), or [`SizedStr`](crate::SizedStr)) omits the collection's length prefix (using [`EncodeUnprefixed`]), instead taking its length from the named field of the same structure.
+/// The length field must be declared before the collection and must be convertible into [`usize`] using [`Into`].
+/// For strings, the length denotes the amount of bytes.
+///
+/// When encoding, a length field that disagrees with the collection yields [`LengthMismatchError`](crate::error::LengthMismatchError) (converted into [`GenericEncodeError::BadLength`](crate::error::GenericEncodeError::BadLength)).
+/// When decoding, exactly that many items are decoded (using [`DecodeUnprefixed`](crate::decode::DecodeUnprefixed)):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq)]
+/// struct Table {
+///     count: u16,
+///
+///     flags: u8,
+///
+///     #[oct(len_from = "count")]
+///     entries: Vec<u16>,
+/// }
+///
+/// let table = Table { count: 0x2, flags: 0x0, entries: vec![0x1, 0x2] };
+///
+/// let mut buf = [0x00; 0x7];
+/// table.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0x02, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00]);
+///
+/// let mut input = Input::new(&buf);
+/// assert_eq!(Table::decode(&mut input).unwrap(), table);
+/// ```
+///
+/// Like `tag_from`, the attribute is only supported on structure fields.
+///
+/// ## `if`
+///
+/// The `if` attribute on an [`Option`] field makes the field's presence depend on the given condition, instead of on a sign byte.
+/// The condition is an expression that may refer to earlier named fields of the same structure, which are bound by reference under their own names.
+///
+/// When decoding, the contained value is only decoded if the condition holds.
+/// When encoding, a field whose presence disagrees with its condition yields [`PresenceError`](crate::error::PresenceError) (converted into [`GenericEncodeError::BadPresence`](crate::error::GenericEncodeError::BadPresence)):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Packet {
+///     flags: u8,
+///
+///     #[oct(if = "*flags & 0x1 != 0x0")]
+///     checksum: Option<u16>,
+/// }
+///
+/// assert_eq!(Packet::MAX_ENCODED_SIZE, 0x3);
+///
+/// let mut buf = [0x00; 0x3];
+/// Packet { flags: 0x1, checksum: Some(0x1234) }.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0x01, 0x34, 0x12]);
+///
+/// let mut input = Input::new(&[0x00]);
+/// assert_eq!(Packet::decode(&mut input).unwrap(), Packet { flags: 0x0, checksum: None });
+///
+/// assert!(Packet { flags: 0x0, checksum: Some(0x1234) }.encode(&mut Output::new(&mut buf)).is_err());
+/// ```
+///
+/// [`SizedEncode`](trait@SizedEncode) counts conditional fields as always being present.
+/// The attribute is only supported on structure fields.
+///
+/// ## `be`, `le`, `varint`, `as`, and `bytes`
+///
+/// The `be` and `le` attributes encode a numeric field in big- or little-endian byte order, regardless of the crate's byte order.
+/// The `varint` attribute encodes an integer field as a variable-length integer (see [`repr::varint`](crate::repr::varint)).
+/// The `as = "Type"` attribute encodes a field as another type, with a checked conversion in each direction; conversions that fail yield [`CastError`](crate::error::CastError).
+/// The `bytes = N` attribute encodes an integer field in exactly `N` bytes (at most sixteen), such as a `u32` in three bytes; values that do not fit yield [`BitFieldError`](crate::error::BitFieldError).
+///
+/// These are shorthands for the `with` attribute using the corresponding modules in [`repr`](crate::repr), and [`SizedEncode`](trait@SizedEncode) accounts for the chosen representation:
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Header {
+///     #[oct(be)]
+///     magic: u32,
+///
+///     #[oct(varint)]
+///     len: u64,
+///
+///     #[oct(as = "u8")]
+///     kind: u32,
+/// }
+///
+/// assert_eq!(Header::MAX_ENCODED_SIZE, 0xF);
+///
+/// let mut buf = [0x00; 0x7];
+/// Header { magic: 0xCAFEBABE, len: 0x12C, kind: 0x3 }.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0xCA, 0xFE, 0xBA, 0xBE, 0xAC, 0x02, 0x03]);
+///
+/// let mut input = Input::new(&buf);
+/// assert_eq!(Header::decode(&mut input).unwrap(), Header { magic: 0xCAFEBABE, len: 0x12C, kind: 0x3 });
+///
+/// assert!(Header { magic: 0x0, len: 0x0, kind: 0x100 }.encode(&mut Output::new(&mut buf)).is_err());
+/// ```
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Entry {
+///     #[oct(bytes = 3)]
+///     offset: u32,
+///
+///     #[oct(be, bytes = 3)]
+///     len: u32,
+/// }
+///
+/// assert_eq!(Entry::MAX_ENCODED_SIZE, 0x6);
+///
+/// let mut buf = [0x00; 0x6];
+/// Entry { offset: 0x123456, len: 0x10 }.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0x56, 0x34, 0x12, 0x00, 0x00, 0x10]);
+///
+/// assert!(Entry { offset: 0x1000000, len: 0x0 }.encode(&mut Output::new(&mut buf)).is_err());
+/// ```
+///
+/// At most one of these attributes may be given, except that `bytes` may be combined with `be` or `le` to select its byte order (the default being little-endian).
+/// They may not be combined with the `with`, `encode_with`, or `decode_with` attributes.
+///
+/// ## `bits`
+///
+/// The `bits = N` attribute stores a field in `N` bits (at most 128) instead of in its own encoding.
+/// Consecutive bit fields form a group, which is packed least-significant bit first into the fewest bytes possible; any other field ends the group.
+/// The field's type must implement [`BitField`](crate::repr::BitField), as is the case for [`bool`] and all fixed-size integers.
+///
+/// When encoding, values that do not fit in their bits yield [`BitFieldError`](crate::error::BitFieldError), as do bits that do not denote a valid value when decoding (converted into `BadBits` of [`GenericEncodeError`] or [`GenericDecodeError`](crate::error::GenericDecodeError)):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, Output, SizedEncode};
+///
+/// #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+/// struct Status {
+///     #[oct(bits = 1)]
+///     enabled: bool,
+///
+///     #[oct(bits = 3)]
+///     level: u8,
+///
+///     #[oct(bits = 4)]
+///     mode: u8,
+///
+///     id: u16,
+/// }
+///
+/// assert_eq!(Status::MAX_ENCODED_SIZE, 0x3);
+///
+/// let mut buf = [0x00; 0x3];
+/// Status { enabled: true, level: 0x5, mode: 0x2, id: 0x1234 }.encode(&mut Output::new(&mut buf)).unwrap();
+/// assert_eq!(buf, [0b0010_1011, 0x34, 0x12]);
+///
+/// let mut input = Input::new(&buf);
+/// assert_eq!(Status::decode(&mut input).unwrap(), Status { enabled: true, level: 0x5, mode: 0x2, id: 0x1234 });
+///
+/// assert!(Status { enabled: true, level: 0x8, mode: 0x0, id: 0x0 }.encode(&mut Output::new(&mut buf)).is_err());
+/// ```
+///
+/// Bit fields cannot have custom encoders or decoders or be combined with most other field attributes, but they may be validated.
+/// The attribute is only supported on structure fields.
+///
+/// # Error types
+///
+/// By default, derived implementations use [`GenericEncodeError`] (or [`EnumEncodeError`](crate::error::EnumEncodeError) for enumerations) as their error type.
+/// With the `error = "generate"` attribute, a dedicated error type named `{Type}EncodeError` is instead generated alongside the implementation.
+///
+/// This type has a variant for each encoded field, containing that field's own error (or [`GenericEncodeError`] for bit fields and fields with custom encoders).
+/// Variants are named after their fields in upper camel case (e.g. `Len` or `Field0`), prefixed by the name of the variant in enumerations (e.g. `DataLen` or `Data0`).
+/// Enumerations additionally have a `BadDiscriminant` variant.
+/// When decoding, failed validations of fields have their own variants, prefixed with `Invalid` (e.g. `InvalidLen`).
+///
+/// Generated error types implement [`FieldError`](crate::error::FieldError), and their [`Display`](core::fmt::Display) implementations denote the path to the failing field:
+///
+/// ```
+/// use oct::encode::{Encode, Output};
+///
+/// #[derive(Encode)]
+/// #[oct(error = "generate")]
+/// struct Chunk {
+///     len: usize,
+/// }
+///
+/// let mut buf = [0x00; 0x2];
+///
+/// let error = Chunk { len: 0x10000 }.encode(&mut Output::new(&mut buf)).unwrap_err();
+///
+/// assert!(matches!(error, ChunkEncodeError::Len(..)));
+/// assert!(error.to_string().starts_with("`Chunk.len`: "));
+/// ```
+///
+/// The same applies to [`Decode`](derive@crate::decode::Decode) with `{Type}DecodeError` types, where enumerations instead have an `UnassignedDiscriminant` variant.
+/// Generated error types are convertible into the generic error types, and are not supported for generic, versioned, or extensible types.
+///
+/// # Generics
+///
+/// Each type parameter that is used by an encoded field is automatically bound by `Encode` (with an error type that is convertible into [`GenericEncodeError`]).
+/// Parameters that are only used by skipped fields, by bit fields, by fields with custom encoders, or by [`PhantomData`](core::marker::PhantomData) are not bound.
+/// Fields with only custom decoders are still bound by `Encode`; likewise, [`Decode`](derive@crate::decode::Decode) only exempts fields with custom decoders, and [`SizedEncode`](derive@SizedEncode) only exempts fields with a `max_size` attribute.
+///
+/// These bounds may be replaced using the `bound` attribute.
+/// On a field, the given bounds replace those inferred from that field's type, whilst on the structure or enumeration itself, they replace all inferred bounds:
+///
+/// ```
+/// use oct::encode::Encode;
+/// use std::convert::Infallible;
+///
+/// #[derive(Encode)]
+/// struct Pair<T> {
+///     #[oct(bound = "T: Encode<Error = Infallible>")]
+///     left: T,
+///
+///     right: T,
+/// }
+///
+/// #[derive(Encode)]
+/// #[oct(bound = "")]
+/// struct Handle<T> {
+///     #[oct(skip)]
+///     state: Option<T>,
+///
+///     id: u32,
+/// }
+/// ```
+///
+/// The same applies to the other derive macros, with bounds on their respective traits.
+///
 /// # Unions
 ///
 /// Unions cannot derive `Encode` due to the uncertainty of their contents.
@@ -122,6 +725,10 @@ pub use oct_macros::Encode;
 ///
 /// For enumerations, the value is set such that each variant is treated like a structure (with the discriminant as an extra field) and where the variant that produces the largest `MAX_ENCODED_SIZE` is chosen.
 ///
+/// Fields marked with the `skip` attribute (see [`Encode`](derive@Encode)) are not included in the total.
+/// Fields with custom encoders instead contribute the value of their `max_size` attribute.
+/// Versioned structures and extensible enumerations additionally include their length prefixes.
+///
 /// As untagged unions cannot derive `Encode`, `SizedEncode` also cannot be derived for them.
 ///
 /// Do remember that deriving this trait is only recommended
@@ -129,3 +736,48 @@ pub use oct_macros::Encode;
 #[cfg_attr(doc, doc(cfg(feature = "proc-macro")))]
 #[doc(inline)]
 pub use oct_macros::SizedEncode;
+
+/// Implements [`FixedEncode`](trait@FixedEncode) for the provided type.
+///
+/// The trait is only implemented if every encoded field implements `FixedEncode` itself.
+/// This is enforced by bounding the type of each field (or that of the `into` proxy) on the trait.
+/// Fields marked with the `skip` attribute are exempt, as are bit fields, whose groups always have the same size.
+/// Fields using the `be`, `le`, or `as` attributes are likewise bounded on the types they are encoded as, whilst fields using `bytes` are always fixed-size.
+///
+/// For enumerations, every variant must additionally have the same encoded size.
+/// This is asserted at compile time, and generic enumerations with fields can therefore not derive the trait.
+///
+/// ```
+/// use oct::encode::{Encode, FixedEncode, SizedEncode};
+///
+/// #[derive(Encode, FixedEncode, SizedEncode)]
+/// struct Record {
+///     id: u32,
+///
+///     #[oct(be)]
+///     time: u64,
+///
+///     #[oct(bits = 4)]
+///     kind: u8,
+///
+///     #[oct(bits = 4)]
+///     flags: u8,
+/// }
+///
+/// #[derive(Encode, FixedEncode, SizedEncode)]
+/// #[repr(u8)]
+/// enum Shape {
+///     Circle { radius: f32 },
+///
+///     Square { side: f32 },
+/// }
+///
+/// assert_eq!(Record::MAX_ENCODED_SIZE, 0xD);
+/// assert_eq!(Shape::MAX_ENCODED_SIZE, 0x5);
+/// ```
+///
+/// Versioned structures, `other` variants, and fields using the `varint`, `if`, `len_from`, or `tag_from` attributes or custom encoders cannot be used, as their sizes may vary.
+#[cfg(feature = "proc-macro")]
+#[cfg_attr(doc, doc(cfg(feature = "proc-macro")))]
+#[doc(inline)]
+pub use oct_macros::FixedEncode;
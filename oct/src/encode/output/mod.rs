@@ -70,6 +70,36 @@ impl<'a> Output<'a> {
 		Ok(())
 	}
 
+	/// Overwrites bytes that have previously been written to the stream.
+	///
+	/// This is mainly useful for back-patching headers -- such as length prefixes -- that cannot be known before the rest of the encoding has been written.
+	/// The cursor position is not affected by this method.
+	///
+	/// # Errors
+	///
+	/// If the requested range is not contained within the already-written part of the stream, then an error is returned.
+	#[inline]
+	pub const fn write_at(&mut self, pos: usize, data: &[u8]) -> Result<(), OutputError> {
+		let count = data.len();
+
+		if pos > self.pos || self.pos - pos < count {
+			return Err(OutputError {
+				capacity: self.buf.len(),
+				position: pos,
+				count,
+			});
+		}
+
+		unsafe {
+			let src = data.as_ptr();
+			let dst = self.buf.as_mut_ptr().add(pos);
+
+			copy_nonoverlapping(src, dst, count);
+		}
+
+		Ok(())
+	}
+
 	/// Gets a pointer to the first byte of the output stream.
 	#[inline(always)]
 	#[must_use]
@@ -20,7 +20,7 @@
 // not, see <https://www.gnu.org/licenses/>.
 
 use oct::SizedStr;
-use oct::encode::{Encode, SizedEncode};
+use oct::encode::{Encode, Output, SizedEncode};
 use std::convert::Infallible;
 use std::marker::PhantomData;
 use std::net::{
@@ -32,6 +32,9 @@ use std::net::{
 	SocketAddrV6,
 };
 use std::num::NonZero;
+use std::string::String;
+use std::time::Duration;
+use std::vec::Vec;
 
 macro_rules! assert_encoded_size {
 	($ty:ty, $value:expr$(,)?) => {{
@@ -97,6 +100,155 @@ fn test_sized_encode_derive() {
 		Teacher { initials: [char; 0x3] },
 	}
 
+	#[derive(Encode, SizedEncode)]
+	struct Baz {
+		_foo: Foo,
+
+		#[oct(skip)]
+		_cache: Vec<u8>,
+	}
+
+	#[derive(Encode, SizedEncode)]
+	#[expect(dead_code)]
+	#[repr(u8)]
+	enum Qux {
+		Sleep(#[oct(encode_with = "encode_millis", max_size = u32::MAX_ENCODED_SIZE)] Duration),
+	}
+
+	fn encode_millis(value: &Duration, output: &mut Output) -> Result<(), Infallible> {
+		let value = u32::try_from(value.as_millis()).unwrap();
+
+		value.encode(output)
+	}
+
+	#[derive(Encode, SizedEncode)]
+	struct Quux<T>(T, PhantomData<Foo>);
+
+	#[derive(Encode, SizedEncode)]
+	#[expect(dead_code)]
+	#[oct(tag = u8)]
+	#[repr(u64)]
+	enum Corge {
+		Unit,
+
+		Value(u32),
+	}
+
+	#[derive(Encode, SizedEncode)]
+	#[expect(dead_code)]
+	#[oct(extensible, tag = u8)]
+	enum Grault {
+		Unit,
+
+		Value(u32),
+	}
+
+	#[derive(Encode, SizedEncode)]
+	struct Garply {
+		_id: u16,
+
+		#[oct(since = 1)]
+		_flags: u8,
+	}
+
+	#[derive(Encode, SizedEncode)]
+	#[oct(versioned)]
+	struct GarplyInitial {
+		_id: u16,
+	}
+
+	#[derive(Clone, Encode, SizedEncode)]
+	#[oct(into = "u16")]
+	struct Waldo(u8);
+
+	impl From<Waldo> for u16 {
+		fn from(value: Waldo) -> Self {
+			value.0.into()
+		}
+	}
+
+	#[derive(Encode, SizedEncode)]
+	#[expect(dead_code)]
+	#[oct(magic = b"OCT1")]
+	enum Fred {
+		Unit,
+
+		Value(#[oct(constant = 0xCAFEu16)] u16),
+	}
+
+	#[derive(Encode, SizedEncode)]
+	struct Plugh {
+		_id: u8,
+
+		#[oct(align = 0x4)]
+		_value: u32,
+
+		#[oct(pad = 0x2)]
+		_flags: u8,
+
+		#[oct(reserved)]
+		_reserved: [u8; 0x3],
+	}
+
+	#[derive(Encode, SizedEncode)]
+	struct Xyzzy {
+		#[oct(be)]
+		_magic: u32,
+
+		#[oct(varint)]
+		_len: u64,
+
+		#[oct(as = "u8")]
+		_kind: u32,
+	}
+
+	#[derive(Encode, SizedEncode)]
+	struct Thud {
+		#[oct(bits = 1)]
+		_enabled: bool,
+
+		#[oct(bits = 4)]
+		_level: u8,
+
+		#[oct(bits = 4)]
+		_mode: u8,
+
+		_id: u16,
+
+		#[oct(bits = 3)]
+		_flags: u8,
+	}
+
 	assert_encoded_size!(Foo, 0x4);
 	assert_encoded_size!(Bar, 0x14);
+	assert_encoded_size!(Baz, 0x4);
+	assert_encoded_size!(Qux, 0x5);
+	assert_encoded_size!(Quux<u64>, 0x8);
+	assert_encoded_size!(Corge, 0x5);
+	assert_encoded_size!(Grault, 0x7);
+	assert_encoded_size!(Garply, 0x5);
+	assert_encoded_size!(GarplyInitial, 0x4);
+	assert_encoded_size!(Waldo, 0x2);
+	assert_encoded_size!(Fred, 0x7);
+	assert_encoded_size!(Plugh, 0xE);
+	assert_encoded_size!(Xyzzy, 0xF);
+	assert_encoded_size!(Thud, 0x5);
+}
+
+#[test]
+fn test_sized_encode_derive_generic() {
+	#[derive(Encode, SizedEncode)]
+	struct Wrapper<T>(T);
+
+	#[derive(Encode, SizedEncode)]
+	struct Count<T>(#[oct(encode_with = "encode_count", max_size = u16::MAX_ENCODED_SIZE)] Vec<T>);
+
+	fn encode_count<T>(value: &[T], output: &mut Output) -> Result<(), Infallible> {
+		let value = u16::try_from(value.len()).unwrap();
+
+		value.encode(output)
+	}
+
+	assert_encoded_size!(Wrapper<u32>, 0x4);
+	assert_encoded_size!(Count<String>, 0x2);
 }
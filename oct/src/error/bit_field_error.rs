@@ -0,0 +1,43 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A bit field held a value outside of its width.
+///
+/// This error type is yielded by [derived](derive@crate::encode::Encode) implementations of [`Encode`](crate::encode::Encode) and [`Decode`](crate::decode::Decode) when a field with the `bits` attribute cannot be represented in, or be recovered from, its bits.
+/// See the [`BitField`](crate::repr::BitField) trait for more information.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct BitFieldError {
+	/// The width of the field, in bits.
+	pub width: u32,
+}
+
+impl Display for BitFieldError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "value is not representable in a bit field of `{}` bits", self.width)
+	}
+}
+
+impl Error for BitFieldError { }
@@ -0,0 +1,42 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A value could not be converted into its encoded representation.
+///
+/// This error type is yielded by the [`cast`](crate::repr::cast) functions -- and thus by the `as` field attribute of the derive macros -- when a value cannot be represented by the target type (or vice versa).
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct CastError {
+	/// The name of the target type.
+	pub target: &'static str,
+}
+
+impl Display for CastError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "value cannot be represented as `{}`", self.target)
+	}
+}
+
+impl Error for CastError { }
@@ -0,0 +1,62 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::ChecksumError;
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A checked value could not be decoded.
+///
+/// This error is returned when decoding [`Checked`](crate::Checked) objects.
+#[derive(Debug)]
+#[must_use]
+pub enum CheckedDecodeError<E> {
+	/// The checksum did not match the data.
+	BadChecksum(ChecksumError),
+
+	/// The value itself could not be decoded.
+	BadValue(E),
+}
+
+impl<E: Display> Display for CheckedDecodeError<E> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::BadChecksum(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadValue(ref e)
+			=> write!(f, "unable to decode checked value: {e}"),
+		}
+	}
+}
+
+impl<E: Error + 'static> Error for CheckedDecodeError<E> {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadChecksum(ref e) => Some(e),
+
+			Self::BadValue(ref e) => Some(e),
+		}
+	}
+}
@@ -0,0 +1,50 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A checksum did not match the checked data.
+///
+/// This error is returned when decoding [`Checked`](crate::Checked) objects.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct ChecksumError {
+	/// The checksum computed from the decoded data.
+	pub expected: u32,
+
+	/// The checksum found in the stream.
+	pub found: u32,
+}
+
+impl Display for ChecksumError {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"checksum ({:#010X}) does not match data (expected {:#010X})",
+			self.found,
+			self.expected,
+		)
+	}
+}
+
+impl Error for ChecksumError { }
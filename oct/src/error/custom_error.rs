@@ -0,0 +1,170 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A user-defined error.
+///
+/// This type allows field types with their own error types to be used by [derived](derive@crate::decode::Decode) implementations, which otherwise require field errors to be convertible into [`GenericDecodeError`](crate::error::GenericDecodeError) or [`GenericEncodeError`](crate::error::GenericEncodeError).
+/// Both of these are convertible from this type.
+///
+/// With the `alloc` feature, any error type may be boxed using [`from_error`](Self::from_error).
+/// Otherwise (or additionally), an error may be described using a static message and a numerical code using [`new`](Self::new):
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use oct::error::{CustomError, GenericDecodeError};
+///
+/// #[derive(Debug)]
+/// struct Even(u8);
+///
+/// impl Decode for Even {
+///     type Error = CustomError;
+///
+///     fn decode(input: &mut Input) -> Result<Self, Self::Error> {
+///         let Ok(value) = u8::decode(input);
+///
+///         if value % 0x2 != 0x0 {
+///             return Err(CustomError::new("value is odd", 0x1));
+///         }
+///
+///         Ok(Self(value))
+///     }
+/// }
+///
+/// #[derive(Debug, Decode)]
+/// struct Pair {
+///     left:  Even,
+///     right: Even,
+/// }
+///
+/// let error = Pair::decode(&mut Input::new(&[0x02, 0x03])).unwrap_err();
+///
+/// let GenericDecodeError::Custom(ref e) = error else { unreachable!() };
+/// assert_eq!(e.code(), Some(0x1));
+/// assert_eq!(error.to_string(), "value is odd (code 1)");
+/// ```
+#[must_use]
+pub struct CustomError(Inner);
+
+enum Inner {
+	Static {
+		message: &'static str,
+
+		code: u32,
+	},
+
+	#[cfg(feature = "alloc")]
+	Boxed(Box<dyn Error + Send + Sync + 'static>),
+}
+
+impl CustomError {
+	/// Constructs a new error from a static message and a numerical code.
+	///
+	/// The meaning of the code is left to the user.
+	#[inline(always)]
+	pub const fn new(message: &'static str, code: u32) -> Self {
+		Self(Inner::Static { message, code })
+	}
+
+	/// Constructs a new error by boxing the provided error.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+	#[inline]
+	pub fn from_error<E: Error + Send + Sync + 'static>(error: E) -> Self {
+		Self(Inner::Boxed(Box::new(error)))
+	}
+
+	/// Retrieves the numerical code of the error.
+	///
+	/// If the error was not constructed using [`new`](Self::new), then this method returns `None`.
+	#[cfg_attr(not(feature = "alloc"), allow(clippy::unnecessary_wraps))]
+	#[inline]
+	#[must_use]
+	pub const fn code(&self) -> Option<u32> {
+		match self.0 {
+			Inner::Static { code, .. } => Some(code),
+
+			#[cfg(feature = "alloc")]
+			Inner::Boxed(..) => None,
+		}
+	}
+
+	/// Gets a reference to the boxed error.
+	///
+	/// If the error was not constructed using [`from_error`](Self::from_error), then this method returns `None`.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+	#[inline]
+	#[must_use]
+	pub fn get_ref(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
+		match self.0 {
+			Inner::Boxed(ref e) => Some(&**e),
+
+			Inner::Static { .. } => None,
+		}
+	}
+}
+
+impl Debug for CustomError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self.0 {
+			Inner::Static { message, code } => {
+				f.debug_struct("CustomError")
+					.field("message", &message)
+					.field("code", &code)
+					.finish()
+			},
+
+			#[cfg(feature = "alloc")]
+			Inner::Boxed(ref e) => f.debug_tuple("CustomError").field(e).finish(),
+		}
+	}
+}
+
+impl Display for CustomError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self.0 {
+			Inner::Static { message, code } => write!(f, "{message} (code {code})"),
+
+			#[cfg(feature = "alloc")]
+			Inner::Boxed(ref e) => write!(f, "{e}"),
+		}
+	}
+}
+
+impl Error for CustomError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self.0 {
+			Inner::Static { .. } => None,
+
+			#[cfg(feature = "alloc")]
+			Inner::Boxed(ref e) => Some(&**e),
+		}
+	}
+}
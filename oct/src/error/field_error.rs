@@ -0,0 +1,104 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A derived error that denotes the failing field.
+///
+/// This trait is implemented by the error types that are generated by the [`Encode`](derive@crate::encode::Encode) and [`Decode`](derive@crate::decode::Decode) derive macros when the `error = "generate"` attribute is specified.
+/// It is used for tracking the path to the failing field across nested types, which is included in their [`Display`] implementations:
+///
+/// ```
+/// use oct::decode::{Decode, Input};
+/// use std::num::NonZeroU16;
+///
+/// #[derive(Debug, Decode)]
+/// #[oct(error = "generate")]
+/// struct Header {
+///     len: NonZeroU16,
+/// }
+///
+/// #[derive(Debug, Decode)]
+/// #[oct(error = "generate")]
+/// struct Packet {
+///     header: Header,
+/// }
+///
+/// let error = Packet::decode(&mut Input::new(&[0x00, 0x00])).unwrap_err();
+///
+/// assert!(matches!(error, PacketDecodeError::Header(HeaderDecodeError::Len(..))));
+/// assert!(error.to_string().starts_with("`Packet.header.len`: "));
+/// ```
+pub trait FieldError: Error {
+	/// Formats the path to the failing field.
+	///
+	/// The path is relative to the type that generated the error (e.g. `.header.len`).
+	fn fmt_path(&self, f: &mut Formatter) -> fmt::Result;
+
+	/// Formats the error of the failing field.
+	fn fmt_cause(&self, f: &mut Formatter) -> fmt::Result;
+}
+
+/// Wrapper for formatting nested field errors.
+///
+/// Generated error types use this to format field errors that may or may not themselves implement [`FieldError`].
+/// When called on a reference to this type, the methods of [`NestedFieldError`] take priority over those of [`NestedError`].
+#[doc(hidden)]
+pub struct Nested<'a, E: ?Sized>(pub &'a E);
+
+#[doc(hidden)]
+pub trait NestedFieldError {
+	fn fmt_nested_path(&self, f: &mut Formatter) -> fmt::Result;
+
+	fn fmt_nested_cause(&self, f: &mut Formatter) -> fmt::Result;
+}
+
+impl<E: FieldError + ?Sized> NestedFieldError for Nested<'_, E> {
+	#[inline(always)]
+	fn fmt_nested_path(&self, f: &mut Formatter) -> fmt::Result {
+		self.0.fmt_path(f)
+	}
+
+	#[inline(always)]
+	fn fmt_nested_cause(&self, f: &mut Formatter) -> fmt::Result {
+		self.0.fmt_cause(f)
+	}
+}
+
+#[doc(hidden)]
+pub trait NestedError {
+	fn fmt_nested_path(&self, f: &mut Formatter) -> fmt::Result;
+
+	fn fmt_nested_cause(&self, f: &mut Formatter) -> fmt::Result;
+}
+
+impl<E: Display + ?Sized> NestedError for &Nested<'_, E> {
+	#[inline(always)]
+	fn fmt_nested_path(&self, _f: &mut Formatter) -> fmt::Result {
+		Ok(())
+	}
+
+	#[inline(always)]
+	fn fmt_nested_cause(&self, f: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.0, f)
+	}
+}
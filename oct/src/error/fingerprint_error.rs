@@ -0,0 +1,50 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Two fingerprints did not match.
+///
+/// This error is returned by [`Handshake`](crate::fingerprint::Handshake) if the fingerprint of the peer differs from the local one.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct FingerprintError {
+	/// The local fingerprint.
+	pub local: u64,
+
+	/// The fingerprint of the peer.
+	pub remote: u64,
+}
+
+impl Display for FingerprintError {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(
+			f,
+			"fingerprint of peer ({:#018X}) does not match local fingerprint ({:#018X})",
+			self.remote,
+			self.local,
+		)
+	}
+}
+
+impl Error for FingerprintError { }
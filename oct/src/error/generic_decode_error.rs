@@ -22,18 +22,31 @@
 use crate::PrimitiveDiscriminant;
 use crate::decode::Decode;
 use crate::error::{
+	BitFieldError,
+	CastError,
+	CheckedDecodeError,
+	ChecksumError,
 	CollectionDecodeError,
+	CustomError,
 	EnumDecodeError,
 	ItemDecodeError,
 	NonZeroDecodeError,
 	LengthError,
+	MagicError,
+	MessageDecodeError,
+	PaddingError,
+	ProxyDecodeError,
 	Utf8Error,
-	SystemTimeDecodeError,
+	ValidationError,
+	VarintDecodeError,
 };
 
 #[cfg(feature = "alloc")]
 use crate::error::CStringDecodeError;
 
+#[cfg(feature = "std")]
+use crate::error::SystemTimeDecodeError;
+
 use core::convert::Infallible;
 use core::error::Error;
 use core::fmt::{self, Display, Formatter};
@@ -47,12 +60,15 @@ use core::hint::unreachable_unchecked;
 #[must_use]
 #[non_exhaustive]
 pub enum GenericDecodeError {
+	/// A checksum did not match its data.
+	BadChecksum(ChecksumError),
+
 	/// A string contained a non-UTF-8 sequence.
 	BadString(Utf8Error),
 
 	/// A C-like string contained a null byte.
-	#[cfg(feature = "std")]
-	#[cfg_attr(doc, doc(cfg(feature = "std")))]
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 	NullString(CStringDecodeError),
 
 	/// A non-null integer was null.
@@ -73,15 +89,40 @@ pub enum GenericDecodeError {
 	#[cfg(feature = "std")]
 	#[cfg_attr(doc, doc(cfg(feature = "std")))]
 	NarrowSystemTime(SystemTimeDecodeError),
+
+	/// A decoded value did not pass validation.
+	Invalid(ValidationError),
+
+	/// A magic value or constant did not match.
+	BadMagic(MagicError),
+
+	/// Padding or reserved bytes were not zero.
+	BadPadding(PaddingError),
+
+	/// A value could not be converted from its encoded representation.
+	BadCast(CastError),
+
+	/// A variable-length integer could not be decoded.
+	BadVarint(VarintDecodeError),
+
+	/// A bit field held a value outside of its width.
+	BadBits(BitFieldError),
+
+	/// A user-defined error occurred.
+	Custom(CustomError),
 }
 
 impl Display for GenericDecodeError {
 	#[inline]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match *self {
+			Self::BadChecksum(ref e)
+			=> write!(f, "{e}"),
+
 			Self::BadString(ref e)
 			=> write!(f, "{e}"),
 
+			#[cfg(feature = "alloc")]
 			Self::NullString(ref e)
 			=> write!(f, "{e}"),
 
@@ -97,6 +138,27 @@ impl Display for GenericDecodeError {
 			#[cfg(feature = "std")]
 			Self::NarrowSystemTime(ref e)
 			=> write!(f, "{e}"),
+
+			Self::Invalid(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadMagic(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadPadding(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadCast(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadVarint(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadBits(ref e)
+			=> write!(f, "{e}"),
+
+			Self::Custom(ref e)
+			=> write!(f, "{e}"),
 		}
 	}
 }
@@ -105,9 +167,11 @@ impl Error for GenericDecodeError {
 	#[inline]
 	fn source(&self) -> Option<&(dyn Error + 'static)> {
 		match *self {
+			Self::BadChecksum(ref e) => Some(e),
+
 			Self::BadString(ref e) => Some(e),
 
-			#[cfg(feature = "std")]
+			#[cfg(feature = "alloc")]
 			Self::NullString(ref e) => Some(e),
 
 			Self::NullInteger(ref e) => Some(e),
@@ -117,11 +181,59 @@ impl Error for GenericDecodeError {
 			#[cfg(feature = "std")]
 			Self::NarrowSystemTime(ref e) => Some(e),
 
+			Self::Invalid(ref e) => Some(e),
+
+			Self::BadMagic(ref e) => Some(e),
+
+			Self::BadPadding(ref e) => Some(e),
+
+			Self::BadCast(ref e) => Some(e),
+
+			Self::BadVarint(ref e) => Some(e),
+
+			Self::BadBits(ref e) => Some(e),
+
+			Self::Custom(ref e) => Some(e),
+
 			_ => None,
 		}
 	}
 }
 
+impl From<BitFieldError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: BitFieldError) -> Self {
+		Self::BadBits(value)
+	}
+}
+
+impl From<CastError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: CastError) -> Self {
+		Self::BadCast(value)
+	}
+}
+
+impl<E: Into<Self>> From<CheckedDecodeError<E>> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: CheckedDecodeError<E>) -> Self {
+		use CheckedDecodeError as Error;
+
+		match value {
+			Error::BadChecksum(e) => e.into(),
+
+			Error::BadValue(e) => e.into(),
+		}
+	}
+}
+
+impl From<ChecksumError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: ChecksumError) -> Self {
+		Self::BadChecksum(value)
+	}
+}
+
 impl<L, I> From<CollectionDecodeError<L, I>> for GenericDecodeError
 where
 	L: Into<Self>,
@@ -148,6 +260,13 @@ impl From<CStringDecodeError> for GenericDecodeError {
 	}
 }
 
+impl From<CustomError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: CustomError) -> Self {
+		Self::Custom(value)
+	}
+}
+
 impl<D, F> From<EnumDecodeError<D, F>> for GenericDecodeError
 where
 	D: Decode<Error: Into<Self>> + PrimitiveDiscriminant,
@@ -183,6 +302,26 @@ impl<I, E: Into<Self>> From<ItemDecodeError<I, E>> for GenericDecodeError {
 	}
 }
 
+impl From<MagicError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: MagicError) -> Self {
+		Self::BadMagic(value)
+	}
+}
+
+impl<E: Into<Self>> From<MessageDecodeError<E>> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: MessageDecodeError<E>) -> Self {
+		use MessageDecodeError as Error;
+
+		match value {
+			Error::UnassignedId { id } => Self::UnassignedDiscriminant { value: id.into() },
+
+			Error::BadMessage { error, .. } => error.into(),
+		}
+	}
+}
+
 impl From<NonZeroDecodeError> for GenericDecodeError {
 	#[inline(always)]
 	fn from(value: NonZeroDecodeError) -> Self {
@@ -197,6 +336,30 @@ impl From<LengthError> for GenericDecodeError {
 	}
 }
 
+impl From<PaddingError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: PaddingError) -> Self {
+		Self::BadPadding(value)
+	}
+}
+
+impl<D, C> From<ProxyDecodeError<D, C>> for GenericDecodeError
+where
+	D: Into<Self>,
+	C: Into<Self>,
+{
+	#[inline(always)]
+	fn from(value: ProxyDecodeError<D, C>) -> Self {
+		use ProxyDecodeError as Error;
+
+		match value {
+			Error::BadProxy(e) => e.into(),
+
+			Error::BadConversion(e) => e.into(),
+		}
+	}
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(doc, doc(cfg(feature = "std")))]
 impl From<SystemTimeDecodeError> for GenericDecodeError {
@@ -212,3 +375,17 @@ impl From<Utf8Error> for GenericDecodeError {
 		Self::BadString(value)
 	}
 }
+
+impl From<ValidationError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: ValidationError) -> Self {
+		Self::Invalid(value)
+	}
+}
+
+impl From<VarintDecodeError> for GenericDecodeError {
+	#[inline(always)]
+	fn from(value: VarintDecodeError) -> Self {
+		Self::BadVarint(value)
+	}
+}
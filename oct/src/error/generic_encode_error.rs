@@ -21,10 +21,17 @@
 
 use crate::encode::Encode;
 use crate::error::{
+	BitFieldError,
+	CastError,
 	CollectionEncodeError,
+	CustomError,
 	EnumEncodeError,
 	IsizeEncodeError,
 	ItemEncodeError,
+	LengthMismatchError,
+	MessageEncodeError,
+	PresenceError,
+	TagMismatchError,
 	UsizeEncodeError,
 };
 
@@ -50,6 +57,24 @@ pub enum GenericEncodeError {
 
 	/// A `usize` object was outside the allowed domain.
 	LargeUsize(UsizeEncodeError),
+
+	/// An externally-tagged value did not match its tag field.
+	BadTag(TagMismatchError),
+
+	/// A collection did not match its length field.
+	BadLength(LengthMismatchError),
+
+	/// A conditional field did not match its condition.
+	BadPresence(PresenceError),
+
+	/// A value could not be converted into its encoded representation.
+	BadCast(CastError),
+
+	/// A bit field held a value outside of its width.
+	BadBits(BitFieldError),
+
+	/// A user-defined error occurred.
+	Custom(CustomError),
 }
 
 impl Display for GenericEncodeError {
@@ -65,6 +90,23 @@ impl Display for GenericEncodeError {
 			Self::LargeUsize(ref e)
 			=> write!(f, "{e}"),
 
+			Self::BadTag(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadLength(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadPresence(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadCast(ref e)
+			=> write!(f, "{e}"),
+
+			Self::BadBits(ref e)
+			=> write!(f, "{e}"),
+
+			Self::Custom(ref e)
+			=> write!(f, "{e}"),
 		}
 	}
 }
@@ -78,10 +120,29 @@ impl Error for GenericEncodeError {
 			Self::LargeIsize(ref e) => Some(e),
 
 			Self::LargeUsize(ref e) => Some(e),
+
+			Self::BadTag(ref e) => Some(e),
+
+			Self::BadLength(ref e) => Some(e),
+
+			Self::BadPresence(ref e) => Some(e),
+
+			Self::BadCast(ref e) => Some(e),
+
+			Self::BadBits(ref e) => Some(e),
+
+			Self::Custom(ref e) => Some(e),
 		}
 	}
 }
 
+impl From<BitFieldError> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: BitFieldError) -> Self {
+		Self::BadBits(value)
+	}
+}
+
 impl From<BorrowError> for GenericEncodeError {
 	#[inline(always)]
 	fn from(value: BorrowError) -> Self {
@@ -89,6 +150,13 @@ impl From<BorrowError> for GenericEncodeError {
 	}
 }
 
+impl From<CastError> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: CastError) -> Self {
+		Self::BadCast(value)
+	}
+}
+
 impl<L, I> From<CollectionEncodeError<L, I>> for GenericEncodeError
 where
 	L: Into<Self>,
@@ -106,6 +174,13 @@ where
 	}
 }
 
+impl From<CustomError> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: CustomError) -> Self {
+		Self::Custom(value)
+	}
+}
+
 impl<D, F> From<EnumEncodeError<D, F>> for GenericEncodeError
 where
 	D: Encode<Error: Into<Self>>,
@@ -146,6 +221,40 @@ impl<I, E: Into<Self>> From<ItemEncodeError<I, E>> for GenericEncodeError {
 	}
 }
 
+impl From<LengthMismatchError> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: LengthMismatchError) -> Self {
+		Self::BadLength(value)
+	}
+}
+
+impl<E: Into<Self>> From<MessageEncodeError<E>> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: MessageEncodeError<E>) -> Self {
+		use MessageEncodeError as Error;
+
+		match value {
+			Error::BadLength(e) => e.into(),
+
+			Error::BadMessage(e) => e.into(),
+		}
+	}
+}
+
+impl From<PresenceError> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: PresenceError) -> Self {
+		Self::BadPresence(value)
+	}
+}
+
+impl From<TagMismatchError> for GenericEncodeError {
+	#[inline(always)]
+	fn from(value: TagMismatchError) -> Self {
+		Self::BadTag(value)
+	}
+}
+
 impl From<UsizeEncodeError> for GenericEncodeError {
 	#[inline(always)]
 	fn from(value: UsizeEncodeError) -> Self {
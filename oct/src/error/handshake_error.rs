@@ -0,0 +1,63 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::FingerprintError;
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use std::io;
+
+/// A fingerprint handshake failed.
+///
+/// This error is returned by [`Handshake::exchange`](crate::fingerprint::Handshake::exchange).
+#[derive(Debug)]
+#[must_use]
+pub enum HandshakeError {
+	/// The stream could not be written to or read from.
+	Io(io::Error),
+
+	/// The fingerprints did not match.
+	Mismatch(FingerprintError),
+}
+
+impl Display for HandshakeError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Io(ref e)
+			=> write!(f, "unable to exchange fingerprints: {e}"),
+
+			Self::Mismatch(ref e)
+			=> write!(f, "{e}"),
+		}
+	}
+}
+
+impl Error for HandshakeError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::Io(ref e) => Some(e),
+
+			Self::Mismatch(ref e) => Some(e),
+		}
+	}
+}
@@ -0,0 +1,61 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A collection did not match its length field.
+///
+/// This error type is yielded by [derived](derive@crate::encode::Encode) implementations of [`Encode`](crate::encode::Encode) when a field with the `len_from` attribute has a different length than that given by its length field.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct LengthMismatchError {
+	/// The length given by the length field.
+	pub expected: usize,
+
+	/// The actual length of the collection.
+	pub found: usize,
+}
+
+impl LengthMismatchError {
+	/// Checks that the length of a collection matches that of its length field.
+	///
+	/// # Errors
+	///
+	/// If the two lengths differ, then an error is returned.
+	#[inline]
+	pub const fn check(expected: usize, found: usize) -> Result<(), Self> {
+		if expected == found {
+			Ok(())
+		} else {
+			Err(Self { expected, found })
+		}
+	}
+}
+
+impl Display for LengthMismatchError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "collection has ({}) elements but its length field is ({})", self.found, self.expected)
+	}
+}
+
+impl Error for LengthMismatchError { }
@@ -0,0 +1,126 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A magic value or constant did not match.
+///
+/// This error type is yielded by [derived](derive@crate::decode::Decode) implementations of [`Decode`](crate::decode::Decode) when the `magic` or `constant` attributes are not matched by the input.
+/// It contains both the expected and the found bytes, each of which may be at most [`MAX_LEN`](Self::MAX_LEN) bytes long.
+///
+/// If a constant could not itself be encoded, then it cannot be matched at all, and both byte sequences are empty (see [`unencodable`](Self::unencodable)).
+#[derive(Debug)]
+#[must_use]
+pub struct MagicError {
+	expected:  [u8; Self::MAX_LEN],
+	found:     [u8; Self::MAX_LEN],
+	len:       usize,
+	encodable: bool,
+}
+
+impl MagicError {
+	/// The maximum length of magic values and constants.
+	pub const MAX_LEN: usize = 0x10;
+
+	/// Constructs a new magic error.
+	///
+	/// # Panics
+	///
+	/// If `expected` and `found` have different lengths, or if they are longer than [`MAX_LEN`](Self::MAX_LEN), then this function will panic.
+	#[inline]
+	#[track_caller]
+	pub fn new(expected: &[u8], found: &[u8]) -> Self {
+		assert_eq!(expected.len(), found.len(), "expected and found bytes must have the same length");
+		assert!(expected.len() <= Self::MAX_LEN, "magic values cannot be longer than ({}) bytes", Self::MAX_LEN);
+
+		let len = expected.len();
+
+		let mut this = Self {
+			expected:  [0x00; Self::MAX_LEN],
+			found:     [0x00; Self::MAX_LEN],
+			len,
+			encodable: true,
+		};
+
+		this.expected[..len].copy_from_slice(expected);
+		this.found[..len].copy_from_slice(found);
+
+		this
+	}
+
+	/// Constructs a new error for a constant that could not be encoded.
+	#[inline(always)]
+	pub const fn unencodable() -> Self {
+		Self {
+			expected:  [0x00; Self::MAX_LEN],
+			found:     [0x00; Self::MAX_LEN],
+			len:       0x0,
+			encodable: false,
+		}
+	}
+
+	/// Checks that the found bytes match the expected ones.
+	///
+	/// # Errors
+	///
+	/// If the two slices differ, then an error is returned.
+	///
+	/// # Panics
+	///
+	/// See [`new`](Self::new).
+	#[inline]
+	#[track_caller]
+	pub fn check(expected: &[u8], found: &[u8]) -> Result<(), Self> {
+		if expected == found {
+			Ok(())
+		} else {
+			Err(Self::new(expected, found))
+		}
+	}
+
+	/// Gets the expected bytes.
+	#[inline(always)]
+	#[must_use]
+	pub fn expected(&self) -> &[u8] {
+		&self.expected[..self.len]
+	}
+
+	/// Gets the found bytes.
+	#[inline(always)]
+	#[must_use]
+	pub fn found(&self) -> &[u8] {
+		&self.found[..self.len]
+	}
+}
+
+impl Display for MagicError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		if !self.encodable {
+			return write!(f, "constant could not be encoded");
+		}
+
+		write!(f, "expected magic `{:02X?}` but found `{:02X?}`", self.expected(), self.found())
+	}
+}
+
+impl Error for MagicError { }
@@ -0,0 +1,75 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A message could not be decoded.
+///
+/// This type is returned by the [`Decode`](crate::decode::Decode) implementation of [`Envelope`](crate::message::Envelope) as well as by [`Registry::decode`](crate::message::Registry::decode).
+#[derive(Debug)]
+#[must_use]
+pub enum MessageDecodeError<E> {
+	/// The message identifier did not denote any known message type.
+	///
+	/// For envelopes, this means that the identifier did not match that of the expected type.
+	UnassignedId {
+		/// The unassigned message identifier.
+		id: u32,
+	},
+
+	/// The message payload could not be decoded.
+	BadMessage {
+		/// The identifier of the invalid message.
+		id: u32,
+
+		/// The payload decoder's error.
+		error: E,
+	},
+}
+
+impl<E: Display> Display for MessageDecodeError<E> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::UnassignedId { id }
+			=> write!(f, "message identifier `{id:#X}` has not been assigned"),
+
+			Self::BadMessage { id, ref error }
+			=> write!(f, "message `{id:#X}` could not be decoded: {error}"),
+		}
+	}
+}
+
+impl<E> Error for MessageDecodeError<E>
+where
+	Self: Debug + Display,
+	E: Error + 'static,
+{
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadMessage { ref error, .. } => Some(error),
+
+			_ => None,
+		}
+	}
+}
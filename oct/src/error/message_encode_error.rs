@@ -0,0 +1,74 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::UsizeEncodeError;
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A message could not be encoded.
+///
+/// This type is returned by the [`Encode`](crate::encode::Encode) implementation of [`Envelope`](crate::message::Envelope).
+#[derive(Debug)]
+#[must_use]
+pub enum MessageEncodeError<E> {
+	/// The length of the message payload could not be encoded.
+	BadLength(UsizeEncodeError),
+
+	/// The message payload could not be encoded.
+	BadMessage(E),
+}
+
+impl<E: Display> Display for MessageEncodeError<E> {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::BadLength(ref e)
+			=> write!(f, "unable to encode message length: {e}"),
+
+			Self::BadMessage(ref e)
+			=> write!(f, "unable to encode message: {e}"),
+		}
+	}
+}
+
+impl<E> Error for MessageEncodeError<E>
+where
+	Self: Debug + Display,
+	E: Error + 'static,
+{
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadLength(ref e) => Some(e),
+
+			Self::BadMessage(ref e) => Some(e),
+		}
+	}
+}
+
+impl<E: Into<Self>> From<MessageEncodeError<E>> for Infallible {
+	#[inline(always)]
+	fn from(_value: MessageEncodeError<E>) -> Self {
+		unreachable!()
+	}
+}
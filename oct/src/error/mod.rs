@@ -24,13 +24,18 @@
 //! This module defines the error types used by oct.
 //! All of these types define (at least conditionally) the [`Error`](core::error::Error) trait.
 
-use crate::use_mod;
-
+use_mod!(pub bit_field_error);
+use_mod!(pub cast_error);
 use_mod!(pub char_decode_error);
+use_mod!(pub checked_decode_error);
+use_mod!(pub checksum_error);
 use_mod!(pub collection_decode_error);
 use_mod!(pub collection_encode_error);
+use_mod!(pub custom_error);
 use_mod!(pub enum_decode_error);
 use_mod!(pub enum_encode_error);
+use_mod!(pub field_error);
+use_mod!(pub fingerprint_error);
 use_mod!(pub generic_decode_error);
 use_mod!(pub generic_encode_error);
 use_mod!(pub input_error);
@@ -38,16 +43,35 @@ use_mod!(pub isize_encode_error);
 use_mod!(pub item_decode_error);
 use_mod!(pub item_encode_error);
 use_mod!(pub length_error);
+use_mod!(pub length_mismatch_error);
+use_mod!(pub magic_error);
+use_mod!(pub message_decode_error);
+use_mod!(pub message_encode_error);
 use_mod!(pub non_zero_decode_error);
 use_mod!(pub output_error);
+use_mod!(pub padding_error);
+use_mod!(pub presence_error);
+use_mod!(pub proxy_decode_error);
 use_mod!(pub ref_cell_encode_error);
 use_mod!(pub string_error);
+use_mod!(pub tag_mismatch_error);
 use_mod!(pub usize_encode_error);
 use_mod!(pub utf16_error);
 use_mod!(pub utf8_error);
+use_mod!(pub validation_error);
+use_mod!(pub varint_decode_error);
 
 #[cfg(feature = "alloc")]
 use_mod!(pub c_string_decode_error);
 
+#[cfg(any(feature = "bytes", feature = "embedded-io"))]
+use_mod!(pub stream_decode_error);
+
+#[cfg(any(feature = "bytes", feature = "embedded-io"))]
+use_mod!(pub stream_encode_error);
+
+#[cfg(feature = "std")]
+use_mod!(pub handshake_error);
+
 #[cfg(feature = "std")]
 use_mod!(pub system_time_decode_error);
@@ -0,0 +1,60 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Padding or reserved bytes were not zero.
+///
+/// This error type is yielded by [derived](derive@crate::decode::Decode) implementations of [`Decode`](crate::decode::Decode) when the `zeroed` attribute is specified.
+#[derive(Debug)]
+#[must_use]
+pub struct PaddingError {
+	/// The offset of the first non-zero byte into the padding.
+	pub offset: usize,
+
+	/// The value of the non-zero byte.
+	pub value: u8,
+}
+
+impl PaddingError {
+	/// Checks that all of the provided padding bytes are zero.
+	///
+	/// # Errors
+	///
+	/// If any byte is non-zero, then an error is returned for the first such byte.
+	#[inline]
+	pub fn check(padding: &[u8]) -> Result<(), Self> {
+		padding
+			.iter()
+			.position(|&value| value != 0x00)
+			.map_or(Ok(()), |offset| Err(Self { offset, value: padding[offset] }))
+	}
+}
+
+impl Display for PaddingError {
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "padding byte at offset ({}) has non-zero value `{:#04X}`", self.offset, self.value)
+	}
+}
+
+impl Error for PaddingError { }
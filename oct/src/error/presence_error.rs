@@ -0,0 +1,62 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// The presence of a conditional field did not match its condition.
+///
+/// This error type is yielded by [derived](derive@crate::encode::Encode) implementations of [`Encode`](crate::encode::Encode) when a field with the `if` attribute is `Some` whilst its condition does not hold, or is `None` whilst its condition holds.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct PresenceError {
+	/// Whether the field was present.
+	pub present: bool,
+}
+
+impl PresenceError {
+	/// Checks that the presence of a field matches its condition.
+	///
+	/// # Errors
+	///
+	/// If `condition` and `present` differ, then an error is returned.
+	#[inline]
+	pub const fn check(condition: bool, present: bool) -> Result<(), Self> {
+		if condition == present {
+			Ok(())
+		} else {
+			Err(Self { present })
+		}
+	}
+}
+
+impl Display for PresenceError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		if self.present {
+			write!(f, "conditional field is present but its condition does not hold")
+		} else {
+			write!(f, "conditional field is absent but its condition holds")
+		}
+	}
+}
+
+impl Error for PresenceError { }
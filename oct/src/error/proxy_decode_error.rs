@@ -0,0 +1,68 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A value could not be decoded through its proxy type.
+///
+/// This error is returned by [derived](derive@crate::decode::Decode) implementations of [`Decode`](crate::decode::Decode) that use the `try_from` attribute.
+#[derive(Debug)]
+#[must_use]
+pub enum ProxyDecodeError<D, C> {
+	/// The proxy type could not be decoded.
+	BadProxy(D),
+
+	/// The proxy could not be converted into the value.
+	BadConversion(C),
+}
+
+impl<D, C> Display for ProxyDecodeError<D, C>
+where
+	D: Display,
+	C: Display,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::BadProxy(ref e)
+			=> write!(f, "unable to decode proxy: {e}"),
+
+			Self::BadConversion(ref e)
+			=> write!(f, "unable to convert proxy: {e}"),
+		}
+	}
+}
+
+impl<D, C> Error for ProxyDecodeError<D, C>
+where
+	D: Error + 'static,
+	C: Error + 'static,
+{
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadProxy(ref e) => Some(e),
+
+			Self::BadConversion(ref e) => Some(e),
+		}
+	}
+}
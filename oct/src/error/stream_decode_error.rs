@@ -0,0 +1,100 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::LengthError;
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A value could not be decoded from a stream.
+///
+/// This error is returned by stream adapters such as [`Reader`](crate::io::Reader) and [`get_framed`](crate::buf::get_framed).
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+#[derive(Debug)]
+#[must_use]
+pub enum StreamDecodeError<E, I> {
+	/// The encoding was too large for the reader's buffer.
+	BadLength(LengthError),
+
+	/// The value itself could not be decoded.
+	BadValue(E),
+
+	/// The stream could not be read from.
+	Io(I),
+
+	/// The stream ended before the entire encoding could be read.
+	UnexpectedEnd,
+}
+
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+impl<E, I> Display for StreamDecodeError<E, I>
+where
+	E: Display,
+	I: Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::BadLength(ref e)
+			=> write!(f, "unable to read value: {e}"),
+
+			Self::BadValue(ref e)
+			=> write!(f, "unable to decode value: {e}"),
+
+			Self::Io(ref e)
+			=> write!(f, "unable to read from stream: {e:?}"),
+
+			Self::UnexpectedEnd
+			=> write!(f, "stream ended unexpectedly"),
+		}
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+impl<E, I> Error for StreamDecodeError<E, I>
+where
+	E: Error + 'static,
+	I: Debug,
+{
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadLength(ref e) => Some(e),
+
+			Self::BadValue(ref e) => Some(e),
+
+			_ => None,
+		}
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+impl<E, I> From<StreamDecodeError<E, I>> for Infallible
+where
+	E: Into<Self>,
+	I: Into<Self>,
+{
+	#[inline(always)]
+	fn from(_value: StreamDecodeError<E, I>) -> Self {
+		unreachable!()
+	}
+}
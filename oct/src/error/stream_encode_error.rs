@@ -0,0 +1,94 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::UsizeEncodeError;
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A value could not be encoded to a stream.
+///
+/// This error is returned by stream adapters such as [`Writer`](crate::io::Writer) and [`put_framed`](crate::buf::put_framed).
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+#[derive(Debug)]
+#[must_use]
+pub enum StreamEncodeError<E, I> {
+	/// The length of the encoding could not be encoded.
+	BadLength(UsizeEncodeError),
+
+	/// The value itself could not be encoded.
+	BadValue(E),
+
+	/// The stream could not be written to.
+	Io(I),
+}
+
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+impl<E, I> Display for StreamEncodeError<E, I>
+where
+	E: Display,
+	I: Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::BadLength(ref e)
+			=> write!(f, "unable to encode length of value: {e}"),
+
+			Self::BadValue(ref e)
+			=> write!(f, "unable to encode value: {e}"),
+
+			Self::Io(ref e)
+			=> write!(f, "unable to write to stream: {e:?}"),
+		}
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+impl<E, I> Error for StreamEncodeError<E, I>
+where
+	E: Error + 'static,
+	I: Debug,
+{
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match *self {
+			Self::BadLength(ref e) => Some(e),
+
+			Self::BadValue(ref e) => Some(e),
+
+			Self::Io(..) => None,
+		}
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(any(feature = "bytes", feature = "embedded-io"))))]
+impl<E, I> From<StreamEncodeError<E, I>> for Infallible
+where
+	E: Into<Self>,
+	I: Into<Self>,
+{
+	#[inline(always)]
+	fn from(_value: StreamEncodeError<E, I>) -> Self {
+		unreachable!()
+	}
+}
@@ -0,0 +1,67 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrimitiveDiscriminant;
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// An externally-tagged value did not match its tag.
+///
+/// This error type is yielded by [derived](derive@crate::encode::Encode) implementations of [`Encode`](crate::encode::Encode) when a field with the `tag_from` attribute has a different discriminant than that given by its tag field.
+/// Both discriminants are stored as zero-extended `u128` values.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct TagMismatchError {
+	/// The discriminant given by the tag field.
+	pub expected: u128,
+
+	/// The discriminant of the tagged value.
+	pub found: u128,
+}
+
+impl TagMismatchError {
+	/// Checks that the discriminant of a tagged value matches that of its tag field.
+	///
+	/// # Errors
+	///
+	/// If the two discriminants differ, then an error is returned.
+	#[inline]
+	pub fn check<T>(expected: T, found: T) -> Result<(), Self>
+	where
+		T: PartialEq + PrimitiveDiscriminant,
+	{
+		if expected == found {
+			Ok(())
+		} else {
+			Err(Self { expected: expected.to_u128(), found: found.to_u128() })
+		}
+	}
+}
+
+impl Display for TagMismatchError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "tagged value has discriminant `{:#X}` but its tag field is `{:#X}`", self.found, self.expected)
+	}
+}
+
+impl Error for TagMismatchError { }
@@ -0,0 +1,80 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A decoded value did not pass validation.
+///
+/// This error type is yielded by [derived](derive@crate::decode::Decode) implementations of [`Decode`](crate::decode::Decode) when the `validate`, `range`, or `max_len` attributes reject a value.
+/// Custom validators also return this type:
+///
+/// ```
+/// use oct::error::ValidationError;
+///
+/// fn validate_name(name: &str) -> Result<(), ValidationError> {
+///     if name.is_empty() {
+///         return Err(ValidationError::Invalid { message: "name is empty" });
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+#[must_use]
+#[non_exhaustive]
+pub enum ValidationError {
+	/// A value was outside of its allowed range.
+	OutOfRange,
+
+	/// A collection was longer than allowed.
+	TooLong {
+		/// The maximum allowed length.
+		max_len: usize,
+
+		/// The actual length.
+		len: usize,
+	},
+
+	/// A custom validator rejected the value.
+	Invalid {
+		/// The reason for the rejection.
+		message: &'static str,
+	},
+}
+
+impl Display for ValidationError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::OutOfRange
+			=> write!(f, "value is out of range"),
+
+			Self::TooLong { max_len, len }
+			=> write!(f, "length ({len}) exceeds maximum of ({max_len})"),
+
+			Self::Invalid { message }
+			=> write!(f, "{message}"),
+		}
+	}
+}
+
+impl Error for ValidationError { }
@@ -0,0 +1,42 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// A variable-length integer could not be decoded.
+///
+/// This error type is yielded by [`varint::decode`](crate::repr::varint::decode) when the encoding is longer than allowed for the target type, or when the decoded value does not fit in it.
+#[derive(Debug, Eq, PartialEq)]
+#[must_use]
+pub struct VarintDecodeError {
+	/// The name of the target type.
+	pub target: &'static str,
+}
+
+impl Display for VarintDecodeError {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "variable-length integer does not fit in `{}`", self.target)
+	}
+}
+
+impl Error for VarintDecodeError { }
@@ -0,0 +1,597 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::fingerprint::FingerprintHasher;
+
+use core::cell::{Cell, LazyCell, RefCell};
+use core::convert::Infallible;
+use core::ffi::CStr;
+use core::marker::{PhantomData, PhantomPinned};
+use core::net::{
+	IpAddr,
+	Ipv4Addr,
+	Ipv6Addr,
+	SocketAddr,
+	SocketAddrV4,
+	SocketAddrV6,
+};
+use core::num::{Saturating, Wrapping};
+use core::ops::{
+	Bound,
+	Range,
+	RangeFrom,
+	RangeFull,
+	RangeInclusive,
+	RangeTo,
+	RangeToInclusive,
+};
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::LinkedList;
+
+#[cfg(feature = "alloc")]
+use alloc::ffi::CString;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "bytes")]
+use bytes::{Bytes, BytesMut};
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "std")]
+use std::sync::{LazyLock, Mutex, RwLock};
+
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+/// Denotes a type with a structural fingerprint.
+///
+/// The fingerprint of a type is a stable hash of its encoded structure.
+/// Two types with the same fingerprint are expected to share the same encoding scheme, whilst two types with different fingerprints are not.
+///
+/// Fingerprints are computed using [`FingerprintHasher`] and are thus stable across builds and platforms.
+/// They do, however, change whenever the structure of a type changes -- including when the data model of oct itself changes.
+///
+/// Types that only wrap another type without changing its encoding -- such as [`Box`](alloc::boxed::Box) and [`Cell`] -- share the fingerprint of the wrapped type.
+///
+/// It is recommended to simply derive this trait for custom types.
+pub trait Fingerprint {
+	/// The fingerprint of the type.
+	const FINGERPRINT: u64;
+}
+
+impl<T: Fingerprint + ?Sized> Fingerprint for &T {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint + ?Sized> Fingerprint for &mut T {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+/// Implemented for tuples with up to twelve members.
+#[cfg_attr(doc, doc(fake_variadic))]
+impl<T: Fingerprint> Fingerprint for (T, ) {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"tuple")
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+impl<T: Fingerprint, const N: usize> Fingerprint for [T; N] {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"array")
+		.write_u64(N as u64)
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+impl<T: Fingerprint> Fingerprint for [T] {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"slice")
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(doc, doc(cfg(all(feature = "alloc", target_has_atomic = "ptr"))))]
+impl<T: Fingerprint + ?Sized> Fingerprint for Arc<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for Bound<T> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"Bound")
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Fingerprint + ?Sized> Fingerprint for Box<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+/// Shares the fingerprint of <code>[\[u8\]](slice)</code>.
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl Fingerprint for Bytes {
+	const FINGERPRINT: u64 = <[u8]>::FINGERPRINT;
+}
+
+/// Shares the fingerprint of <code>[\[u8\]](slice)</code>.
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl Fingerprint for BytesMut {
+	const FINGERPRINT: u64 = <[u8]>::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for Cell<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Fingerprint + ?Sized + ToOwned> Fingerprint for Cow<'_, T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+/// Shares the fingerprint of <code>[\[u8\]](slice)</code>.
+impl Fingerprint for CStr {
+	const FINGERPRINT: u64 = <[u8]>::FINGERPRINT;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl Fingerprint for CString {
+	const FINGERPRINT: u64 = CStr::FINGERPRINT;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl<K: Fingerprint, V: Fingerprint, S> Fingerprint for HashMap<K, V, S> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"map")
+		.write_u64(K::FINGERPRINT)
+		.write_u64(V::FINGERPRINT)
+		.finish();
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl<K: Fingerprint, S> Fingerprint for HashSet<K, S> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"set")
+		.write_u64(K::FINGERPRINT)
+		.finish();
+}
+
+impl<T: Fingerprint> Fingerprint for LazyCell<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl<T: Fingerprint> Fingerprint for LazyLock<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+/// Shares the fingerprint of <code>[\[T\]](slice)</code>.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Fingerprint> Fingerprint for LinkedList<T> {
+	const FINGERPRINT: u64 = <[T]>::FINGERPRINT;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl<T: Fingerprint + ?Sized> Fingerprint for Mutex<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for Option<T> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"Option")
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+impl<T: ?Sized> Fingerprint for PhantomData<T> {
+	const FINGERPRINT: u64 = <()>::FINGERPRINT;
+}
+
+impl Fingerprint for PhantomPinned {
+	const FINGERPRINT: u64 = <()>::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for Range<T> {
+	const FINGERPRINT: u64 = <(T, T)>::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for RangeFrom<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl Fingerprint for RangeFull {
+	const FINGERPRINT: u64 = <()>::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for RangeInclusive<T> {
+	const FINGERPRINT: u64 = <(T, T)>::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for RangeTo<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for RangeToInclusive<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Fingerprint + ?Sized> Fingerprint for Rc<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint + ?Sized> Fingerprint for RefCell<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint, E: Fingerprint> Fingerprint for Result<T, E> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"Result")
+		.write_u64(T::FINGERPRINT)
+		.write_u64(E::FINGERPRINT)
+		.finish();
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl<T: Fingerprint + ?Sized> Fingerprint for RwLock<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for Saturating<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+/// Shares the fingerprint of <code>[\[u8\]](slice)</code>.
+impl Fingerprint for str {
+	const FINGERPRINT: u64 = <[u8]>::FINGERPRINT;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl Fingerprint for String {
+	const FINGERPRINT: u64 = str::FINGERPRINT;
+}
+
+/// Shares the fingerprint of [`i64`].
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl Fingerprint for SystemTime {
+	const FINGERPRINT: u64 = i64::FINGERPRINT;
+}
+
+impl Fingerprint for () {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"tuple")
+		.finish();
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<T: Fingerprint> Fingerprint for Vec<T> {
+	const FINGERPRINT: u64 = <[T]>::FINGERPRINT;
+}
+
+impl<T: Fingerprint> Fingerprint for Wrapping<T> {
+	const FINGERPRINT: u64 = T::FINGERPRINT;
+}
+
+macro_rules! impl_named {
+	($ty:ty$(,)?) => {
+		impl ::oct::fingerprint::Fingerprint for $ty {
+			const FINGERPRINT: u64 = ::oct::fingerprint::FingerprintHasher::new()
+				.write(stringify!($ty).as_bytes())
+				.finish();
+		}
+	};
+}
+
+macro_rules! impl_tuple {
+	{
+		$($tys:ident),+$(,)?
+	} => {
+		#[doc(hidden)]
+		impl<$($tys: ::oct::fingerprint::Fingerprint, )*> ::oct::fingerprint::Fingerprint for ($($tys, )*) {
+			const FINGERPRINT: u64 = ::oct::fingerprint::FingerprintHasher::new()
+				.write(b"tuple")
+				$(.write_u64(<$tys as ::oct::fingerprint::Fingerprint>::FINGERPRINT))*
+				.finish();
+		}
+	};
+}
+
+macro_rules! impl_transparent {
+	($ty:ty => $inner:ty$(,)?) => {
+		impl ::oct::fingerprint::Fingerprint for $ty {
+			const FINGERPRINT: u64 = <$inner as ::oct::fingerprint::Fingerprint>::FINGERPRINT;
+		}
+	};
+}
+
+macro_rules! impl_atomic {
+	{
+		width: $width:literal,
+		ty: $ty:ty,
+		atomic_ty: $atomic_ty:ty$(,)?
+	} => {
+		#[cfg(target_has_atomic = $width)]
+		#[cfg_attr(doc, doc(cfg(target_has_atomic = $width)))]
+		impl ::oct::fingerprint::Fingerprint for $atomic_ty {
+			const FINGERPRINT: u64 = <$ty as ::oct::fingerprint::Fingerprint>::FINGERPRINT;
+		}
+	};
+}
+
+//impl_named!(f128);
+//impl_named!(f16);
+impl_named!(f32);
+impl_named!(f64);
+impl_named!(i128);
+impl_named!(i16);
+impl_named!(i32);
+impl_named!(i64);
+impl_named!(i8);
+impl_named!(u128);
+impl_named!(u16);
+impl_named!(u32);
+impl_named!(u64);
+impl_named!(u8);
+
+impl_named!(bool);
+impl_named!(char);
+impl_named!(Duration);
+impl_named!(Infallible);
+impl_named!(IpAddr);
+impl_named!(Ipv4Addr);
+impl_named!(Ipv6Addr);
+impl_named!(SocketAddr);
+impl_named!(SocketAddrV4);
+impl_named!(SocketAddrV6);
+
+// These encode as their respective, fixed-size coun-
+// terparts.
+impl_transparent!(isize => i16);
+impl_transparent!(usize => u16);
+
+
+impl_transparent!(::core::num::NonZero<i128>  => i128);
+impl_transparent!(::core::num::NonZero<i16>   => i16);
+impl_transparent!(::core::num::NonZero<i32>   => i32);
+impl_transparent!(::core::num::NonZero<i64>   => i64);
+impl_transparent!(::core::num::NonZero<i8>    => i8);
+impl_transparent!(::core::num::NonZero<isize> => isize);
+impl_transparent!(::core::num::NonZero<u128>  => u128);
+impl_transparent!(::core::num::NonZero<u16>   => u16);
+impl_transparent!(::core::num::NonZero<u32>   => u32);
+impl_transparent!(::core::num::NonZero<u64>   => u64);
+impl_transparent!(::core::num::NonZero<u8>    => u8);
+impl_transparent!(::core::num::NonZero<usize> => usize);
+
+impl_tuple! {
+	T0,
+	T1,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+	T9,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+	T9,
+	T10,
+}
+
+impl_tuple! {
+	T0,
+	T1,
+	T2,
+	T3,
+	T4,
+	T5,
+	T6,
+	T7,
+	T8,
+	T9,
+	T10,
+	T11,
+}
+
+impl_atomic! {
+	width: "8",
+	ty: bool,
+	atomic_ty: core::sync::atomic::AtomicBool,
+}
+
+impl_atomic! {
+	width: "16",
+	ty: i16,
+	atomic_ty: core::sync::atomic::AtomicI16,
+}
+
+impl_atomic! {
+	width: "32",
+	ty: i32,
+	atomic_ty: core::sync::atomic::AtomicI32,
+}
+
+impl_atomic! {
+	width: "64",
+	ty: i64,
+	atomic_ty: core::sync::atomic::AtomicI64,
+}
+
+impl_atomic! {
+	width: "8",
+	ty: i8,
+	atomic_ty: core::sync::atomic::AtomicI8,
+}
+
+impl_atomic! {
+	width: "ptr",
+	ty: isize,
+	atomic_ty: core::sync::atomic::AtomicIsize,
+}
+
+impl_atomic! {
+	width: "16",
+	ty: u16,
+	atomic_ty: core::sync::atomic::AtomicU16,
+}
+
+impl_atomic! {
+	width: "32",
+	ty: u32,
+	atomic_ty: core::sync::atomic::AtomicU32,
+}
+
+impl_atomic! {
+	width: "64",
+	ty: u64,
+	atomic_ty: core::sync::atomic::AtomicU64,
+}
+
+impl_atomic! {
+	width: "8",
+	ty: u8,
+	atomic_ty: core::sync::atomic::AtomicU8,
+}
+
+impl_atomic! {
+	width: "ptr",
+	ty: usize,
+	atomic_ty: core::sync::atomic::AtomicUsize,
+}
@@ -0,0 +1,223 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::fingerprint::Fingerprint;
+use std::boxed::Box;
+use std::string::String;
+use std::vec::Vec;
+
+macro_rules! test {
+	(!$left:ty, $right:ty $(,)?) => {{
+		assert_ne!(<$left as Fingerprint>::FINGERPRINT, <$right as Fingerprint>::FINGERPRINT);
+	}};
+
+	($left:ty, $right:ty $(,)?) => {{
+		assert_eq!(<$left as Fingerprint>::FINGERPRINT, <$right as Fingerprint>::FINGERPRINT);
+	}};
+}
+
+#[test]
+fn test_fingerprint() {
+	test!(!u8, i8);
+	test!(!u16, u32);
+	test!(usize, u16);
+	test!(isize, i16);
+
+	test!(Box<u32>, u32);
+	test!(&u32, u32);
+
+	test!(String, str);
+	test!(str, [u8]);
+	test!(Vec<u8>, [u8]);
+	test!(![u8; 0x2], [u8; 0x3]);
+	test!(![u8; 0x2], [u8]);
+
+	test!(!(u8, u16), (u16, u8));
+	test!(!(u8, (u16,)), (u8, u16));
+	test!(!Option<u8>, u8);
+}
+
+#[test]
+fn test_fingerprint_derive() {
+	#[derive(Fingerprint)]
+	struct Foo {
+		_bar: u16,
+		_baz: char,
+	}
+
+	#[derive(Fingerprint)]
+	struct Qux {
+		_bar: char,
+		_baz: u16,
+	}
+
+	test!(Foo, (u16, char));
+	test!(!Foo, Qux);
+
+	#[derive(Fingerprint)]
+	#[repr(u8)]
+	enum Enum0 {
+		_Foo(u16),
+		_Bar,
+	}
+
+	#[derive(Fingerprint)]
+	#[repr(u8)]
+	enum Enum1 {
+		_Foo(u16) = 0x1,
+		_Bar,
+	}
+
+	#[derive(Fingerprint)]
+	#[repr(u16)]
+	enum Enum2 {
+		_Foo(u16),
+		_Bar,
+	}
+
+	#[derive(Fingerprint)]
+	#[repr(u8)]
+	enum Enum3 {
+		_Foo(u32),
+		_Bar,
+	}
+
+	test!(!Enum0, Enum1);
+	test!(!Enum0, Enum2);
+	test!(!Enum0, Enum3);
+
+	#[derive(Fingerprint)]
+	struct Version0 {
+		_id: u16,
+	}
+
+	#[derive(Fingerprint)]
+	struct Version1 {
+		_id: u16,
+
+		#[oct(since = 1)]
+		_flags: u8,
+	}
+
+	#[derive(Fingerprint)]
+	struct Version2 {
+		_id: u16,
+
+		#[oct(since = 1)]
+		_flags: u8,
+
+		#[oct(since = 2)]
+		_name: char,
+	}
+
+	#[derive(Fingerprint)]
+	struct Version1Wide {
+		_id: u16,
+
+		#[oct(since = 1)]
+		_flags: u64,
+	}
+
+	#[derive(Fingerprint)]
+	struct Version2Flags {
+		_id: u16,
+
+		#[oct(since = 2)]
+		_flags: u8,
+	}
+
+	#[derive(Fingerprint)]
+	#[oct(versioned)]
+	struct Version0Prefixed {
+		_id: u16,
+	}
+
+	test!(Version0, (u16,));
+	test!(!Version0, Version0Prefixed);
+	test!(!Version0, Version1);
+	test!(!Version1, Version2);
+	test!(!Version1, Version1Wide);
+	test!(!Version1, Version2Flags);
+
+	#[derive(Fingerprint)]
+	#[oct(into = "u32", from = "u32")]
+	struct Proxied {
+		_value: u8,
+	}
+
+	test!(Proxied, u32);
+
+	#[derive(Fingerprint)]
+	struct Be32 {
+		#[oct(be)]
+		_value: u32,
+	}
+
+	#[derive(Fingerprint)]
+	struct Be64 {
+		#[oct(be)]
+		_value: u64,
+	}
+
+	#[derive(Fingerprint)]
+	struct Varint32 {
+		#[oct(varint)]
+		_value: u32,
+	}
+
+	#[derive(Fingerprint)]
+	struct VarintI32 {
+		#[oct(varint)]
+		_value: i32,
+	}
+
+	#[derive(Fingerprint)]
+	struct Cast8 {
+		#[oct(as = "u8")]
+		_value: u32,
+	}
+
+	#[derive(Fingerprint)]
+	struct Cast16 {
+		#[oct(as = "u16")]
+		_value: u32,
+	}
+
+	test!(!Be32, Be64);
+	test!(!Varint32, VarintI32);
+	test!(!Cast8, Cast16);
+	test!(!Be32, Varint32);
+
+	#[derive(Fingerprint)]
+	struct Constant1 {
+		#[oct(constant = 0x1u16)]
+		_version: u16,
+	}
+
+	#[derive(Fingerprint)]
+	struct Constant2 {
+		#[oct(constant = 0x2u16)]
+		_version: u16,
+	}
+
+	test!(!Constant1, Constant2);
+	test!(!Constant1, (u16,));
+}
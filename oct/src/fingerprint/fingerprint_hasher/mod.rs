@@ -0,0 +1,103 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+/// Constant hasher for fingerprints.
+///
+/// This type implements the 64-bit variant of the [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hash function.
+/// It is used for computing [fingerprints](crate::fingerprint::Fingerprint) in constant expressions, and the resulting hashes are therefore stable across builds and platforms.
+///
+/// # Examples
+///
+/// ```
+/// use oct::fingerprint::{Fingerprint, FingerprintHasher};
+///
+/// struct Foo(u16, char);
+///
+/// impl Fingerprint for Foo {
+///     const FINGERPRINT: u64 = FingerprintHasher::new()
+///         .write(b"tuple")
+///         .write_u64(u16::FINGERPRINT)
+///         .write_u64(char::FINGERPRINT)
+///         .finish();
+/// }
+///
+/// assert_eq!(Foo::FINGERPRINT, <(u16, char)>::FINGERPRINT);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct FingerprintHasher(u64);
+
+impl FingerprintHasher {
+	const OFFSET_BASIS: u64 = 0xCB_F2_9C_E4_84_22_23_25;
+
+	const PRIME: u64 = 0x00_00_01_00_00_00_01_B3;
+
+	/// Constructs a new hasher.
+	#[inline(always)]
+	pub const fn new() -> Self {
+		Self(Self::OFFSET_BASIS)
+	}
+
+	/// Hashes a sequence of bytes.
+	#[inline]
+	pub const fn write(self, data: &[u8]) -> Self {
+		let Self(mut hash) = self;
+
+		let mut i = 0x0;
+		while i < data.len() {
+			hash ^= data[i] as u64;
+			hash = hash.wrapping_mul(Self::PRIME);
+
+			i += 0x1;
+		}
+
+		Self(hash)
+	}
+
+	/// Hashes a [`u64`] value in little-endian.
+	#[inline(always)]
+	pub const fn write_u64(self, value: u64) -> Self {
+		self.write(&value.to_le_bytes())
+	}
+
+	/// Hashes a [`u128`] value in little-endian.
+	#[inline(always)]
+	pub const fn write_u128(self, value: u128) -> Self {
+		self.write(&value.to_le_bytes())
+	}
+
+	/// Retrieves the resulting hash.
+	#[inline(always)]
+	#[must_use]
+	pub const fn finish(self) -> u64 {
+		self.0
+	}
+}
+
+impl Default for FingerprintHasher {
+	#[inline(always)]
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,36 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::fingerprint::FingerprintHasher;
+
+#[test]
+fn test_fingerprint_hasher() {
+	// Test vectors from the reference implementation.
+
+	assert_eq!(FingerprintHasher::new().finish(),                  0xCB_F2_9C_E4_84_22_23_25);
+	assert_eq!(FingerprintHasher::new().write(b"a").finish(),      0xAF_63_DC_4C_86_01_EC_8C);
+	assert_eq!(FingerprintHasher::new().write(b"foobar").finish(), 0x85_94_41_71_F7_39_67_E8);
+
+	assert_eq!(
+		FingerprintHasher::new().write(b"foo").write(b"bar").finish(),
+		FingerprintHasher::new().write(b"foobar").finish(),
+	);
+}
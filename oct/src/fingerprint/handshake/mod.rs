@@ -0,0 +1,139 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::decode::{Decode, Input};
+use crate::encode::{Encode, Output};
+use crate::error::FingerprintError;
+use crate::fingerprint::Fingerprint;
+
+#[cfg(feature = "std")]
+use crate::encode::SizedEncode;
+
+#[cfg(feature = "std")]
+use crate::error::HandshakeError;
+
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
+/// Fingerprint handshake.
+///
+/// This type is used for comparing the [fingerprint](Fingerprint) of a local type with that of a peer.
+/// If the two fingerprints do not match, then the peers are assumed to disagree on the encoding of the type, and no further data should be exchanged.
+///
+/// The fingerprint itself is exchanged as a [`u64`] (in the usual little-endian).
+///
+/// # Examples
+///
+/// ```
+/// use oct::fingerprint::{Fingerprint, Handshake};
+///
+/// #[derive(Fingerprint)]
+/// enum Request {
+///     Ping,
+///     Echo(char),
+/// }
+///
+/// let handshake = Handshake::new::<Request>();
+///
+/// assert!(handshake.verify(Request::FINGERPRINT).is_ok());
+/// assert!(handshake.verify(char::FINGERPRINT).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct Handshake {
+	local: u64,
+}
+
+impl Handshake {
+	/// Constructs a new handshake for the given type.
+	#[inline(always)]
+	pub const fn new<T: Fingerprint + ?Sized>() -> Self {
+		Self { local: T::FINGERPRINT }
+	}
+
+	/// Retrieves the local fingerprint.
+	#[inline(always)]
+	#[must_use]
+	pub const fn local(&self) -> u64 {
+		self.local
+	}
+
+	/// Encodes the local fingerprint into the provided output.
+	#[inline]
+	pub fn encode(&self, output: &mut Output) {
+		let Ok(_) = self.local.encode(output);
+	}
+
+	/// Decodes the fingerprint of the peer from the provided input and verifies it.
+	///
+	/// # Errors
+	///
+	/// If the fingerprints do not match, then an error is returned.
+	#[inline]
+	pub fn decode(&self, input: &mut Input) -> Result<(), FingerprintError> {
+		let Ok(remote) = u64::decode(input);
+
+		self.verify(remote)
+	}
+
+	/// Verifies the fingerprint of the peer.
+	///
+	/// # Errors
+	///
+	/// If the fingerprints do not match, then an error is returned.
+	#[inline]
+	pub const fn verify(&self, remote: u64) -> Result<(), FingerprintError> {
+		if remote != self.local {
+			return Err(FingerprintError { local: self.local, remote });
+		}
+
+		Ok(())
+	}
+
+	/// Exchanges fingerprints with a peer.
+	///
+	/// The local fingerprint is first written to `stream`, after which the fingerprint of the peer is read back and [verified](Self::verify).
+	/// Both peers are expected to perform the same exchange.
+	///
+	/// # Errors
+	///
+	/// If `stream` could not be written to or read from, or if the fingerprints do not match, then an error is returned.
+	#[cfg(feature = "std")]
+	#[cfg_attr(doc, doc(cfg(feature = "std")))]
+	#[inline]
+	pub fn exchange<S: Read + Write>(&self, stream: &mut S) -> Result<(), HandshakeError> {
+		let mut buf = [0x00; u64::MAX_ENCODED_SIZE];
+
+		self.encode(&mut Output::new(&mut buf));
+
+		stream.write_all(&buf).map_err(HandshakeError::Io)?;
+		stream.flush().map_err(HandshakeError::Io)?;
+
+		stream.read_exact(&mut buf).map_err(HandshakeError::Io)?;
+
+		self
+			.decode(&mut Input::new(&buf))
+			.map_err(HandshakeError::Mismatch)
+	}
+}
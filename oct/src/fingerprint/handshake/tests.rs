@@ -0,0 +1,77 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::error::{FingerprintError, HandshakeError};
+use oct::fingerprint::{Fingerprint, Handshake};
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+/// In-memory stream with pre-filled input.
+struct Pipe {
+	input:  io::Cursor<Vec<u8>>,
+	output: Vec<u8>,
+}
+
+impl Read for Pipe {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.input.read(buf)
+	}
+}
+
+impl Write for Pipe {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.output.write(buf)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+#[test]
+fn test_handshake_exchange() {
+	let handshake = Handshake::new::<(u16, char)>();
+
+	let mut pipe = Pipe {
+		input:  io::Cursor::new(<(u16, char)>::FINGERPRINT.to_le_bytes().into()),
+		output: Vec::new(),
+	};
+
+	handshake.exchange(&mut pipe).unwrap();
+	assert_eq!(pipe.output, <(u16, char)>::FINGERPRINT.to_le_bytes());
+
+	let mut pipe = Pipe {
+		input:  io::Cursor::new(<(char, u16)>::FINGERPRINT.to_le_bytes().into()),
+		output: Vec::new(),
+	};
+
+	assert!(matches!(
+		handshake.exchange(&mut pipe),
+		Err(HandshakeError::Mismatch(FingerprintError { .. })),
+	));
+
+	let mut pipe = Pipe {
+		input:  io::Cursor::new(Vec::new()),
+		output: Vec::new(),
+	};
+
+	assert!(matches!(handshake.exchange(&mut pipe), Err(HandshakeError::Io(..))));
+}
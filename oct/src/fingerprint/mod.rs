@@ -0,0 +1,62 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Fingerprint-related facilities.
+//!
+//! As the data model of oct is not stabilised, two builds of the same program may disagree on the encoding of the same type.
+//! To detect this, each type may be assigned a [fingerprint](trait@Fingerprint) that denotes its encoded structure.
+//! Peers can then compare fingerprints -- e.g. using a [`Handshake`] -- before exchanging any real data.
+
+use_mod!(pub fingerprint);
+use_mod!(pub fingerprint_hasher);
+use_mod!(pub handshake);
+
+/// Implements [`Fingerprint`](trait@Fingerprint) for the provided type.
+///
+/// The fingerprint is computed from the structure of the type as it is used by the [`Encode`](derive@crate::encode::Encode) and [`Decode`](derive@crate::decode::Decode) derive macros.
+/// This includes the fingerprint of each field type (in order of declaration) and -- for enumerations -- the representation and discriminant of each variant.
+///
+/// The names of the type itself and of its fields are **not** included, as these do not affect the encoding:
+///
+/// ```
+/// use oct::fingerprint::Fingerprint;
+///
+/// #[derive(Fingerprint)]
+/// struct Foo {
+///     bar: u16,
+///     baz: char,
+/// }
+///
+/// #[derive(Fingerprint)]
+/// struct Qux(u16, char);
+///
+/// #[derive(Fingerprint)]
+/// struct Quux(char, u16);
+///
+/// assert_eq!(Foo::FINGERPRINT, Qux::FINGERPRINT);
+/// assert_ne!(Foo::FINGERPRINT, Quux::FINGERPRINT);
+/// ```
+///
+/// Fields with the `since` attribute are included together with their versions, so each version of a versioned structure has its own fingerprint.
+#[cfg(feature = "proc-macro")]
+#[cfg_attr(doc, doc(cfg(feature = "proc-macro")))]
+#[doc(inline)]
+pub use oct_macros::Fingerprint;
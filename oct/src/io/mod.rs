@@ -0,0 +1,54 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Stream adapters for [`embedded-io`](embedded_io).
+//!
+//! The [`Writer`] and [`Reader`] types allow for encoding to an [`embedded_io::Write`] stream and decoding from an [`embedded_io::Read`] stream, respectively.
+//! Both types use a statically-sized buffer that is stored inline, meaning that neither of them requires an allocator.
+//!
+//! As streams do not preserve message boundaries, each value is prefixed with the length of its encoding (which is itself encoded as a [`usize`]).
+//!
+//! # Examples
+//!
+//! ```
+//! use oct::decode::Decode;
+//! use oct::encode::{Encode, SizedEncode};
+//! use oct::io::{Reader, Writer};
+//!
+//! #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+//! struct Reading {
+//!     channel: u8,
+//!     value:   f32,
+//! }
+//!
+//! const SIZE: usize = Reading::MAX_ENCODED_SIZE;
+//!
+//! let mut buf = [0x00; 0x10];
+//!
+//! let mut writer = Writer::<_, SIZE>::new(buf.as_mut_slice());
+//! writer.write(&Reading { channel: 0x3, value: 0.5 }).unwrap();
+//!
+//! let mut reader = Reader::<_, SIZE>::new(buf.as_slice());
+//! assert_eq!(reader.read::<Reading>().unwrap(), Reading { channel: 0x3, value: 0.5 });
+//! ```
+
+use_mod!(pub reader);
+use_mod!(pub writer);
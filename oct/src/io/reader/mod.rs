@@ -0,0 +1,102 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::decode::{Decode, Input};
+use crate::encode::SizedEncode;
+use crate::error::{LengthError, StreamDecodeError};
+
+use embedded_io::{Read, ReadExactError};
+
+/// Decoder for [`embedded_io::Read`] streams.
+///
+/// Values are expected to be prefixed with the length of their encoding, as is done by [`Writer`](crate::io::Writer).
+/// The encoding is read into an inline buffer of `N` bytes before it is decoded.
+/// It is therefore recommended to set `N` to the [`MAX_ENCODED_SIZE`](SizedEncode::MAX_ENCODED_SIZE) value of the largest type that is to be read.
+#[derive(Debug)]
+pub struct Reader<R, const N: usize> {
+	inner: R,
+	buf:   [u8; N],
+}
+
+impl<R: Read, const N: usize> Reader<R, N> {
+	/// Constructs a new reader.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(inner: R) -> Self {
+		Self { inner, buf: [0x00; N] }
+	}
+
+	/// Reads and decodes a value from the stream.
+	///
+	/// # Errors
+	///
+	/// If the stream could not be read from, if the encoding could not fit into the buffer, or if the value could not be decoded, then an error is returned.
+	///
+	/// In the case of the encoding not fitting, the stream is left directly after the length prefix.
+	#[inline]
+	pub fn read<T: Decode>(&mut self) -> Result<T, StreamDecodeError<T::Error, R::Error>> {
+		let mut len_buf = [0x00; usize::MAX_ENCODED_SIZE];
+		self.inner.read_exact(&mut len_buf).map_err(map_read_error)?;
+
+		let Ok(len) = usize::decode(&mut Input::new(&len_buf));
+
+		let Some(buf) = self.buf.get_mut(..len) else {
+			return Err(StreamDecodeError::BadLength(LengthError { capacity: N, len }));
+		};
+
+		self.inner.read_exact(buf).map_err(map_read_error)?;
+
+		T::decode(&mut Input::new(buf)).map_err(StreamDecodeError::BadValue)
+	}
+
+	/// Gets a reference to the underlying stream.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	/// Gets a mutable reference to the underlying stream.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get_mut(&mut self) -> &mut R {
+		&mut self.inner
+	}
+
+	/// Destructs the reader into its underlying stream.
+	#[inline(always)]
+	#[must_use]
+	pub fn into_inner(self) -> R {
+		self.inner
+	}
+}
+
+#[inline]
+fn map_read_error<E, I>(e: ReadExactError<I>) -> StreamDecodeError<E, I> {
+	match e {
+		ReadExactError::UnexpectedEof => StreamDecodeError::UnexpectedEnd,
+
+		ReadExactError::Other(e) => StreamDecodeError::Io(e),
+	}
+}
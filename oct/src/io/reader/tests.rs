@@ -0,0 +1,45 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::encode::SizedEncode;
+use oct::error::StreamDecodeError;
+use oct::io::Reader;
+
+#[test]
+fn test_reader() {
+	let buf = [0x04, 0x00, 0x04, 0x03, 0x02, 0x01, 0x01, 0x00, 0x05];
+
+	let mut reader = Reader::<_, { u32::MAX_ENCODED_SIZE }>::new(buf.as_slice());
+
+	assert_eq!(reader.read::<u32>().unwrap(), 0x01020304);
+	assert_eq!(reader.read::<u8>().unwrap(), 0x05);
+
+	assert!(matches!(reader.read::<u8>(), Err(StreamDecodeError::UnexpectedEnd)));
+}
+
+#[test]
+fn test_reader_overflow() {
+	let buf = [0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+	let mut reader = Reader::<_, { u32::MAX_ENCODED_SIZE }>::new(buf.as_slice());
+
+	assert!(matches!(reader.read::<u64>(), Err(StreamDecodeError::BadLength(..))));
+}
@@ -0,0 +1,100 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::encode::{Encode, Output, SizedEncode};
+use crate::error::StreamEncodeError;
+
+use embedded_io::Write;
+
+/// Encoder for [`embedded_io::Write`] streams.
+///
+/// Values are first encoded into an inline buffer of `N` bytes, after which the length of the encoding and the encoding itself are written to the stream.
+/// It is therefore recommended to set `N` to the [`MAX_ENCODED_SIZE`](SizedEncode::MAX_ENCODED_SIZE) value of the largest type that is to be written.
+///
+/// See [`Reader`](crate::io::Reader) for the decoding counterpart.
+#[derive(Debug)]
+pub struct Writer<W, const N: usize> {
+	inner: W,
+	buf:   [u8; N],
+}
+
+impl<W: Write, const N: usize> Writer<W, N> {
+	/// Constructs a new writer.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new(inner: W) -> Self {
+		Self { inner, buf: [0x00; N] }
+	}
+
+	/// Encodes and writes a value to the stream.
+	///
+	/// The stream is flushed after the value has been written.
+	///
+	/// It is a compile-time error to call this method with a type whose maximum encoded size exceeds `N`.
+	///
+	/// # Errors
+	///
+	/// If the value could not be encoded, or if the stream could not be written to, then an error is returned.
+	#[inline]
+	pub fn write<T: SizedEncode>(&mut self, value: &T) -> Result<(), StreamEncodeError<T::Error, W::Error>> {
+		const { assert!(T::MAX_ENCODED_SIZE <= N, "value cannot fit into writer buffer") };
+
+		let mut output = Output::new(&mut self.buf);
+		value.encode(&mut output).map_err(StreamEncodeError::BadValue)?;
+
+		let mut len_buf = [0x00; usize::MAX_ENCODED_SIZE];
+
+		output
+			.position()
+			.encode(&mut Output::new(&mut len_buf))
+			.map_err(StreamEncodeError::BadLength)?;
+
+		self.inner.write_all(&len_buf).map_err(StreamEncodeError::Io)?;
+		self.inner.write_all(output.as_slice()).map_err(StreamEncodeError::Io)?;
+		self.inner.flush().map_err(StreamEncodeError::Io)?;
+
+		Ok(())
+	}
+
+	/// Gets a reference to the underlying stream.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	/// Gets a mutable reference to the underlying stream.
+	#[inline(always)]
+	#[must_use]
+	pub const fn get_mut(&mut self) -> &mut W {
+		&mut self.inner
+	}
+
+	/// Destructs the writer into its underlying stream.
+	#[inline(always)]
+	#[must_use]
+	pub fn into_inner(self) -> W {
+		self.inner
+	}
+}
@@ -0,0 +1,47 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::encode::SizedEncode;
+use oct::error::StreamEncodeError;
+use oct::io::Writer;
+
+#[test]
+fn test_writer() {
+	let mut buf = [0x00; 0x9];
+
+	let mut writer = Writer::<_, { u32::MAX_ENCODED_SIZE }>::new(buf.as_mut_slice());
+
+	writer.write(&0x01020304u32).unwrap();
+	writer.write(&0x05u8).unwrap();
+
+	assert_eq!(writer.into_inner(), [].as_mut_slice());
+
+	assert_eq!(buf, [0x04, 0x00, 0x04, 0x03, 0x02, 0x01, 0x01, 0x00, 0x05]);
+}
+
+#[test]
+fn test_writer_full() {
+	let mut buf = [0x00; 0x4];
+
+	let mut writer = Writer::<_, { u32::MAX_ENCODED_SIZE }>::new(buf.as_mut_slice());
+
+	assert!(matches!(writer.write(&0x01020304u32), Err(StreamEncodeError::Io(..))));
+}
@@ -317,6 +317,11 @@
 //! * `alloc`: Enables the [`Slot`] type and implementations for e.g. [`Box`](alloc::boxed::Box) and [`Arc`](alloc::sync::Arc)
 //! * `proc-macro`: Pulls the procedural macros from the [`oct-macros`](https://crates.io/crates/oct-macros/) crate
 //! * `std`: Enables implementations for types such as [`Mutex`](std::sync::Mutex) and [`RwLock`](std::sync::RwLock)
+//! 
+//! The following features are optional:
+//! 
+//! * `bytes`: Enables the [`buf`](crate::buf) module and implementations for [`Bytes`](bytes::Bytes) and [`BytesMut`](bytes::BytesMut)
+//! * `embedded-io`: Enables the [`io`](crate::io) module for encoding to and decoding from [`embedded-io`](https://crates.io/crates/embedded-io/) streams
 //!
 //! # Documentation
 //!
@@ -367,8 +372,8 @@ macro_rules! use_mod {
 		$vis use $name::*;
 	};
 }
-pub(crate) use use_mod;
 
+use_mod!(pub checked);
 use_mod!(pub primitive_discriminant);
 use_mod!(pub sized_iter);
 use_mod!(pub sized_slice);
@@ -377,6 +382,18 @@ use_mod!(pub sized_str);
 #[cfg(feature = "alloc")]
 use_mod!(pub slot);
 
+pub mod checksum;
 pub mod decode;
 pub mod encode;
 pub mod error;
+pub mod fingerprint;
+pub mod message;
+pub mod repr;
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+pub mod buf;
+
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(doc, doc(cfg(feature = "embedded-io")))]
+pub mod io;
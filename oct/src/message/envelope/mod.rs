@@ -0,0 +1,147 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::decode::{Decode, Input};
+use crate::encode::{Encode, Output, SizedEncode};
+use crate::error::{MessageDecodeError, MessageEncodeError};
+use crate::fingerprint::{Fingerprint, FingerprintHasher};
+use crate::message::Message;
+
+/// Identified and length-prefixed message.
+///
+/// This type encodes the message identifier of `T` (see [`Message`]) as a [`u32`], followed by the length of the encoded message (as a [`usize`]), and finally the message itself.
+///
+/// The length prefix allows receivers that do not recognise the identifier to skip the message entirely.
+/// See [`Registry`](crate::message::Registry) for decoding envelopes of different types from the same stream.
+///
+/// # Examples
+///
+/// ```
+/// use oct::Slot;
+/// use oct::decode::Decode;
+/// use oct::encode::{Encode, SizedEncode};
+/// use oct::message::{Envelope, Message};
+///
+/// #[derive(Debug, Decode, Encode, Message, PartialEq, SizedEncode)]
+/// #[oct(message_id = 0x2)]
+/// struct Pong(u64);
+///
+/// let mut buf = Slot::<Envelope<Pong>>::new();
+///
+/// buf.write(Envelope(Pong(0x10))).unwrap();
+///
+/// assert_eq!(
+///     buf,
+///     [
+///         0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x10, 0x00,
+///         0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     ].as_slice(),
+/// );
+///
+/// assert_eq!(buf.read().unwrap(), Envelope(Pong(0x10)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Envelope<T>(
+	/// The contained message.
+	pub T,
+);
+
+impl<T> Envelope<T> {
+	/// Unwraps the contained message.
+	#[inline(always)]
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		self.0
+	}
+}
+
+impl<T: Decode + Message> Decode for Envelope<T> {
+	type Error = MessageDecodeError<T::Error>;
+
+	/// Decodes the envelope's header and message.
+	///
+	/// If the message decoder does not consume the entire payload, the remaining bytes are skipped.
+	#[inline]
+	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
+		let Ok(id) = u32::decode(input);
+
+		if id != T::MESSAGE_ID {
+			return Err(MessageDecodeError::UnassignedId { id });
+		}
+
+		let Ok(len) = usize::decode(input);
+
+		let mut payload = Input::new(input.read(len).unwrap());
+
+		let value = T::decode(&mut payload)
+			.map_err(|e| MessageDecodeError::BadMessage { id, error: e })?;
+
+		let this = Self(value);
+		Ok(this)
+	}
+}
+
+impl<T: Encode + Message> Encode for Envelope<T> {
+	type Error = MessageEncodeError<T::Error>;
+
+	#[inline]
+	fn encode(&self, output: &mut Output) -> Result<(), Self::Error> {
+		let Ok(_) = T::MESSAGE_ID.encode(output);
+
+		// Reserve the length prefix for now and write it
+		// once the length of the message is known.
+
+		let start = output.position();
+		output.write(&[0x00; usize::MAX_ENCODED_SIZE]).unwrap();
+
+		self.0
+			.encode(output)
+			.map_err(MessageEncodeError::BadMessage)?;
+
+		let len = output.position() - start - usize::MAX_ENCODED_SIZE;
+
+		let mut buf = [0x00; usize::MAX_ENCODED_SIZE];
+		let mut prefix = Output::new(&mut buf);
+
+		len
+			.encode(&mut prefix)
+			.map_err(MessageEncodeError::BadLength)?;
+
+		output.write_at(start, prefix.as_slice()).unwrap();
+
+		Ok(())
+	}
+}
+
+impl<T: Fingerprint + Message> Fingerprint for Envelope<T> {
+	const FINGERPRINT: u64 = FingerprintHasher::new()
+		.write(b"Envelope")
+		.write_u64(T::MESSAGE_ID as u64)
+		.write_u64(T::FINGERPRINT)
+		.finish();
+}
+
+impl<T: Message + SizedEncode> SizedEncode for Envelope<T> {
+	const MAX_ENCODED_SIZE: usize =
+		u32::MAX_ENCODED_SIZE
+		+ usize::MAX_ENCODED_SIZE
+		+ T::MAX_ENCODED_SIZE;
+}
@@ -0,0 +1,53 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+/// Denotes a type with a stable message identifier.
+///
+/// Message identifiers are used by [`Envelope`](crate::message::Envelope) and [`Registry`](crate::message::Registry) to distinguish between different message types on the same stream.
+/// Each identifier should therefore be unique within the set of messages that share a stream.
+///
+/// It is recommended to simply derive this trait using the `message_id` attribute.
+///
+/// # Examples
+///
+/// A manual implementation of `Message`:
+///
+/// ```
+/// use oct::message::Message;
+///
+/// struct Ping;
+///
+/// impl Message for Ping {
+///     const MESSAGE_ID: u32 = 0x2A;
+/// }
+/// ```
+pub trait Message {
+	/// The message identifier of the type.
+	const MESSAGE_ID: u32;
+}
+
+impl<T: Message> Message for &T {
+	const MESSAGE_ID: u32 = T::MESSAGE_ID;
+}
+
+impl<T: Message> Message for &mut T {
+	const MESSAGE_ID: u32 = T::MESSAGE_ID;
+}
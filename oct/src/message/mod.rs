@@ -0,0 +1,54 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Message-related facilities.
+//!
+//! A single connection often carries many unrelated message types.
+//! Instead of wrapping all of these in one, large enumeration, each type can be assigned a numerical identifier using the [`Message`](trait@Message) trait.
+//!
+//! Messages are encoded in [envelopes](Envelope), which prefix the encoded message with its identifier and length.
+//! On the receiving end, a [`Registry`] can then be used to dispatch each envelope to the correct type.
+
+use_mod!(pub envelope);
+use_mod!(pub message);
+
+#[cfg(feature = "alloc")]
+use_mod!(pub registry);
+
+/// Implements [`Message`](trait@Message) for the provided type.
+///
+/// The message identifier is set using the `message_id` attribute, which accepts any constant expression of the type [`u32`]:
+///
+/// ```
+/// use oct::message::Message;
+///
+/// #[derive(Message)]
+/// #[oct(message_id = 0x2A)]
+/// struct Ping;
+///
+/// assert_eq!(Ping::MESSAGE_ID, 0x2A);
+/// ```
+///
+/// Omitting this attribute is an error.
+#[cfg(feature = "proc-macro")]
+#[cfg_attr(doc, doc(cfg(feature = "proc-macro")))]
+#[doc(inline)]
+pub use oct_macros::Message;
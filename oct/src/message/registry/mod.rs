@@ -0,0 +1,293 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(test)]
+mod tests;
+
+use crate::decode::{Decode, Input};
+use crate::error::{GenericDecodeError, MessageDecodeError};
+use crate::message::Message;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::Any;
+use core::fmt::{self, Debug, Formatter};
+
+type DecodeFn = fn(&mut Input) -> Result<Box<dyn Any>, GenericDecodeError>;
+
+type HandlerFn = Box<dyn FnMut(&mut Input) -> Result<(), GenericDecodeError>>;
+
+/// A registered message type.
+enum Entry {
+	/// The message is decoded and returned to the caller.
+	Value(DecodeFn),
+
+	/// The message is decoded and passed to a handler.
+	Handler(HandlerFn),
+}
+
+/// The outcome of dispatching a message.
+///
+/// See [`Registry::decode`].
+#[must_use]
+pub enum Dispatch {
+	/// The message was passed to its registered handler.
+	Handled {
+		/// The identifier of the message.
+		id: u32,
+	},
+
+	/// The message was decoded without a handler.
+	///
+	/// The contained value may be downcast to the registered type.
+	Unhandled {
+		/// The identifier of the message.
+		id: u32,
+
+		/// The decoded message.
+		value: Box<dyn Any>,
+	},
+
+	/// The message identifier was unknown and the message was skipped.
+	///
+	/// This only happens if the registry has been configured to [skip unknown messages](Registry::skip_unknown).
+	Skipped {
+		/// The identifier of the message.
+		id: u32,
+	},
+}
+
+impl Debug for Dispatch {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::Handled { id }
+			=> f.debug_struct("Handled").field("id", &id).finish(),
+
+			Self::Unhandled { id, .. }
+			=> f.debug_struct("Unhandled").field("id", &id).finish_non_exhaustive(),
+
+			Self::Skipped { id }
+			=> f.debug_struct("Skipped").field("id", &id).finish(),
+		}
+	}
+}
+
+/// Dynamic dispatcher of messages.
+///
+/// This type maps the identifiers of [messages](Message) to their decoders, thereby allowing many unrelated types to share the same stream without being wrapped in one enumeration.
+/// The expected format is that of [`Envelope`](crate::message::Envelope).
+///
+/// Each type may either be registered without a handler -- in which case decoded messages are returned as [`Box<dyn Any>`](Any) -- or with a handler that is called with the decoded message.
+///
+/// # Examples
+///
+/// ```
+/// use oct::Slot;
+/// use oct::decode::{Decode, Input};
+/// use oct::encode::{Encode, SizedEncode};
+/// use oct::message::{Dispatch, Envelope, Message, Registry};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// #[derive(Debug, Decode, Encode, Message, PartialEq, SizedEncode)]
+/// #[oct(message_id = 0x1)]
+/// struct Ping(u64);
+///
+/// #[derive(Debug, Decode, Encode, Message, PartialEq, SizedEncode)]
+/// #[oct(message_id = 0x2)]
+/// struct Pong(u64);
+///
+/// let pings = Rc::new(Cell::new(0x0));
+///
+/// let mut registry = Registry::new();
+///
+/// registry.register_handler::<Ping, _>({
+///     let pings = pings.clone();
+///
+///     move |Ping(_)| pings.set(pings.get() + 0x1)
+/// });
+///
+/// registry.register::<Pong>();
+///
+/// let mut buf = Slot::<Envelope<Ping>>::new();
+/// buf.write(Envelope(Ping(0x45))).unwrap();
+///
+/// registry.decode(&mut Input::new(&buf)).unwrap();
+/// assert_eq!(pings.get(), 0x1);
+///
+/// let mut buf = Slot::<Envelope<Pong>>::new();
+/// buf.write(Envelope(Pong(0x45))).unwrap();
+///
+/// let Dispatch::Unhandled { value, .. } = registry.decode(&mut Input::new(&buf)).unwrap() else {
+///     unreachable!();
+/// };
+///
+/// assert_eq!(*value.downcast::<Pong>().unwrap(), Pong(0x45));
+/// ```
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+#[derive(Default)]
+pub struct Registry {
+	entries: BTreeMap<u32, Entry>,
+
+	skip_unknown: bool,
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl Registry {
+	/// Constructs a new, empty registry.
+	#[inline(always)]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			entries: BTreeMap::new(),
+
+			skip_unknown: false,
+		}
+	}
+
+	/// Registers a message type without a handler.
+	///
+	/// Messages of this type are returned by [`decode`](Self::decode) as [`Dispatch::Unhandled`].
+	///
+	/// # Panics
+	///
+	/// If a message type with the same identifier has already been registered, then this method will panic.
+	#[inline]
+	pub fn register<T>(&mut self) -> &mut Self
+	where
+		T: Decode<Error: Into<GenericDecodeError>> + Message + 'static,
+	{
+		let decode: DecodeFn = |input| {
+			let value = T::decode(input).map_err(Into::into)?;
+			Ok(Box::new(value))
+		};
+
+		self.insert(T::MESSAGE_ID, Entry::Value(decode))
+	}
+
+	/// Registers a message type with a handler.
+	///
+	/// Messages of this type are decoded and passed to `handler` by [`decode`](Self::decode).
+	///
+	/// # Panics
+	///
+	/// If a message type with the same identifier has already been registered, then this method will panic.
+	#[inline]
+	pub fn register_handler<T, F>(&mut self, mut handler: F) -> &mut Self
+	where
+		T: Decode<Error: Into<GenericDecodeError>> + Message + 'static,
+		F: FnMut(T) + 'static,
+	{
+		let handler: HandlerFn = Box::new(move |input| {
+			let value = T::decode(input).map_err(Into::into)?;
+
+			handler(value);
+			Ok(())
+		});
+
+		self.insert(T::MESSAGE_ID, Entry::Handler(handler))
+	}
+
+	/// Sets whether messages with unknown identifiers should be skipped.
+	///
+	/// By default, unknown identifiers yield an error.
+	/// If skipping is enabled, the length prefix of unknown messages is instead used to skip their payload, and [`decode`](Self::decode) returns [`Dispatch::Skipped`].
+	#[inline(always)]
+	pub fn skip_unknown(&mut self, skip: bool) -> &mut Self {
+		self.skip_unknown = skip;
+		self
+	}
+
+	/// Tests if a message identifier has been registered.
+	#[inline(always)]
+	#[must_use]
+	pub fn contains(&self, id: u32) -> bool {
+		self.entries.contains_key(&id)
+	}
+
+	/// Decodes and dispatches a single message.
+	///
+	/// The message is read in the [`Envelope`](crate::message::Envelope) format.
+	/// Any bytes of the payload not consumed by the message's decoder are skipped.
+	///
+	/// # Errors
+	///
+	/// If the message identifier has not been registered (and unknown messages are not skipped), then [`UnassignedId`](MessageDecodeError::UnassignedId) is returned.
+	/// If the message itself could not be decoded, then [`BadMessage`](MessageDecodeError::BadMessage) is returned.
+	#[inline]
+	pub fn decode(&mut self, input: &mut Input) -> Result<Dispatch, MessageDecodeError<GenericDecodeError>> {
+		let Ok(id) = u32::decode(input);
+
+		let Some(entry) = self.entries.get_mut(&id) else {
+			if !self.skip_unknown {
+				return Err(MessageDecodeError::UnassignedId { id });
+			}
+
+			let Ok(len) = usize::decode(input);
+			let _ = input.read(len).unwrap();
+
+			return Ok(Dispatch::Skipped { id });
+		};
+
+		let Ok(len) = usize::decode(input);
+
+		let mut payload = Input::new(input.read(len).unwrap());
+
+		let dispatch = match *entry {
+			Entry::Value(decode) => {
+				let value = decode(&mut payload)
+					.map_err(|e| MessageDecodeError::BadMessage { id, error: e })?;
+
+				Dispatch::Unhandled { id, value }
+			}
+
+			Entry::Handler(ref mut handler) => {
+				handler(&mut payload)
+					.map_err(|e| MessageDecodeError::BadMessage { id, error: e })?;
+
+				Dispatch::Handled { id }
+			}
+		};
+
+		Ok(dispatch)
+	}
+
+	#[inline]
+	fn insert(&mut self, id: u32, entry: Entry) -> &mut Self {
+		let prev = self.entries.insert(id, entry);
+		assert!(prev.is_none(), "message identifier `{id:#X}` has already been registered");
+
+		self
+	}
+}
+
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl Debug for Registry {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f
+			.debug_struct("Registry")
+			.field("ids", &self.entries.keys())
+			.field("skip_unknown", &self.skip_unknown)
+			.finish()
+	}
+}
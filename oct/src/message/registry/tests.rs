@@ -0,0 +1,118 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::Slot;
+use oct::decode::{Decode, Input};
+use oct::encode::{Encode, SizedEncode};
+use oct::error::MessageDecodeError;
+use oct::message::{Dispatch, Envelope, Message, Registry};
+use std::vec::Vec;
+
+#[derive(Debug, Decode, Encode, Message, PartialEq, SizedEncode)]
+#[oct(message_id = 0x1)]
+struct Join {
+	user_id: u32,
+}
+
+#[derive(Debug, Decode, Encode, Message, PartialEq, SizedEncode)]
+#[oct(message_id = 0x2)]
+struct Quit {
+	user_id: u32,
+	reason:  u8,
+}
+
+#[derive(Debug, Decode, Encode, Message, PartialEq, SizedEncode)]
+#[oct(message_id = 0x3)]
+struct Kick;
+
+#[test]
+fn test_registry_dispatch() {
+	let mut data = Vec::new();
+
+	let mut buf = Slot::<Envelope<Join>>::new();
+	buf.write(Envelope(Join { user_id: 0x45 })).unwrap();
+	data.extend_from_slice(&buf);
+
+	let mut buf = Slot::<Envelope<Quit>>::new();
+	buf.write(Envelope(Quit { user_id: 0x45, reason: 0x2 })).unwrap();
+	data.extend_from_slice(&buf);
+
+	let mut registry = Registry::new();
+
+	registry
+		.register::<Join>()
+		.register::<Quit>();
+
+	let mut stream = Input::new(&data);
+
+	let Dispatch::Unhandled { id, value } = registry.decode(&mut stream).unwrap() else { panic!() };
+	assert_eq!(id, Join::MESSAGE_ID);
+	assert_eq!(*value.downcast::<Join>().unwrap(), Join { user_id: 0x45 });
+
+	let Dispatch::Unhandled { id, value } = registry.decode(&mut stream).unwrap() else { panic!() };
+	assert_eq!(id, Quit::MESSAGE_ID);
+	assert_eq!(*value.downcast::<Quit>().unwrap(), Quit { user_id: 0x45, reason: 0x2 });
+
+	assert_eq!(stream.remaining(), 0x0);
+}
+
+#[test]
+fn test_registry_unknown() {
+	let mut data = Vec::new();
+
+	let mut buf = Slot::<Envelope<Kick>>::new();
+	buf.write(Envelope(Kick)).unwrap();
+	data.extend_from_slice(&buf);
+
+	let mut buf = Slot::<Envelope<Join>>::new();
+	buf.write(Envelope(Join { user_id: 0x1 })).unwrap();
+	data.extend_from_slice(&buf);
+
+	let mut registry = Registry::new();
+	registry.register::<Join>();
+
+	let mut stream = Input::new(&data);
+
+	assert!(matches!(
+		registry.decode(&mut stream),
+		Err(MessageDecodeError::UnassignedId { id: 0x3 }),
+	));
+
+	registry.skip_unknown(true);
+
+	let mut stream = Input::new(&data);
+
+	assert!(matches!(registry.decode(&mut stream), Ok(Dispatch::Skipped { id: 0x3 })));
+	assert!(matches!(registry.decode(&mut stream), Ok(Dispatch::Unhandled { id: 0x1, .. })));
+}
+
+#[test]
+fn test_envelope_mismatch() {
+	let mut buf = Slot::<Envelope<Join>>::new();
+	buf.write(Envelope(Join { user_id: 0x1 })).unwrap();
+
+	let mut stream = Input::new(&buf);
+
+	assert!(matches!(
+		Envelope::<Quit>::decode(&mut stream),
+		Err(MessageDecodeError::UnassignedId { id: 0x1 }),
+	));
+}
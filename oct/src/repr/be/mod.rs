@@ -0,0 +1,55 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! The big-endian representation.
+//!
+//! This is the representation used by the `be` field attribute.
+//! Note that the default representation of numeric types is little-endian.
+
+use crate::decode::Input;
+use crate::encode::Output;
+use crate::repr::Endian;
+
+use core::convert::Infallible;
+
+/// Encodes a value in big-endian byte order.
+///
+/// # Errors
+///
+/// This function cannot fail.
+#[allow(clippy::unnecessary_wraps)]
+#[inline(always)]
+pub fn encode<T: Endian>(value: &T, output: &mut Output) -> Result<(), Infallible> {
+	value.encode_be(output);
+
+	Ok(())
+}
+
+/// Decodes a value in big-endian byte order.
+///
+/// # Errors
+///
+/// This function cannot fail.
+#[allow(clippy::unnecessary_wraps)]
+#[inline(always)]
+pub fn decode<T: Endian>(input: &mut Input) -> Result<T, Infallible> {
+	Ok(T::decode_be(input))
+}
@@ -0,0 +1,162 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+/// Denotes a type that can be stored in a bit field.
+///
+/// This trait is used by the `bits` field attribute of the derive macros (see [`Encode`](derive@crate::encode::Encode)), which packs consecutive fields into as few bytes as possible.
+/// It is implemented for [`bool`] and all fixed-size integers; signed integers are stored in two's complement.
+///
+/// Other small types, e.g. fieldless enumerations, may implement this trait manually:
+///
+/// ```
+/// use oct::repr::BitField;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(u8)]
+/// enum Mode {
+///     Idle    = 0x0,
+///     Running = 0x1,
+///     Stopped = 0x2,
+/// }
+///
+/// impl BitField for Mode {
+///     fn to_bits(&self, width: u32) -> Option<u128> {
+///         (*self as u8).to_bits(width)
+///     }
+///
+///     fn from_bits(bits: u128, width: u32) -> Option<Self> {
+///         match u8::from_bits(bits, width)? {
+///             0x0 => Some(Self::Idle),
+///             0x1 => Some(Self::Running),
+///             0x2 => Some(Self::Stopped),
+///             _   => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Mode::Stopped.to_bits(0x2), Some(0b10));
+/// assert_eq!(Mode::from_bits(0b11, 0x2), None);
+/// ```
+pub trait BitField: Sized {
+	/// Converts the value into `width` bits.
+	///
+	/// If the value cannot be represented in `width` bits, then [`None`] is returned.
+	/// Otherwise, no bits at or above `width` may be set.
+	#[must_use]
+	fn to_bits(&self, width: u32) -> Option<u128>;
+
+	/// Converts `width` bits into a value.
+	///
+	/// If the bits do not denote a valid value, then [`None`] is returned.
+	#[must_use]
+	fn from_bits(bits: u128, width: u32) -> Option<Self>;
+}
+
+/// Retrieves the mask of the lower `width` bits.
+#[inline(always)]
+#[must_use]
+const fn mask(width: u32) -> u128 {
+	if width >= u128::BITS {
+		u128::MAX
+	} else {
+		(0x1 << width) - 0x1
+	}
+}
+
+impl BitField for bool {
+	#[inline]
+	fn to_bits(&self, width: u32) -> Option<u128> {
+		(width > 0x0 || !*self).then_some(u128::from(*self))
+	}
+
+	#[inline]
+	fn from_bits(bits: u128, _width: u32) -> Option<Self> {
+		match bits {
+			0x0 => Some(false),
+			0x1 => Some(true),
+			_   => None,
+		}
+	}
+}
+
+macro_rules! impl_unsigned {
+	($ty:ty$(,)?) => {
+		impl ::oct::repr::BitField for $ty {
+			#[inline]
+			fn to_bits(&self, width: u32) -> ::core::option::Option<u128> {
+				let bits = u128::from(*self);
+
+				(bits & !mask(width) == 0x0).then_some(bits)
+			}
+
+			#[inline]
+			fn from_bits(bits: u128, _width: u32) -> ::core::option::Option<Self> {
+				Self::try_from(bits).ok()
+			}
+		}
+	};
+}
+
+macro_rules! impl_signed {
+	($ty:ty$(,)?) => {
+		impl ::oct::repr::BitField for $ty {
+			#[allow(clippy::cast_sign_loss)]
+			#[inline]
+			fn to_bits(&self, width: u32) -> ::core::option::Option<u128> {
+				if width == 0x0 {
+					return (*self == 0x0).then_some(0x0);
+				}
+
+				// The value must survive truncation to the
+				// given width and subsequent sign extension.
+				let value = i128::from(*self);
+				let shift = u128::BITS - width.min(u128::BITS);
+
+				((value << shift) >> shift == value).then_some(value as u128 & mask(width))
+			}
+
+			#[allow(clippy::cast_possible_wrap)]
+			#[inline]
+			fn from_bits(bits: u128, width: u32) -> ::core::option::Option<Self> {
+				if width == 0x0 {
+					return Some(0x0);
+				}
+
+				let shift = u128::BITS - width.min(u128::BITS);
+				let value = ((bits as i128) << shift) >> shift;
+
+				Self::try_from(value).ok()
+			}
+		}
+	};
+}
+
+impl_unsigned!(u8);
+impl_unsigned!(u16);
+impl_unsigned!(u32);
+impl_unsigned!(u64);
+impl_unsigned!(u128);
+
+impl_signed!(i8);
+impl_signed!(i16);
+impl_signed!(i32);
+impl_signed!(i64);
+impl_signed!(i128);
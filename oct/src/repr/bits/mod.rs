@@ -0,0 +1,75 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! The bit-field representation.
+//!
+//! This is the representation used by the `bits` field attribute.
+//! Consecutive bit fields share a buffer of bytes, into which each field is packed least-significant bit first at its offset.
+
+use crate::error::BitFieldError;
+use crate::repr::BitField;
+
+/// Packs a value into the given bits of a buffer.
+///
+/// The value occupies `width` bits, starting at bit `offset` of `buf`.
+/// Bits are numbered from the least significant bit of the first byte.
+///
+/// # Errors
+///
+/// If the value cannot be represented in `width` bits, then an error is returned.
+///
+/// # Panics
+///
+/// If the bits are out of bounds of `buf`, then this function will panic.
+#[inline]
+pub fn pack<T: BitField>(value: &T, buf: &mut [u8], offset: usize, width: u32) -> Result<(), BitFieldError> {
+	let bits = value.to_bits(width).ok_or(BitFieldError { width })?;
+
+	for (index, pos) in (offset..).take(width as usize).enumerate() {
+		if bits >> index & 0x1 != 0x0 {
+			buf[pos / 0x8] |= 0x1 << (pos % 0x8);
+		}
+	}
+
+	Ok(())
+}
+
+/// Unpacks a value from the given bits of a buffer.
+///
+/// See [`pack`] for the layout of the bits.
+///
+/// # Errors
+///
+/// If the bits do not denote a valid value, then an error is returned.
+///
+/// # Panics
+///
+/// If the bits are out of bounds of `buf`, then this function will panic.
+#[inline]
+pub fn unpack<T: BitField>(buf: &[u8], offset: usize, width: u32) -> Result<T, BitFieldError> {
+	let mut bits = 0x0u128;
+
+	for (index, pos) in (offset..).take(width as usize).enumerate() {
+		bits |= u128::from(buf[pos / 0x8] >> (pos % 0x8) & 0x1) << index;
+	}
+
+	T::from_bits(bits, width).ok_or(BitFieldError { width })
+}
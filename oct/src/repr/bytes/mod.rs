@@ -0,0 +1,104 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! The byte-width representation.
+//!
+//! This is the representation used by the `bytes` field attribute.
+//! Integers are stored in exactly `N` bytes (at most sixteen), which allows for widths that do not correspond to any primitive type (such as 24-bit integers).
+//! Values are narrowed with a check, and signed values are sign-extended when widened again.
+//!
+//! By default, the bytes are in little-endian byte order, as is the case for the default representation of numeric types.
+//! The [`encode_be`] and [`decode_be`] functions instead use big-endian byte order.
+
+use crate::decode::Input;
+use crate::encode::Output;
+use crate::error::BitFieldError;
+use crate::repr::BitField;
+
+/// Retrieves the width of `N` bytes in bits.
+#[allow(clippy::cast_possible_truncation)]
+#[inline(always)]
+#[must_use]
+const fn width<const N: usize>() -> u32 {
+	const { assert!(N > 0x0 && N <= 0x10, "byte widths must be between 1 and 16 bytes") };
+
+	(N * 0x8) as u32
+}
+
+/// Encodes a value in `N` bytes in little-endian byte order.
+///
+/// # Errors
+///
+/// If the value cannot be represented in `N` bytes, then an error is returned.
+#[inline]
+pub fn encode<T: BitField, const N: usize>(value: &T, output: &mut Output) -> Result<(), BitFieldError> {
+	let width = width::<N>();
+	let bits  = value.to_bits(width).ok_or(BitFieldError { width })?;
+
+	output.write(&bits.to_le_bytes()[..N]).unwrap();
+
+	Ok(())
+}
+
+/// Decodes a value from `N` bytes in little-endian byte order.
+///
+/// # Errors
+///
+/// If the bytes do not denote a valid value, then an error is returned.
+#[inline]
+pub fn decode<T: BitField, const N: usize>(input: &mut Input) -> Result<T, BitFieldError> {
+	let width = width::<N>();
+
+	let mut data = [0x00; 0x10];
+	input.read_into(&mut data[..N]).unwrap();
+
+	T::from_bits(u128::from_le_bytes(data), width).ok_or(BitFieldError { width })
+}
+
+/// Encodes a value in `N` bytes in big-endian byte order.
+///
+/// # Errors
+///
+/// If the value cannot be represented in `N` bytes, then an error is returned.
+#[inline]
+pub fn encode_be<T: BitField, const N: usize>(value: &T, output: &mut Output) -> Result<(), BitFieldError> {
+	let width = width::<N>();
+	let bits  = value.to_bits(width).ok_or(BitFieldError { width })?;
+
+	output.write(&bits.to_be_bytes()[0x10 - N..]).unwrap();
+
+	Ok(())
+}
+
+/// Decodes a value from `N` bytes in big-endian byte order.
+///
+/// # Errors
+///
+/// If the bytes do not denote a valid value, then an error is returned.
+#[inline]
+pub fn decode_be<T: BitField, const N: usize>(input: &mut Input) -> Result<T, BitFieldError> {
+	let width = width::<N>();
+
+	let mut data = [0x00; 0x10];
+	input.read_into(&mut data[0x10 - N..]).unwrap();
+
+	T::from_bits(u128::from_be_bytes(data), width).ok_or(BitFieldError { width })
+}
@@ -0,0 +1,67 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! The cast representation.
+//!
+//! This is the representation used by the `as` field attribute, which encodes a value as another type (e.g. a [`u32`] as a [`u16`]).
+//! Conversions are checked in both directions using [`TryFrom`].
+//!
+//! As the target type cannot be inferred, it must be specified explicitly when using these functions directly, e.g. as `cast::encode::<u16, _>`.
+
+use crate::decode::{Decode, Input};
+use crate::encode::{Encode, Output};
+use crate::error::{CastError, GenericDecodeError, GenericEncodeError};
+
+use core::any::type_name;
+
+/// Encodes a value as the type `U`.
+///
+/// # Errors
+///
+/// If the value cannot be represented as `U`, then [`CastError`] is returned.
+/// Any error from encoding `U` is also returned.
+#[inline]
+pub fn encode<U, T>(value: &T, output: &mut Output) -> Result<(), GenericEncodeError>
+where
+	U: TryFrom<T> + Encode<Error: Into<GenericEncodeError>>,
+	T: Clone,
+{
+	let value = U::try_from(value.clone()).map_err(|_e| CastError { target: type_name::<U>() })?;
+
+	value.encode(output).map_err(Into::into)
+}
+
+/// Decodes a value from the type `U`.
+///
+/// # Errors
+///
+/// If the decoded value cannot be represented as `T`, then [`CastError`] is returned.
+/// Any error from decoding `U` is also returned.
+#[inline]
+pub fn decode<U, T>(input: &mut Input) -> Result<T, GenericDecodeError>
+where
+	U: Decode<Error: Into<GenericDecodeError>>,
+	T: TryFrom<U>,
+{
+	let value = U::decode(input).map_err(Into::into)?;
+
+	T::try_from(value).map_err(|_e| CastError { target: type_name::<T>() }.into())
+}
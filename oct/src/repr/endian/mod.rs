@@ -0,0 +1,99 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use crate::encode::SizedEncode;
+
+mod sealed {
+	use crate::decode::Input;
+	use crate::encode::Output;
+
+	/// Denotes a primitive, numeric type with a byte order.
+	///
+	/// See the public [`Endian`](crate::repr::Endian) trait for more information.
+	pub trait Endian: Sized {
+		/// Writes the value in big-endian byte order.
+		fn encode_be(self, output: &mut Output);
+
+		/// Writes the value in little-endian byte order.
+		fn encode_le(self, output: &mut Output);
+
+		/// Reads a value in big-endian byte order.
+		fn decode_be(input: &mut Input) -> Self;
+
+		/// Reads a value in little-endian byte order.
+		fn decode_le(input: &mut Input) -> Self;
+	}
+}
+
+pub(crate) use sealed::Endian as SealedEndian;
+
+/// Denotes a primitive, numeric type with a byte order.
+///
+/// This trait is implemented for all fixed-size integers as well as for [`f32`] and [`f64`].
+/// It is used by the [`be`](crate::repr::be) and [`le`](crate::repr::le) representations.
+pub trait Endian: Copy + SealedEndian + SizedEncode { }
+
+macro_rules! impl_endian {
+	($ty:ty$(,)?) => {
+		impl ::oct::repr::SealedEndian for $ty {
+			#[inline]
+			fn encode_be(self, output: &mut ::oct::encode::Output) {
+				output.write(&self.to_be_bytes()).unwrap();
+			}
+
+			#[inline]
+			fn encode_le(self, output: &mut ::oct::encode::Output) {
+				output.write(&self.to_le_bytes()).unwrap();
+			}
+
+			#[inline]
+			fn decode_be(input: &mut ::oct::decode::Input) -> Self {
+				let mut data = [0x00; <Self as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE];
+				input.read_into(&mut data).unwrap();
+
+				Self::from_be_bytes(data)
+			}
+
+			#[inline]
+			fn decode_le(input: &mut ::oct::decode::Input) -> Self {
+				let mut data = [0x00; <Self as ::oct::encode::SizedEncode>::MAX_ENCODED_SIZE];
+				input.read_into(&mut data).unwrap();
+
+				Self::from_le_bytes(data)
+			}
+		}
+
+		impl ::oct::repr::Endian for $ty { }
+	};
+}
+
+impl_endian!(f32);
+impl_endian!(f64);
+impl_endian!(i128);
+impl_endian!(i16);
+impl_endian!(i32);
+impl_endian!(i64);
+impl_endian!(i8);
+impl_endian!(u128);
+impl_endian!(u16);
+impl_endian!(u32);
+impl_endian!(u64);
+impl_endian!(u8);
@@ -0,0 +1,76 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Alternative representations of fields.
+//!
+//! Each submodule provides an `encode` and a `decode` function for use with the `with` field attribute of the derive macros (see [`Encode`](derive@crate::encode::Encode)).
+//! These are also used by the `be`, `le`, `varint`, `as`, and `bytes` field attributes, which override the representation of a single field without any wrapper types:
+//!
+//! ```
+//! use oct::decode::{Decode, Input};
+//! use oct::encode::{Encode, Output, SizedEncode};
+//!
+//! #[derive(Debug, Decode, Encode, PartialEq, SizedEncode)]
+//! struct Header {
+//!     #[oct(be)]
+//!     magic: u32,
+//!
+//!     #[oct(with = "oct::repr::le", max_size = u16::MAX_ENCODED_SIZE)]
+//!     id: u16,
+//!
+//!     #[oct(varint)]
+//!     len: u64,
+//!
+//!     #[oct(as = "u8")]
+//!     kind: u32,
+//! }
+//!
+//! assert_eq!(Header::MAX_ENCODED_SIZE, 0x11);
+//!
+//! let header = Header { magic: 0xCAFEBABE, id: 0x2A, len: 0x12C, kind: 0x3 };
+//!
+//! let mut buf = [0x00; 0x11];
+//!
+//! let mut output = Output::new(&mut buf);
+//! header.encode(&mut output).unwrap();
+//!
+//! let len = output.position();
+//! assert_eq!(buf[..len], [0xCA, 0xFE, 0xBA, 0xBE, 0x2A, 0x00, 0xAC, 0x02, 0x03]);
+//!
+//! assert_eq!(Header::decode(&mut Input::new(&buf[..len])).unwrap(), header);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use_mod!(pub bit_field);
+use_mod!(pub endian);
+
+pub mod be;
+pub mod bits;
+pub mod bytes;
+pub mod cast;
+pub mod le;
+pub mod varint;
+
+pub use varint::Varint;
+
+pub(crate) use varint::SealedVarint;
@@ -0,0 +1,147 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+use oct::decode::Input;
+use oct::encode::Output;
+use oct::error::{BitFieldError, CastError, GenericEncodeError, VarintDecodeError};
+use oct::repr::{BitField, be, bits, bytes, cast, le, varint};
+
+#[test]
+fn test_endian() {
+	let mut buf = [0x00; 0x4];
+
+	be::encode(&0x12345678u32, &mut Output::new(&mut buf)).unwrap();
+	assert_eq!(buf, [0x12, 0x34, 0x56, 0x78]);
+	assert_eq!(be::decode::<u32>(&mut Input::new(&buf)), Ok(0x12345678));
+
+	le::encode(&0x12345678u32, &mut Output::new(&mut buf)).unwrap();
+	assert_eq!(buf, [0x78, 0x56, 0x34, 0x12]);
+	assert_eq!(le::decode::<u32>(&mut Input::new(&buf)), Ok(0x12345678));
+}
+
+#[test]
+fn test_varint() {
+	macro_rules! test {
+		($ty:ty: $value:expr => $data:expr) => {{
+			let data: &[u8] = &$data;
+
+			let mut buf = [0x00; 0x13];
+
+			let mut output = Output::new(&mut buf);
+			varint::encode(&$value, &mut output).unwrap();
+
+			let len = output.position();
+			assert_eq!(&buf[..len], data);
+
+			assert_eq!(varint::decode::<$ty>(&mut Input::new(data)), Ok($value));
+		}};
+	}
+
+	test!(u8: 0x0u8 => [0x00]);
+	test!(u8: 0xFFu8 => [0xFF, 0x01]);
+	test!(u16: 0x12Cu16 => [0xAC, 0x02]);
+	test!(u64: u64::MAX => [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+	test!(i32: 0x0i32 => [0x00]);
+	test!(i32: -0x1i32 => [0x01]);
+	test!(i32: 0x1i32 => [0x02]);
+	test!(i16: i16::MIN => [0xFF, 0xFF, 0x03]);
+	test!(usize: 0x12Cusize => [0xAC, 0x02]);
+	test!(isize: -0x1isize => [0x01]);
+	test!(isize: 0x40isize => [0x80, 0x01]);
+
+	assert_eq!(varint::decode::<u8>(&mut Input::new(&[0x80, 0x02])), Err(VarintDecodeError { target: "u8" }));
+	assert_eq!(varint::decode::<u16>(&mut Input::new(&[0x80, 0x80, 0x80, 0x01])), Err(VarintDecodeError { target: "u16" }));
+
+	let data = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+	assert_eq!(varint::decode::<usize>(&mut Input::new(&data)), Err(VarintDecodeError { target: "usize" }));
+	assert_eq!(varint::decode::<isize>(&mut Input::new(&data)), Err(VarintDecodeError { target: "isize" }));
+
+	let mut data = [0xFF; 0x13];
+
+	data[0x12] = 0x03;
+	assert_eq!(varint::decode::<u128>(&mut Input::new(&data)), Ok(u128::MAX));
+
+	data[0x12] = 0x04;
+	assert_eq!(varint::decode::<u128>(&mut Input::new(&data)), Err(VarintDecodeError { target: "u128" }));
+	assert_eq!(varint::decode::<i128>(&mut Input::new(&data)), Err(VarintDecodeError { target: "i128" }));
+}
+
+#[test]
+fn test_bit_field() {
+	assert_eq!(true.to_bits(0x1), Some(0b1));
+	assert_eq!(bool::from_bits(0b10, 0x2), None);
+
+	assert_eq!(0x7u8.to_bits(0x3), Some(0b111));
+	assert_eq!(0x8u8.to_bits(0x3), None);
+	assert_eq!(u8::from_bits(0x1FF, 0x9), None);
+
+	assert_eq!((-0x1i8).to_bits(0x3), Some(0b111));
+	assert_eq!((-0x4i8).to_bits(0x3), Some(0b100));
+	assert_eq!((-0x5i8).to_bits(0x3), None);
+	assert_eq!(0x4i8.to_bits(0x3), None);
+	assert_eq!(i8::from_bits(0b101, 0x3), Some(-0x3));
+	assert_eq!(i128::from_bits(u128::MAX, 0x80), Some(-0x1));
+}
+
+#[test]
+fn test_bits() {
+	let mut buf = [0x00; 0x2];
+
+	bits::pack(&true, &mut buf, 0x0, 0x1).unwrap();
+	bits::pack(&0x5u8, &mut buf, 0x1, 0x3).unwrap();
+	bits::pack(&0x3Fu16, &mut buf, 0x4, 0x6).unwrap();
+	assert_eq!(buf, [0b1111_1011, 0b0000_0011]);
+
+	assert_eq!(bits::unpack::<bool>(&buf, 0x0, 0x1), Ok(true));
+	assert_eq!(bits::unpack::<u8>(&buf, 0x1, 0x3), Ok(0x5));
+	assert_eq!(bits::unpack::<u16>(&buf, 0x4, 0x6), Ok(0x3F));
+
+	assert_eq!(bits::pack(&0x8u8, &mut buf, 0x0, 0x3), Err(BitFieldError { width: 0x3 }));
+	assert_eq!(bits::unpack::<bool>(&buf, 0x0, 0x2), Err(BitFieldError { width: 0x2 }));
+}
+
+#[test]
+fn test_bytes() {
+	let mut buf = [0x00; 0x3];
+
+	bytes::encode::<_, 0x3>(&0x123456u32, &mut Output::new(&mut buf)).unwrap();
+	assert_eq!(buf, [0x56, 0x34, 0x12]);
+	assert_eq!(bytes::decode::<u32, 0x3>(&mut Input::new(&buf)), Ok(0x123456));
+
+	bytes::encode_be::<_, 0x3>(&-0x2i32, &mut Output::new(&mut buf)).unwrap();
+	assert_eq!(buf, [0xFF, 0xFF, 0xFE]);
+	assert_eq!(bytes::decode_be::<i32, 0x3>(&mut Input::new(&buf)), Ok(-0x2));
+
+	assert_eq!(bytes::encode::<_, 0x3>(&0x1000000u32, &mut Output::new(&mut buf)), Err(BitFieldError { width: 0x18 }));
+	assert_eq!(bytes::decode::<u16, 0x3>(&mut Input::new(&[0x00, 0x00, 0x01])), Err(BitFieldError { width: 0x18 }));
+}
+
+#[test]
+fn test_cast() {
+	let mut buf = [0x00; 0x2];
+
+	cast::encode::<u16, _>(&0x1234u32, &mut Output::new(&mut buf)).unwrap();
+	assert_eq!(buf, [0x34, 0x12]);
+	assert_eq!(cast::decode::<u16, u32>(&mut Input::new(&buf)).unwrap(), 0x1234);
+
+	let error = cast::encode::<u16, _>(&0x10000u32, &mut Output::new(&mut buf)).unwrap_err();
+	assert!(matches!(error, GenericEncodeError::BadCast(CastError { target: "u16" })));
+}
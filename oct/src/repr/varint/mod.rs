@@ -0,0 +1,202 @@
+// Copyright 2024 Gabriel Bjørnager Jensen.
+//
+// This file is part of Oct.
+//
+// Oct is free software: you can redistribute it
+// and/or modify it under the terms of the GNU
+// Lesser General Public License as published by
+// the Free Software Foundation, either version 3
+// of the License, or (at your option) any later
+// version.
+//
+// Oct is distributed in the hope that it will be
+// useful, but WITHOUT ANY WARRANTY; without even
+// the implied warranty of MERCHANTABILITY or FIT-
+// NESS FOR A PARTICULAR PURPOSE. See the GNU Less-
+// er General Public License for more details.
+//
+// You should have received a copy of the GNU Less-
+// er General Public License along with Oct. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! The variable-length integer representation.
+//!
+//! This is the representation used by the `varint` field attribute.
+//! Integers are encoded as [LEB128](https://en.wikipedia.org/wiki/LEB128), with signed integers being zigzag-encoded first (so that small negative values are also encoded using few bytes).
+
+use crate::decode::{Decode, Input};
+use crate::encode::Output;
+use crate::error::VarintDecodeError;
+
+use core::any::type_name;
+use core::convert::Infallible;
+
+mod sealed {
+	/// Denotes an integral type with a variable-length representation.
+	///
+	/// See the public [`Varint`](crate::repr::Varint) trait for more information.
+	pub trait Varint: Sized {
+		/// Converts the value into its (unsigned and possibly zigzag-encoded) variable-length value.
+		#[must_use]
+		fn to_varint(self) -> u128;
+
+		/// Converts a variable-length value back into the original type.
+		///
+		/// If the value does not fit, then [`None`] is returned.
+		#[must_use]
+		fn from_varint(value: u128) -> Option<Self>;
+	}
+}
+
+pub(crate) use sealed::Varint as SealedVarint;
+
+/// Denotes an integral type with a variable-length representation.
+///
+/// This trait is implemented for all fixed-size integers, as well as for [`usize`] and [`isize`].
+/// The latter two are encoded as [`u64`] and [`i64`], respectively, and decoding values that do not fit yields an error.
+pub trait Varint: Copy + SealedVarint {
+	/// The maximum amount of bytes in the variable-length representation.
+	const MAX_VARINT_SIZE: usize;
+}
+
+macro_rules! impl_unsigned_varint {
+	($ty:ty$(,)?) => {
+		impl ::oct::repr::SealedVarint for $ty {
+			#[inline(always)]
+			fn to_varint(self) -> u128 {
+				u128::from(self)
+			}
+
+			#[inline(always)]
+			fn from_varint(value: u128) -> Option<Self> {
+				Self::try_from(value).ok()
+			}
+		}
+
+		impl ::oct::repr::Varint for $ty {
+			const MAX_VARINT_SIZE: usize = (<$ty>::BITS as usize).div_ceil(0x7);
+		}
+	};
+}
+
+macro_rules! impl_signed_varint {
+	($ty:ty, $unsigned:ty$(,)?) => {
+		impl ::oct::repr::SealedVarint for $ty {
+			#[allow(clippy::cast_sign_loss)]
+			#[inline(always)]
+			fn to_varint(self) -> u128 {
+				let value = ((self << 0x1) ^ (self >> (<$ty>::BITS - 0x1))) as $unsigned;
+
+				u128::from(value)
+			}
+
+			#[allow(clippy::cast_possible_wrap)]
+			#[inline(always)]
+			fn from_varint(value: u128) -> Option<Self> {
+				let value = <$unsigned>::try_from(value).ok()?;
+
+				Some(((value >> 0x1) as $ty) ^ -((value & 0x1) as $ty))
+			}
+		}
+
+		impl ::oct::repr::Varint for $ty {
+			const MAX_VARINT_SIZE: usize = (<$ty>::BITS as usize).div_ceil(0x7);
+		}
+	};
+}
+
+macro_rules! impl_pointer_varint {
+	($ty:ty, $via:ty$(,)?) => {
+		impl ::oct::repr::SealedVarint for $ty {
+			#[inline(always)]
+			fn to_varint(self) -> u128 {
+				// Pointer-sized integers are at most 64 bits wide.
+				let value = self as $via;
+
+				value.to_varint()
+			}
+
+			#[inline(always)]
+			fn from_varint(value: u128) -> Option<Self> {
+				let value = <$via>::from_varint(value)?;
+
+				Self::try_from(value).ok()
+			}
+		}
+
+		impl ::oct::repr::Varint for $ty {
+			const MAX_VARINT_SIZE: usize = <$via as ::oct::repr::Varint>::MAX_VARINT_SIZE;
+		}
+	};
+}
+
+impl_signed_varint!(i128, u128);
+impl_signed_varint!(i16,  u16);
+impl_signed_varint!(i32,  u32);
+impl_signed_varint!(i64,  u64);
+impl_signed_varint!(i8,   u8);
+
+impl_unsigned_varint!(u128);
+impl_unsigned_varint!(u16);
+impl_unsigned_varint!(u32);
+impl_unsigned_varint!(u64);
+impl_unsigned_varint!(u8);
+
+impl_pointer_varint!(isize, i64);
+impl_pointer_varint!(usize, u64);
+
+/// Encodes an integer as a variable-length integer.
+///
+/// # Errors
+///
+/// This function cannot fail.
+#[allow(clippy::cast_possible_truncation, clippy::unnecessary_wraps)]
+#[inline]
+pub fn encode<T: Varint>(value: &T, output: &mut Output) -> Result<(), Infallible> {
+	let mut value = value.to_varint();
+
+	loop {
+		let byte = (value & 0x7F) as u8;
+		value >>= 0x7;
+
+		if value == 0x0 {
+			output.write(&[byte]).unwrap();
+			break;
+		}
+
+		output.write(&[byte | 0x80]).unwrap();
+	}
+
+	Ok(())
+}
+
+/// Decodes a variable-length integer.
+///
+/// # Errors
+///
+/// If the encoding is longer than [`MAX_VARINT_SIZE`](Varint::MAX_VARINT_SIZE) bytes, or if the decoded value does not fit in `T`, then an error is returned.
+#[inline]
+pub fn decode<T: Varint>(input: &mut Input) -> Result<T, VarintDecodeError> {
+	let mut value = 0x0u128;
+
+	for i in 0x0..T::MAX_VARINT_SIZE {
+		let Ok(byte) = u8::decode(input);
+
+		let payload = u128::from(byte & 0x7F);
+		let shift   = i * 0x7;
+
+		// The last byte of a 128-bit value may only carry
+		// its two remaining bits.
+		if shift + 0x7 > 0x80 && payload >> (0x80 - shift) != 0x0 {
+			return Err(VarintDecodeError { target: type_name::<T>() });
+		}
+
+		value |= payload << shift;
+
+		if byte & 0x80 == 0x0 {
+			return T::from_varint(value).ok_or_else(|| VarintDecodeError { target: type_name::<T>() });
+		}
+	}
+
+	Err(VarintDecodeError { target: type_name::<T>() })
+}
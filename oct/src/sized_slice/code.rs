@@ -20,23 +20,34 @@
 // not, see <https://www.gnu.org/licenses/>.
 
 use crate::SizedSlice;
-use crate::decode::{Decode, DecodeBorrowed, Input};
-use crate::encode::{Encode, Output, SizedEncode};
+use crate::decode::{Decode, DecodeBorrowed, DecodeUnprefixed, Input};
+use crate::encode::{Encode, EncodeUnprefixed, Output, SizedEncode};
 use crate::error::{CollectionDecodeError, ItemDecodeError, LengthError};
+use crate::fingerprint::Fingerprint;
 
 use core::mem::MaybeUninit;
 
 impl<T: Decode, const N: usize> Decode for SizedSlice<T, N> {
 	type Error = CollectionDecodeError<LengthError, ItemDecodeError<usize, T::Error>>;
 
-	#[inline]
+	#[inline(always)]
 	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
 		let len = Decode::decode(input).unwrap();
+
+		Self::decode_unprefixed(len, input)
+	}
+}
+
+impl<T: Decode, const N: usize> DecodeBorrowed<[T]> for SizedSlice<T, N> { }
+
+impl<T: Decode, const N: usize> DecodeUnprefixed for SizedSlice<T, N> {
+	#[inline]
+	fn decode_unprefixed(len: usize, input: &mut Input) -> Result<Self, <Self as Decode>::Error> {
 		if len > N { return Err(CollectionDecodeError::BadLength(LengthError { capacity: N, len })) };
 
 		let mut buf = [const { MaybeUninit::<T>::uninit() };N];
 
-		for (i, slot) in buf.iter_mut().enumerate() {
+		for (i, slot) in buf.iter_mut().take(len).enumerate() {
 			let v = Decode::decode(input)
 				.map_err(|e| CollectionDecodeError::BadItem(ItemDecodeError { index: i, error: e }))?;
 
@@ -47,8 +58,6 @@ impl<T: Decode, const N: usize> Decode for SizedSlice<T, N> {
 	}
 }
 
-impl<T: Decode, const N: usize> DecodeBorrowed<[T]> for SizedSlice<T, N> { }
-
 impl<T: Encode, const N: usize> Encode for SizedSlice<T, N> {
 	type Error = <[T] as Encode>::Error;
 
@@ -58,6 +67,23 @@ impl<T: Encode, const N: usize> Encode for SizedSlice<T, N> {
 	}
 }
 
+impl<T: Encode, const N: usize> EncodeUnprefixed for SizedSlice<T, N> {
+	#[inline(always)]
+	fn encoded_len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error> {
+		self.as_slice().encode_unprefixed(output)
+	}
+}
+
+/// Shares the fingerprint of <code>[\[T\]](slice)</code>.
+impl<T: Fingerprint, const N: usize> Fingerprint for SizedSlice<T, N> {
+	const FINGERPRINT: u64 = <[T]>::FINGERPRINT;
+}
+
 impl<T: SizedEncode, const N: usize> SizedEncode for SizedSlice<T, N> {
 	const MAX_ENCODED_SIZE: usize = T::MAX_ENCODED_SIZE * N;
 }
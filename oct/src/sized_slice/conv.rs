@@ -25,9 +25,11 @@ use crate::error::LengthError;
 use core::borrow::{Borrow, BorrowMut};
 use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
-use core::ptr::copy_nonoverlapping;
 use core::slice;
 
+#[cfg(feature = "alloc")]
+use core::ptr::copy_nonoverlapping;
+
 #[cfg(feature = "alloc")]
 use alloc::alloc::{alloc, Layout};
 
@@ -20,17 +20,27 @@
 // not, see <https://www.gnu.org/licenses/>.
 
 use crate::SizedStr;
-use crate::decode::{Decode, DecodeBorrowed, Input};
-use crate::encode::{Encode, Output, SizedEncode};
+use crate::decode::{Decode, DecodeBorrowed, DecodeUnprefixed, Input};
+use crate::encode::{Encode, EncodeUnprefixed, Output, SizedEncode};
 use crate::error::{CollectionDecodeError, LengthError, StringError, Utf8Error};
+use crate::fingerprint::Fingerprint;
 
 impl<const N: usize> Decode for SizedStr<N> {
 	type Error = CollectionDecodeError<LengthError, Utf8Error>;
 
-	#[inline]
+	#[inline(always)]
 	fn decode(input: &mut Input) -> Result<Self, Self::Error> {
 		let len = Decode::decode(input).unwrap();
 
+		Self::decode_unprefixed(len, input)
+	}
+}
+
+impl<const N: usize> DecodeBorrowed<str> for SizedStr<N> { }
+
+impl<const N: usize> DecodeUnprefixed for SizedStr<N> {
+	#[inline]
+	fn decode_unprefixed(len: usize, input: &mut Input) -> Result<Self, <Self as Decode>::Error> {
 		let data = input.read(len).unwrap();
 
 		Self::from_utf8(data)
@@ -44,8 +54,6 @@ impl<const N: usize> Decode for SizedStr<N> {
 	}
 }
 
-impl<const N: usize> DecodeBorrowed<str> for SizedStr<N> { }
-
 impl<const N: usize> Encode for SizedStr<N> {
 	type Error = <str as Encode>::Error;
 
@@ -55,8 +63,25 @@ impl<const N: usize> Encode for SizedStr<N> {
 	}
 }
 
+impl<const N: usize> EncodeUnprefixed for SizedStr<N> {
+	#[inline(always)]
+	fn encoded_len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn encode_unprefixed(&self, output: &mut Output) -> Result<(), Self::Error> {
+		self.as_str().encode_unprefixed(output)
+	}
+}
+
+/// Shares the fingerprint of [`str`].
+impl<const N: usize> Fingerprint for SizedStr<N> {
+	const FINGERPRINT: u64 = str::FINGERPRINT;
+}
+
 impl<const N: usize> SizedEncode for SizedStr<N> {
 	const MAX_ENCODED_SIZE: usize =
 		usize::MAX_ENCODED_SIZE
-		+ u8::MAX_ENCODED_SIZE * N;
+		+ <[u8; N]>::MAX_ENCODED_SIZE;
 }
@@ -124,13 +124,13 @@ impl<const N: usize> SizedStr<N> {
 
 	/// Returns an iterator of the string's characters.
 	#[inline(always)]
-	pub fn chars(&self) -> Chars {
+	pub fn chars(&self) -> Chars<'_> {
 		self.as_str().chars()
 	}
 
 	/// Returns an iterator of the string's characters along with their positions.
 	#[inline(always)]
-	pub fn char_indices(&self) -> CharIndices {
+	pub fn char_indices(&self) -> CharIndices<'_> {
 		self.as_str().char_indices()
 	}
 }
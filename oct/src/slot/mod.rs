@@ -34,6 +34,9 @@ use core::ops::{Deref, DerefMut, Index, IndexMut};
 use core::ptr::{self, copy_nonoverlapping};
 use core::slice::{self, SliceIndex};
 
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+
 /// Typed encode/decode slot.
 ///
 /// This structure is intended as a lightweight byte buffer suitable for encoding a single, predefined type.
@@ -61,7 +64,7 @@ use core::slice::{self, SliceIndex};
 /// let mut buf = Slot::new();
 ///
 /// buf.write(Request::Join { username: "epsiloneridani".parse().unwrap() }).unwrap();
-/// assert_eq!(buf.as_slice(), b"\0\0\x0E\0epsiloneridani");
+/// assert_eq!(buf.as_slice(), b"\0\x0E\0epsiloneridani");
 ///
 /// // Do something with the slot...
 /// ```
@@ -384,6 +387,23 @@ impl<T> DerefMut for Slot<T> {
 	}
 }
 
+/// Converts the slot into a shared buffer.
+///
+/// The slot's allocation is reused, and the returned buffer will only include the used part of the slot (as specified by [`len`](Slot::len)).
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc, doc(cfg(feature = "bytes")))]
+impl<T> From<Slot<T>> for Bytes {
+	#[inline]
+	fn from(value: Slot<T>) -> Self {
+		let len = value.len();
+
+		let mut this = Self::from(value.buf);
+		this.truncate(len);
+
+		this
+	}
+}
+
 #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
 impl<T, I: SliceIndex<[u8]>> Index<I> for Slot<T> {
 	type Output = I::Output;